@@ -0,0 +1,51 @@
+/*
+ * C API of the intfloat crate, built with the `ffi` feature. See the `intfloat::ffi` module for
+ * the full documentation.
+ *
+ * An IntFloat64 has the value base x 10^-pow, so {1250, 2} is 12.50. Functions that can fail
+ * write their result through `out` and return true, or return false and leave `out` untouched.
+ */
+#ifndef INTFLOAT_H
+#define INTFLOAT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct IntFloat64 {
+    int64_t base;
+    intptr_t pow;
+} IntFloat64;
+
+/* The IntFloat64 with value base x 10^-pow. */
+IntFloat64 intfloat_new(int64_t base, intptr_t pow);
+
+/* Parse a decimal string like "12.345" or "1.5e-3". */
+bool intfloat_parse(const char *str, IntFloat64 *out);
+
+/* Exact arithmetic, failing on overflow. */
+bool intfloat_add(IntFloat64 lhs, IntFloat64 rhs, IntFloat64 *out);
+bool intfloat_sub(IntFloat64 lhs, IntFloat64 rhs, IntFloat64 *out);
+bool intfloat_mul(IntFloat64 lhs, IntFloat64 rhs, IntFloat64 *out);
+
+/* lhs / rhs rounded half up to `decimals` decimals, failing on division by zero or overflow. */
+bool intfloat_div(IntFloat64 lhs, IntFloat64 rhs, intptr_t decimals, IntFloat64 *out);
+
+/* -1, 0 or 1 as lhs is smaller than, equal to or larger than rhs. 12.50 equals 12.5. */
+int intfloat_cmp(IntFloat64 lhs, IntFloat64 rhs);
+
+/*
+ * Write the decimal string, e.g. "12.50", to `buffer` like snprintf: cut off at len - 1 bytes and
+ * always NUL-terminated if len > 0. Returns the length of the whole string without the NUL.
+ */
+size_t intfloat_to_string(IntFloat64 value, char *buffer, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* INTFLOAT_H */
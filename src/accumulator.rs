@@ -0,0 +1,271 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloat128, OutOfRangeError, RoundingMode};
+use std::marker::PhantomData;
+use std::ops::{AddAssign, SubAssign};
+
+/// Running sum of IntFloats in an i128 base, so that summing many values can't overflow the base
+/// of the values themselves: with i64 bases, it takes about 2^64 of the largest values to
+/// overflow. The sum is exact, with the largest pow of the values, and only converted back (and
+/// rounded, if asked) at the end. It also counts the values, for the mean.
+///
+/// Adding panics if the i128 sum overflows anyway, which mostly happens when a value with many
+/// more decimals than the sum so far arrives. `checked_add` and `checked_sub` return None
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloatAccumulator, RoundingMode};
+/// let prices = vec![IntFloat::new(isize::MAX, 2); 3];
+/// let total: IntFloatAccumulator = prices.iter().collect();
+/// assert_eq!(total.sum().to_string(), "276701161105643274.21");
+/// assert!(IntFloat::try_from(total).is_err());
+/// assert_eq!(total.round_sum(0, RoundingMode::HalfUp).unwrap().to_string(), "276701161105643274");
+/// assert_eq!(total.mean(2, RoundingMode::HalfUp), Some(IntFloat::new(isize::MAX, 2)));
+///
+/// let mut total = IntFloatAccumulator::new();
+/// total += IntFloat::new(1250, 2);
+/// total -= IntFloat::new(5, 1);
+/// assert_eq!(IntFloat::try_from(total), Ok(IntFloat::new(1200, 2)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IntFloatAccumulator<T> {
+    sum: IntFloat128,
+    count: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: BaseInt> IntFloatAccumulator<T> {
+    /// An empty accumulator, with a sum of zero.
+    pub fn new() -> Self {
+        IntFloatAccumulator {
+            sum: IntFloat::new(0, 0),
+            count: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Add `value` to the sum. Panics if the sum overflows.
+    pub fn add(&mut self, value: IntFloat<T>) {
+        *self = self
+            .checked_add(value)
+            .expect("attempt to add with overflow");
+    }
+
+    /// Subtract `value` from the sum, which still counts as a value. Panics if the sum overflows.
+    pub fn sub(&mut self, value: IntFloat<T>) {
+        *self = self
+            .checked_sub(value)
+            .expect("attempt to subtract with overflow");
+    }
+
+    /// Like `add`, but None if the sum overflows.
+    pub fn checked_add(self, value: IntFloat<T>) -> Option<Self> {
+        let value = value.cast().expect("every base fits in an i128");
+        Some(IntFloatAccumulator {
+            sum: self.sum.checked_add(value)?,
+            count: self.count + 1,
+            marker: PhantomData,
+        })
+    }
+
+    /// Like `sub`, but None if the sum overflows.
+    pub fn checked_sub(self, value: IntFloat<T>) -> Option<Self> {
+        let value: IntFloat128 = value.cast().expect("every base fits in an i128");
+        Some(IntFloatAccumulator {
+            sum: self.sum.checked_sub(value)?,
+            count: self.count + 1,
+            marker: PhantomData,
+        })
+    }
+
+    /// Combine with the sum and count of another accumulator, e.g. of another thread. None if the
+    /// sum overflows.
+    pub fn checked_merge(self, other: Self) -> Option<Self> {
+        Some(IntFloatAccumulator {
+            sum: self.sum.checked_add(other.sum)?,
+            count: self.count + other.count,
+            marker: PhantomData,
+        })
+    }
+
+    /// The exact sum, with the largest pow of the values.
+    pub fn sum(&self) -> IntFloat128 {
+        self.sum
+    }
+
+    /// The number of values added or subtracted.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The sum, rounded to `decimals` decimals with `mode`. None if that doesn't fit in the base.
+    pub fn round_sum(&self, decimals: isize, mode: RoundingMode) -> Option<IntFloat<T>> {
+        self.sum.round_dp_with(decimals, mode).cast()
+    }
+
+    /// The mean of the values, rounded to exactly `decimals` decimals with `mode`. None if there
+    /// are no values or the mean doesn't fit in the base.
+    pub fn mean(&self, decimals: isize, mode: RoundingMode) -> Option<IntFloat<T>> {
+        let count = IntFloat::new(i128::try_from(self.count).ok()?, 0);
+        self.sum
+            .checked_div_with_mode(count, decimals, mode)?
+            .cast()
+    }
+}
+
+impl<T: BaseInt> Default for IntFloatAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: BaseInt> AddAssign<IntFloat<T>> for IntFloatAccumulator<T> {
+    fn add_assign(&mut self, value: IntFloat<T>) {
+        self.add(value);
+    }
+}
+
+impl<T: BaseInt> SubAssign<IntFloat<T>> for IntFloatAccumulator<T> {
+    fn sub_assign(&mut self, value: IntFloat<T>) {
+        self.sub(value);
+    }
+}
+
+/// Merge another accumulator, like `checked_merge`. Panics if the sum overflows.
+impl<T: BaseInt> AddAssign for IntFloatAccumulator<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = self
+            .checked_merge(other)
+            .expect("attempt to add with overflow");
+    }
+}
+
+impl<T: BaseInt> Extend<IntFloat<T>> for IntFloatAccumulator<T> {
+    fn extend<I: IntoIterator<Item = IntFloat<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.add(value));
+    }
+}
+
+impl<'a, T: BaseInt> Extend<&'a IntFloat<T>> for IntFloatAccumulator<T> {
+    fn extend<I: IntoIterator<Item = &'a IntFloat<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.add(*value));
+    }
+}
+
+impl<T: BaseInt> FromIterator<IntFloat<T>> for IntFloatAccumulator<T> {
+    fn from_iter<I: IntoIterator<Item = IntFloat<T>>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+impl<'a, T: BaseInt> FromIterator<&'a IntFloat<T>> for IntFloatAccumulator<T> {
+    fn from_iter<I: IntoIterator<Item = &'a IntFloat<T>>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+/// The exact sum, which fails if it doesn't fit in the base.
+impl<T: BaseInt> TryFrom<IntFloatAccumulator<T>> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(accumulator: IntFloatAccumulator<T>) -> Result<Self, Self::Error> {
+        accumulator.sum.cast().ok_or(OutOfRangeError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generic, IntFloat, IntFloat64};
+
+    #[test]
+    fn test_sum() {
+        let values: Vec<IntFloat64> = (0..1_000_000)
+            .map(|_| IntFloat64::new(i64::MAX, 2))
+            .collect();
+        let accumulator: IntFloatAccumulator<i64> = values.iter().collect();
+        assert_eq!(accumulator.count(), 1_000_000);
+        assert_eq!(
+            accumulator.sum(),
+            IntFloat128::new(i64::MAX as i128 * 1_000_000, 2)
+        );
+        assert_eq!(IntFloat64::try_from(accumulator), Err(OutOfRangeError));
+        assert_eq!(
+            accumulator.mean(2, RoundingMode::HalfUp),
+            Some(IntFloat64::new(i64::MAX, 2))
+        );
+
+        let mixed: IntFloatAccumulator<isize> = [
+            IntFloat::new(1, -3),
+            IntFloat::new(5, 1),
+            IntFloat::new(-125, 3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(mixed.sum().to_string(), "1000.375");
+        assert_eq!(
+            mixed.round_sum(2, RoundingMode::HalfEven),
+            Some(IntFloat::new(100038, 2))
+        );
+        assert_eq!(
+            mixed.round_sum(2, RoundingMode::Floor),
+            Some(IntFloat::new(100037, 2))
+        );
+        assert_eq!(
+            mixed.mean(1, RoundingMode::HalfUp),
+            Some(IntFloat::new(3335, 1))
+        );
+
+        let empty = IntFloatAccumulator::<isize>::default();
+        assert_eq!(IntFloat::try_from(empty), Ok(IntFloat::new(0, 0)));
+        assert_eq!(empty.mean(2, RoundingMode::HalfUp), None);
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut accumulator = generic::IntFloatAccumulator::<i8>::new();
+        for _ in 0..10 {
+            accumulator += generic::IntFloat::new(100, 0);
+        }
+        accumulator -= generic::IntFloat::new(-5, 1);
+        assert_eq!(accumulator.sum().to_string(), "1000.5");
+        assert_eq!(accumulator.count(), 11);
+        let mut other = generic::IntFloatAccumulator::<i8>::new();
+        other.extend([generic::IntFloat::new(-127, 1)]);
+        accumulator += other;
+        assert_eq!(accumulator.sum().to_string(), "987.8");
+        assert_eq!(accumulator.count(), 12);
+        assert_eq!(
+            accumulator.round_sum(-1, RoundingMode::HalfUp),
+            Some(generic::IntFloat::new(99, -1))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let accumulator = IntFloatAccumulator::new().checked_add(IntFloat::new(1, -30));
+        assert!(accumulator
+            .unwrap()
+            .checked_add(IntFloat::new(1, 10))
+            .is_none());
+        let max = IntFloatAccumulator::<i128>::new()
+            .checked_add(IntFloat128::new(i128::MAX, 0))
+            .unwrap();
+        assert!(max.checked_add(IntFloat128::new(1, 0)).is_none());
+        assert!(max.checked_merge(max).is_none());
+        assert!(max.checked_sub(IntFloat128::new(1, 0)).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_panics() {
+        let mut accumulator = IntFloatAccumulator::<i128>::new();
+        accumulator += IntFloat128::new(i128::MAX, 0);
+        accumulator += IntFloat128::new(1, 0);
+    }
+}
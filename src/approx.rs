@@ -0,0 +1,169 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloat128};
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+fn wide<T: BaseInt>(value: IntFloat<T>) -> IntFloat128 {
+    value.cast().expect("every base fits in an i128")
+}
+
+/// `|a - b| <= epsilon`. IntFloat is exact, so the default epsilon is zero and
+/// `assert_abs_diff_eq!(a, b)` only passes for equal values: pass one with
+/// `epsilon = IntFloat::new(1, 2)`. The difference is computed with an i128 base, and comparing
+/// fails if that overflows.
+///
+/// # Examples
+///
+/// ```
+/// use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+/// use intfloat::IntFloat;
+/// let price = IntFloat::new(1999, 2);
+/// assert_abs_diff_eq!(price, IntFloat::new(19994, 3), epsilon = IntFloat::new(5, 3));
+/// assert_relative_eq!(price, IntFloat::new(20, 0), max_relative = IntFloat::new(1, 3));
+/// assert_ulps_eq!(price, IntFloat::new(20, 0), max_ulps = 1);
+/// assert_abs_diff_eq!(price, IntFloat::new(19990, 3));
+/// ```
+impl<T: BaseInt> AbsDiffEq for IntFloat<T> {
+    type Epsilon = IntFloat<T>;
+
+    fn default_epsilon() -> Self::Epsilon {
+        IntFloat::new(T::ZERO, 0)
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match wide(*self).checked_sub(wide(*other)) {
+            Some(diff) => diff.abs() <= wide(epsilon),
+            None => false,
+        }
+    }
+}
+
+/// `|a - b| <= max(|a|, |b|) * max_relative`, or within `epsilon`. Like `AbsDiffEq`, the default
+/// `max_relative` is zero, and comparing fails if the computation overflows an i128 base.
+impl<T: BaseInt> RelativeEq for IntFloat<T> {
+    fn default_max_relative() -> Self::Epsilon {
+        IntFloat::new(T::ZERO, 0)
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        let (lhs, rhs) = (wide(*self), wide(*other));
+        let largest = lhs.abs().max(rhs.abs());
+        match (
+            lhs.checked_sub(rhs),
+            largest.checked_mul(wide(max_relative)),
+        ) {
+            (Some(diff), Some(tolerance)) => diff.abs() <= tolerance,
+            _ => false,
+        }
+    }
+}
+
+/// Units in the last place are steps of the smallest decimal of both values: 19.99 and 20 are 1
+/// ulp apart, and 19.990 and 20 are 10. The default `max_ulps` is zero.
+impl<T: BaseInt> UlpsEq for IntFloat<T> {
+    fn default_max_ulps() -> u32 {
+        0
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        let pow = self.pow.max(other.pow);
+        let (lhs, rhs) = (wide(*self).with_scale(pow), wide(*other).with_scale(pow));
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs
+                .base
+                .checked_sub(rhs.base)
+                .is_some_and(|diff| diff.unsigned_abs() <= max_ulps as u128),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloat128};
+    use ::approx::{abs_diff_eq, abs_diff_ne, relative_eq, relative_ne, ulps_eq, ulps_ne};
+
+    #[test]
+    fn test_abs_diff_eq() {
+        let a = IntFloat::new(1250, 2);
+        assert!(abs_diff_eq!(a, IntFloat::new(125, 1)));
+        assert!(abs_diff_ne!(a, IntFloat::new(12501, 3)));
+        assert!(abs_diff_eq!(
+            a,
+            IntFloat::new(12501, 3),
+            epsilon = IntFloat::new(1, 3)
+        ));
+        assert!(abs_diff_eq!(
+            a,
+            IntFloat::new(12499, 3),
+            epsilon = IntFloat::new(1, 3)
+        ));
+        assert!(abs_diff_ne!(
+            a,
+            IntFloat::new(12502, 3),
+            epsilon = IntFloat::new(1, 3)
+        ));
+        let max = IntFloat128::new(i128::MAX, 0);
+        assert!(abs_diff_ne!(max, -max, epsilon = max));
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        let a = IntFloat::new(1000, 0);
+        let max_relative = IntFloat::new(1, 2);
+        assert!(relative_eq!(
+            a,
+            IntFloat::new(1010, 0),
+            max_relative = max_relative
+        ));
+        assert!(relative_eq!(
+            -a,
+            IntFloat::new(-990, 0),
+            max_relative = max_relative
+        ));
+        assert!(relative_ne!(
+            a,
+            IntFloat::new(1011, 0),
+            max_relative = max_relative
+        ));
+        assert!(relative_ne!(a, -a, max_relative = max_relative));
+        assert!(relative_eq!(
+            IntFloat::new(0, 0),
+            IntFloat::new(1, 6),
+            epsilon = IntFloat::new(1, 6)
+        ));
+        assert!(relative_ne!(a, IntFloat::new(1001, 0)));
+    }
+
+    #[test]
+    fn test_ulps_eq() {
+        let a = IntFloat::new(1999, 2);
+        assert!(ulps_eq!(a, IntFloat::new(20, 0), max_ulps = 1));
+        assert!(ulps_ne!(
+            IntFloat::new(19990, 3),
+            IntFloat::new(20, 0),
+            max_ulps = 9
+        ));
+        assert!(ulps_eq!(
+            IntFloat::new(19990, 3),
+            IntFloat::new(20, 0),
+            max_ulps = 10
+        ));
+        assert!(ulps_ne!(a, IntFloat::new(20, 0)));
+        assert!(ulps_ne!(
+            IntFloat::new(1, -40),
+            IntFloat::new(1, 40),
+            max_ulps = u32::MAX
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+/// Any base, with a pow from -4 to as many decimals as the base allows (e.g. 18 for an i64), like
+/// `any::<IntFloat>()` of the `proptest` feature. Larger pows are valid too, but then hardly any
+/// digits are left before the decimal point and formatting gets slow, so fuzzers shouldn't spend
+/// time there.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use intfloat::IntFloat;
+/// let mut bytes = Unstructured::new(&[0xd2, 0x04, 0, 0, 0, 0, 0, 0, 6]);
+/// let value = IntFloat::arbitrary(&mut bytes).unwrap();
+/// assert_eq!(value.to_string(), "12.34");
+/// ```
+impl<'a, T: BaseInt + Arbitrary<'a>> Arbitrary<'a> for IntFloat<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let base = T::arbitrary(u)?;
+        let pow = u.int_in_range(-4..=T::DIGITS as isize)?;
+        Ok(IntFloat::new(base, pow))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(T::size_hint(depth), (0, Some(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    #[test]
+    fn test_arbitrary() {
+        let value = IntFloat32::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!((value.base, value.pow), (0, -4));
+        let value = IntFloat32::arbitrary(&mut Unstructured::new(&[0xff; 5])).unwrap();
+        assert_eq!((value.base, value.pow), (-1, -1));
+
+        let bytes: Vec<u8> = (0..=255).cycle().step_by(7).take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let value = IntFloat128::arbitrary(&mut u).unwrap();
+            assert!((-4..=38).contains(&value.pow));
+        }
+        assert_eq!(IntFloat::size_hint(0), (8, Some(9)));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::intfloat::IntFloat;
+use crate::OutOfRangeError;
+use ::rkyv::bytecheck::CheckBytes;
+use ::rkyv::munge::munge;
+use ::rkyv::rancor::{Fallible, Source};
+use ::rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+use std::marker::PhantomData;
+
+/// The archived form of an IntFloat for rkyv, which can be read in place without deserializing.
+/// It holds the `LEN` bytes of `to_le_bytes` for base `T`, so the layout is the same on every platform and
+/// needs no alignment. This also keeps the full isize base, which rkyv would otherwise truncate
+/// to 32 bits.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use rkyv::rancor::Error;
+///
+/// let prices = vec![IntFloat::new(1999, 2), IntFloat::new(-5, 1)];
+/// let bytes = rkyv::to_bytes::<Error>(&prices).unwrap();
+/// let archived = rkyv::access::<rkyv::Archived<Vec<IntFloat>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived[0].to_intfloat(), Ok(IntFloat::new(1999, 2)));
+/// let deserialized: Vec<IntFloat> = rkyv::deserialize::<_, Error>(archived).unwrap();
+/// assert_eq!(deserialized, prices);
+/// ```
+#[derive(Portable, CheckBytes, Clone, Copy, Debug)]
+#[bytecheck(crate = ::rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedIntFloat<T, const LEN: usize> {
+    bytes: [u8; LEN],
+    base: PhantomData<T>,
+}
+
+macro_rules! impl_archive {
+    ($($int:ident: $len:literal),*) => {
+        $(
+            impl ArchivedIntFloat<$int, $len> {
+                /// The IntFloat this was archived from. Fails only if the base or the pow
+                /// doesn't fit in an isize, on platforms where it is smaller than 64 bits.
+                pub fn to_intfloat(&self) -> Result<IntFloat<$int>, OutOfRangeError> {
+                    IntFloat::<$int>::from_le_bytes(self.bytes)
+                }
+            }
+
+            impl Archive for IntFloat<$int> {
+                type Archived = ArchivedIntFloat<$int, $len>;
+                type Resolver = ();
+
+                fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+                    munge!(let ArchivedIntFloat { bytes, .. } = out);
+                    bytes.write(self.to_le_bytes());
+                }
+            }
+
+            impl<S: Fallible + ?Sized> Serialize<S> for IntFloat<$int> {
+                fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<D> Deserialize<IntFloat<$int>, D> for ArchivedIntFloat<$int, $len>
+            where
+                D: Fallible + ?Sized,
+                D::Error: Source,
+            {
+                fn deserialize(&self, _: &mut D) -> Result<IntFloat<$int>, D::Error> {
+                    self.to_intfloat().map_err(D::Error::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_archive!(i8: 9, i16: 10, i32: 12, i64: 16, i128: 24, isize: 16);
+
+#[cfg(test)]
+mod tests {
+    use crate::generic;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use rkyv::rancor::Error;
+    use rkyv::Archived;
+
+    #[test]
+    fn test_round_trip() {
+        let values = vec![
+            IntFloat::new(0, 0),
+            IntFloat::new(isize::MIN, isize::MAX),
+            IntFloat::new(isize::MAX, -3),
+            IntFloat::new(-1250, 3),
+        ];
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<Archived<Vec<IntFloat>>, Error>(&bytes).unwrap();
+        assert_eq!(archived[1].to_intfloat(), Ok(values[1]));
+        let deserialized: Vec<IntFloat> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(deserialized, values);
+        assert_eq!(deserialized[3].to_string(), "-1.250");
+
+        let wide = IntFloat128::new(i128::MIN, 38);
+        let bytes = rkyv::to_bytes::<Error>(&wide).unwrap();
+        assert_eq!(
+            rkyv::from_bytes::<IntFloat128, Error>(&bytes).unwrap(),
+            wide
+        );
+        let small = generic::IntFloat::<i8>::new(-7, 1);
+        let bytes = rkyv::to_bytes::<Error>(&small).unwrap();
+        assert_eq!(
+            rkyv::from_bytes::<generic::IntFloat<i8>, Error>(&bytes).unwrap(),
+            small
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(size_of::<Archived<IntFloat>>(), 16);
+        assert_eq!(size_of::<Archived<IntFloat32>>(), 12);
+        let bytes = rkyv::to_bytes::<Error>(&IntFloat32::new(-2, 3)).unwrap();
+        assert_eq!(&bytes[..], &IntFloat32::new(-2, 3).to_le_bytes()[..]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Only 8 of the 12 bytes of an archived IntFloat32.
+        assert!(rkyv::access::<Archived<IntFloat32>, Error>(&[0; 8]).is_err());
+        assert!(rkyv::access::<Archived<IntFloat32>, Error>(&[0; 12]).is_ok());
+    }
+}
@@ -0,0 +1,148 @@
+//! Exact conversions between IntFloat and Apache Arrow's Decimal128, for exchanging decimal
+//! columns with Arrow-based tools like PyArrow without a detour through floats. A Decimal128 is
+//! an i128 with a fixed scale and precision for the whole column: the value is that integer
+//! times 10^-scale, and it has at most `precision` digits.
+//!
+//! ```
+//! use arrow_array::Array;
+//! use intfloat::{arrow, IntFloat};
+//! let prices = [Some(IntFloat::new(1999, 2)), None, Some(IntFloat::new(5, 0))];
+//! let array = arrow::to_decimal128_array(prices, 10, 2).unwrap();
+//! assert_eq!(array.value(2), 500);
+//! assert_eq!(array.value_as_string(0), "19.99");
+//! assert!(array.is_null(1));
+//! let back: Vec<Option<IntFloat>> = arrow::from_decimal128_array(&array).unwrap();
+//! assert_eq!(back[2].unwrap().to_string(), "5.00");
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use arrow_array::types::{Decimal128Type, DecimalType};
+use arrow_array::Decimal128Array;
+
+/// The integer of a Decimal128 with `scale` for `value`. Fails if `value` has non-zero digits
+/// beyond `scale`, or if the result doesn't fit in an i128.
+pub fn to_decimal128<T: BaseInt>(value: IntFloat<T>, scale: i8) -> Result<i128, OutOfRangeError> {
+    value.to_scaled_i128(scale as isize).ok_or(OutOfRangeError)
+}
+
+/// The IntFloat with `scale` decimals for the integer of a Decimal128. Fails if `value` doesn't
+/// fit in the base.
+pub fn from_decimal128<T: BaseInt>(value: i128, scale: i8) -> Result<IntFloat<T>, OutOfRangeError> {
+    let base = num_traits::cast(value).ok_or(OutOfRangeError)?;
+    Ok(IntFloat::new(base, scale as isize))
+}
+
+/// A Decimal128Array with the given precision and scale, with a null for every None. Fails if
+/// the precision and scale aren't valid for Decimal128, or if a value doesn't fit exactly.
+pub fn to_decimal128_array<T, I>(
+    values: I,
+    precision: u8,
+    scale: i8,
+) -> Result<Decimal128Array, OutOfRangeError>
+where
+    T: BaseInt,
+    I: IntoIterator<Item = Option<IntFloat<T>>>,
+{
+    let values = values
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    let decimal = to_decimal128(value, scale)?;
+                    Decimal128Type::is_valid_decimal_precision(decimal, precision)
+                        .then_some(decimal)
+                        .ok_or(OutOfRangeError)
+                })
+                .transpose()
+        })
+        .collect::<Result<Decimal128Array, OutOfRangeError>>()?;
+    values
+        .with_precision_and_scale(precision, scale)
+        .map_err(|_| OutOfRangeError)
+}
+
+/// The values of a Decimal128Array, with None for every null. The values keep the scale of the
+/// array. Fails if a value doesn't fit in the base.
+pub fn from_decimal128_array<T: BaseInt>(
+    array: &Decimal128Array,
+) -> Result<Vec<Option<IntFloat<T>>>, OutOfRangeError> {
+    array
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| from_decimal128(value, array.scale()))
+                .transpose()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use arrow_array::Array;
+
+    #[test]
+    fn test_to_decimal128() {
+        assert_eq!(to_decimal128(IntFloat::new(1999, 2), 2), Ok(1999));
+        assert_eq!(to_decimal128(IntFloat::new(5, 0), 3), Ok(5000));
+        assert_eq!(to_decimal128(IntFloat::new(1230, 3), 2), Ok(123));
+        assert_eq!(
+            to_decimal128(IntFloat::new(1234, 3), 2),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(to_decimal128(IntFloat::new(-15, -2), -1), Ok(-150));
+        assert_eq!(to_decimal128(IntFloat::new(0, 40), 2), Ok(0));
+        assert_eq!(
+            to_decimal128(IntFloat128::new(i128::MAX, 0), 1),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_from_decimal128() {
+        assert_eq!(from_decimal128(1999, 2), Ok(IntFloat::new(1999, 2)));
+        assert_eq!(
+            from_decimal128::<isize>(-5, -3).map(|value| value.to_string()),
+            Ok("-5000".to_string())
+        );
+        assert_eq!(
+            from_decimal128(i128::MIN, 38),
+            Ok(IntFloat128::new(i128::MIN, 38))
+        );
+        assert_eq!(from_decimal128::<i32>(1 << 40, 2), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_array() {
+        let values = vec![
+            Some(IntFloat32::new(125, 2)),
+            None,
+            Some(IntFloat32::new(-3, 0)),
+            Some(IntFloat32::new(1, 3)),
+        ];
+        let array = to_decimal128_array(values.clone(), 5, 3).unwrap();
+        assert_eq!((array.precision(), array.scale()), (5, 3));
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.value(0), 1250);
+        assert_eq!(array.value_as_string(2), "-3.000");
+        let back: Vec<Option<IntFloat32>> = from_decimal128_array(&array).unwrap();
+        assert_eq!(back, values);
+        assert_eq!(back[3].unwrap().to_string(), "0.001");
+    }
+
+    #[test]
+    fn test_array_out_of_range() {
+        let values = [Some(IntFloat::new(1234, 3))];
+        assert_eq!(to_decimal128_array(values, 10, 2), Err(OutOfRangeError));
+        let values = [Some(IntFloat::new(100, 0))];
+        assert_eq!(to_decimal128_array(values, 2, 0), Err(OutOfRangeError));
+        assert!(to_decimal128_array(values, 3, 0).is_ok());
+        assert_eq!(to_decimal128_array(values, 39, 0), Err(OutOfRangeError));
+        assert_eq!(to_decimal128_array(values, 2, 3), Err(OutOfRangeError));
+        let wide = to_decimal128_array([Some(IntFloat128::new(1 << 100, 0))], 38, 0).unwrap();
+        assert_eq!(from_decimal128_array::<isize>(&wide), Err(OutOfRangeError));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::FixedIntFloat;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+/// FixedIntFloat that can be shared and updated between threads without a lock, for example
+/// for counters that many threads add to. Because the scale is part of the type, only the base
+/// has to be stored, in an AtomicIsize, and adding never has to rescale.
+///
+/// Like the atomic integers of std, `fetch_add` and `fetch_sub` wrap around on overflow.
+/// `checked_fetch_add` and `checked_fetch_sub` leave the value unchanged instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{AtomicIntFloat, FixedIntFloat};
+/// use std::sync::atomic::Ordering;
+/// use std::thread;
+///
+/// let revenue = AtomicIntFloat::<2>::default();
+/// thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| {
+///             for _ in 0..1000 {
+///                 revenue.fetch_add(FixedIntFloat::new(1999), Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(revenue.load(Ordering::Relaxed).to_string(), "79960.00");
+/// ```
+#[derive(Default)]
+#[repr(transparent)]
+pub struct AtomicIntFloat<const SCALE: i32> {
+    base: AtomicIsize,
+}
+
+impl<const SCALE: i32> AtomicIntFloat<SCALE> {
+    pub fn new(value: FixedIntFloat<SCALE>) -> Self {
+        AtomicIntFloat {
+            base: AtomicIsize::new(value.base()),
+        }
+    }
+
+    pub fn load(&self, order: Ordering) -> FixedIntFloat<SCALE> {
+        FixedIntFloat::new(self.base.load(order))
+    }
+
+    pub fn store(&self, value: FixedIntFloat<SCALE>, order: Ordering) {
+        self.base.store(value.base(), order);
+    }
+
+    /// Store `value` and return the previous value.
+    pub fn swap(&self, value: FixedIntFloat<SCALE>, order: Ordering) -> FixedIntFloat<SCALE> {
+        FixedIntFloat::new(self.base.swap(value.base(), order))
+    }
+
+    /// Store `new` if the current value is `current`, like `AtomicIsize::compare_exchange`. Returns
+    /// the previous value, as Ok if it was replaced.
+    pub fn compare_exchange(
+        &self,
+        current: FixedIntFloat<SCALE>,
+        new: FixedIntFloat<SCALE>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<FixedIntFloat<SCALE>, FixedIntFloat<SCALE>> {
+        self.base
+            .compare_exchange(current.base(), new.base(), success, failure)
+            .map(FixedIntFloat::new)
+            .map_err(FixedIntFloat::new)
+    }
+
+    /// Add `value` and return the previous value. Wraps around on overflow.
+    pub fn fetch_add(&self, value: FixedIntFloat<SCALE>, order: Ordering) -> FixedIntFloat<SCALE> {
+        FixedIntFloat::new(self.base.fetch_add(value.base(), order))
+    }
+
+    /// Subtract `value` and return the previous value. Wraps around on overflow.
+    pub fn fetch_sub(&self, value: FixedIntFloat<SCALE>, order: Ordering) -> FixedIntFloat<SCALE> {
+        FixedIntFloat::new(self.base.fetch_sub(value.base(), order))
+    }
+
+    /// Add `value` and return the previous value, or None without changing anything if the sum
+    /// doesn't fit. This retries with `compare_exchange`, so it is slower than `fetch_add`.
+    pub fn checked_fetch_add(
+        &self,
+        value: FixedIntFloat<SCALE>,
+        order: Ordering,
+    ) -> Option<FixedIntFloat<SCALE>> {
+        self.base
+            .fetch_update(order, Ordering::Relaxed, |base| {
+                base.checked_add(value.base())
+            })
+            .map(FixedIntFloat::new)
+            .ok()
+    }
+
+    /// Like `checked_fetch_add`, but subtracting.
+    pub fn checked_fetch_sub(
+        &self,
+        value: FixedIntFloat<SCALE>,
+        order: Ordering,
+    ) -> Option<FixedIntFloat<SCALE>> {
+        self.base
+            .fetch_update(order, Ordering::Relaxed, |base| {
+                base.checked_sub(value.base())
+            })
+            .map(FixedIntFloat::new)
+            .ok()
+    }
+
+    pub fn into_inner(self) -> FixedIntFloat<SCALE> {
+        FixedIntFloat::new(self.base.into_inner())
+    }
+}
+
+impl<const SCALE: i32> From<FixedIntFloat<SCALE>> for AtomicIntFloat<SCALE> {
+    fn from(value: FixedIntFloat<SCALE>) -> Self {
+        AtomicIntFloat::new(value)
+    }
+}
+
+/// Shows the current value, loaded with `Ordering::Relaxed`.
+impl<const SCALE: i32> Debug for AtomicIntFloat<SCALE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+    use std::sync::atomic::Ordering::SeqCst;
+    use std::thread;
+
+    type Cents = FixedIntFloat<2>;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size_of::<AtomicIntFloat<2>>(), size_of::<isize>());
+    }
+
+    #[test]
+    fn test_load_store() {
+        let atomic = AtomicIntFloat::new(Cents::new(150));
+        assert_eq!(atomic.load(SeqCst), Cents::new(150));
+        atomic.store(Cents::new(-25), SeqCst);
+        let loaded: IntFloat = atomic.load(SeqCst).into();
+        assert_eq!(IntFloat::new(-25, 2), loaded);
+        assert_eq!(atomic.swap(Cents::new(1), SeqCst), Cents::new(-25));
+        assert_eq!(
+            atomic.compare_exchange(Cents::new(2), Cents::new(3), SeqCst, SeqCst),
+            Err(Cents::new(1))
+        );
+        assert_eq!(
+            atomic.compare_exchange(Cents::new(1), Cents::new(3), SeqCst, SeqCst),
+            Ok(Cents::new(1))
+        );
+        assert_eq!(atomic.into_inner(), Cents::new(3));
+        assert_eq!(
+            format!("{:?}", AtomicIntFloat::from(Cents::new(7))),
+            "FixedIntFloat { base: 7 }"
+        );
+    }
+
+    #[test]
+    fn test_fetch_add() {
+        let atomic = AtomicIntFloat::<2>::default();
+        assert_eq!(atomic.fetch_add(Cents::new(250), SeqCst), Cents::new(0));
+        assert_eq!(atomic.fetch_sub(Cents::new(50), SeqCst), Cents::new(250));
+        assert_eq!(atomic.load(SeqCst).to_string(), "2.00");
+        atomic.store(Cents::new(isize::MAX), SeqCst);
+        assert_eq!(atomic.checked_fetch_add(Cents::new(1), SeqCst), None);
+        assert_eq!(atomic.load(SeqCst), Cents::new(isize::MAX));
+        assert_eq!(
+            atomic.checked_fetch_sub(Cents::new(1), SeqCst),
+            Some(Cents::new(isize::MAX))
+        );
+        atomic.fetch_add(Cents::new(2), SeqCst);
+        assert_eq!(atomic.load(SeqCst), Cents::new(isize::MIN));
+        assert_eq!(atomic.checked_fetch_sub(Cents::new(1), SeqCst), None);
+    }
+
+    #[test]
+    fn test_threads() {
+        let atomic = AtomicIntFloat::<3>::default();
+        thread::scope(|scope| {
+            for thread in 0..8 {
+                let atomic = &atomic;
+                scope.spawn(move || {
+                    for _ in 0..1000 {
+                        atomic.fetch_add(FixedIntFloat::new(thread), SeqCst);
+                        atomic.checked_fetch_add(FixedIntFloat::new(1), SeqCst);
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.into_inner().to_string(), "36.000");
+    }
+}
@@ -0,0 +1,100 @@
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use num_traits::{
+    CheckedNeg, CheckedRem, Num, PrimInt, SaturatingMul, Signed, WrappingAdd, WrappingMul,
+    WrappingNeg, WrappingSub,
+};
+use std::fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex};
+use std::hash::Hash;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Signed primitive integer that can be used as the base of an IntFloat: i8, i16, i32, i64, i128
+/// or isize. This trait is sealed, so it can't be implemented outside of this crate.
+pub trait BaseInt:
+    PrimInt
+    + Signed
+    + Num<FromStrRadixErr = ParseIntError>
+    + FromStr<Err = ParseIntError>
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Binary
+    + Octal
+    + LowerHex
+    + UpperHex
+    + CheckedNeg
+    + CheckedRem
+    + SaturatingMul
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+    + WrappingNeg
+    + OverflowingAdd
+    + OverflowingSub
+    + OverflowingMul
+    + private::Sealed
+{
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    #[doc(hidden)]
+    const ONE: Self;
+
+    #[doc(hidden)]
+    const TEN: Self;
+
+    #[doc(hidden)]
+    const MIN: Self;
+
+    #[doc(hidden)]
+    const MAX: Self;
+
+    /// The number of decimal digits that always fit, like `f64::DIGITS`: 18 for an i64.
+    #[doc(hidden)]
+    const DIGITS: u32;
+
+    #[doc(hidden)]
+    fn unsigned_abs(self) -> u128;
+
+    #[doc(hidden)]
+    fn overflowing_pow(self, exp: u32) -> (Self, bool);
+
+    #[doc(hidden)]
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! impl_base_int {
+    ($($int:ident),*) => {
+        $(
+            impl private::Sealed for $int {}
+
+            impl BaseInt for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TEN: Self = 10;
+                const MIN: Self = $int::MIN;
+                const MAX: Self = $int::MAX;
+                const DIGITS: u32 = $int::MAX.ilog10();
+
+                fn unsigned_abs(self) -> u128 {
+                    $int::unsigned_abs(self) as u128
+                }
+
+                fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                    $int::overflowing_pow(self, exp)
+                }
+
+                fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                    $int::overflowing_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_base_int!(i8, i16, i32, i64, i128, isize);
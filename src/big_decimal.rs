@@ -0,0 +1,166 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+use num_traits::ToPrimitive;
+
+/// Lossless conversion to a bigdecimal BigDecimal, with the same number of decimals.
+///
+/// # Examples
+///
+/// ```
+/// use bigdecimal::BigDecimal;
+/// use intfloat::IntFloat;
+/// let price = IntFloat::new(1250, 2);
+/// assert_eq!(BigDecimal::from(price).to_string(), "12.50");
+/// ```
+impl<T: BaseInt> From<IntFloat<T>> for BigDecimal {
+    fn from(value: IntFloat<T>) -> Self {
+        let base = value.base.to_i128().expect("every base fits in an i128");
+        BigDecimal::new(BigInt::from(base), value.pow as i64)
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Conversion that keeps the scale of the BigDecimal, or None if its digits don't fit.
+    fn from_bigdecimal_exact(value: &BigDecimal) -> Option<Self> {
+        let (digits, scale) = value.as_bigint_and_scale();
+        let base = digits.to_i128().and_then(num_traits::cast)?;
+        Some(IntFloat::new(base, isize::try_from(scale).ok()?))
+    }
+
+    /// Convert a BigDecimal to exactly `decimals` decimals, rounding half away from zero. Fails if
+    /// the rounded value doesn't fit in the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bigdecimal::BigDecimal;
+    /// use intfloat::IntFloat;
+    /// use std::str::FromStr;
+    /// let numeric = BigDecimal::from_str("12.345").unwrap();
+    /// assert_eq!(IntFloat::try_from_bigdecimal(&numeric, 2), Ok(IntFloat::new(1235, 2)));
+    /// assert_eq!(IntFloat::try_from_bigdecimal(&numeric, 4), Ok(IntFloat::new(123450, 4)));
+    /// ```
+    pub fn try_from_bigdecimal(
+        value: &BigDecimal,
+        decimals: isize,
+    ) -> Result<Self, OutOfRangeError> {
+        let rounded = value.with_scale_round(decimals as i64, bigdecimal::RoundingMode::HalfUp);
+        Self::from_bigdecimal_exact(&rounded).ok_or(OutOfRangeError)
+    }
+}
+
+/// Exact conversion from a bigdecimal BigDecimal, keeping its number of decimals where the base
+/// allows. Fails if the value doesn't fit in the base, even without trailing zeros; use
+/// `IntFloat::try_from_bigdecimal` to round it first.
+///
+/// # Examples
+///
+/// ```
+/// use bigdecimal::BigDecimal;
+/// use intfloat::IntFloat;
+/// use std::str::FromStr;
+/// let numeric = BigDecimal::from_str("12.50").unwrap();
+/// assert_eq!(IntFloat::try_from(&numeric), Ok(IntFloat::new(1250, 2)));
+/// ```
+impl<T: BaseInt> TryFrom<&BigDecimal> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        Self::from_bigdecimal_exact(value)
+            .or_else(|| Self::from_bigdecimal_exact(&value.normalized()))
+            .ok_or(OutOfRangeError)
+    }
+}
+
+impl<T: BaseInt> TryFrom<BigDecimal> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use std::str::FromStr;
+
+    fn big(str: &str) -> BigDecimal {
+        BigDecimal::from_str(str).unwrap()
+    }
+
+    #[test]
+    fn test_to_bigdecimal() {
+        assert_eq!(
+            BigDecimal::from(IntFloat::new(1250, 2)).to_string(),
+            "12.50"
+        );
+        assert_eq!(BigDecimal::from(IntFloat::new(-5, -3)), big("-5000"));
+        assert_eq!(BigDecimal::from(IntFloat::new(1, 40)), big("1e-40"));
+        assert_eq!(
+            BigDecimal::from(IntFloat128::new(i128::MIN, 0)),
+            big("-170141183460469231731687303715884105728")
+        );
+    }
+
+    #[test]
+    fn test_from_bigdecimal() {
+        assert_eq!(IntFloat::try_from(big("12.50")), Ok(IntFloat::new(1250, 2)));
+        assert_eq!(IntFloat::try_from(big("-1e20")), Ok(IntFloat::new(-1, -20)));
+        assert_eq!(
+            IntFloat::try_from(big("1.00000000000000000000000")),
+            Ok(IntFloat::new(1, 0))
+        );
+        assert_eq!(
+            IntFloat32::try_from(big("1e10")),
+            Ok(IntFloat32::new(1, -10))
+        );
+        assert_eq!(
+            IntFloat::try_from(big("1.0000000000000000000000001")),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_try_from_bigdecimal() {
+        let numeric = big("-12.345");
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&numeric, 2),
+            Ok(IntFloat::new(-1235, 2))
+        );
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&numeric, 0),
+            Ok(IntFloat::new(-12, 0))
+        );
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&numeric, -1),
+            Ok(IntFloat::new(-1, -1))
+        );
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&numeric, 5),
+            Ok(IntFloat::new(-1234500, 5))
+        );
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&big("1.0000000000000000000000001"), 18),
+            Ok(IntFloat::new(1000000000000000000, 18))
+        );
+        assert_eq!(
+            IntFloat::try_from_bigdecimal(&numeric, 18),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            IntFloat::new(1234, 2),
+            IntFloat::new(isize::MIN, 40),
+            IntFloat::new(7, -5),
+        ] {
+            assert_eq!(IntFloat::try_from(BigDecimal::from(value)), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use crate::intfloat::{place_point, IntFloat};
+use crate::BaseInt;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+#[derive(Clone, Default, Debug)]
+/// Sibling of IntFloat with an arbitrary-precision num_bigint::BigInt as base, so that
+/// intermediate results can never overflow. The number is a x 10^-b, just like IntFloat.
+///
+/// Conversion from IntFloat is always lossless. Converting back with `to_intfloat` only succeeds if
+/// the value fits exactly; use `round_dp` first to bring it down to the wanted number of decimals.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{BigIntFloat, IntFloat};
+/// let a = BigIntFloat::from(IntFloat::new(isize::MAX, 4));
+/// let product = &a * &a;
+/// assert_eq!(product.to_intfloat::<isize>(), None);
+///
+/// let quotient = (product / a).round_dp(4);
+/// assert_eq!(quotient.to_intfloat(), Some(IntFloat::new(isize::MAX, 4)));
+/// ```
+pub struct BigIntFloat {
+    base: BigInt,
+    pow: isize,
+}
+
+impl BigIntFloat {
+    pub fn new(base: impl Into<BigInt>, pow: isize) -> BigIntFloat {
+        BigIntFloat {
+            base: base.into(),
+            pow,
+        }
+    }
+
+    /// Multiply `base` by 10^`diff`.
+    fn rescale(base: &BigInt, diff: isize) -> BigInt {
+        base * num_traits::pow(BigInt::from(10), diff as usize)
+    }
+
+    /// Bring both bases to the largest of both powers.
+    fn align(&self, rhs: &BigIntFloat) -> (BigInt, BigInt, isize) {
+        if rhs.pow > self.pow {
+            (
+                Self::rescale(&self.base, rhs.pow - self.pow),
+                rhs.base.clone(),
+                rhs.pow,
+            )
+        } else {
+            (
+                self.base.clone(),
+                Self::rescale(&rhs.base, self.pow - rhs.pow),
+                self.pow,
+            )
+        }
+    }
+
+    /// Strip trailing zeros from the base, so that all equal values share one representation.
+    fn canonical(&self) -> (BigInt, isize) {
+        if self.base.is_zero() {
+            return (BigInt::zero(), 0);
+        }
+        let ten = BigInt::from(10);
+        let (mut base, mut pow) = (self.base.clone(), self.pow);
+        while (&base % &ten).is_zero() {
+            base /= &ten;
+            pow -= 1;
+        }
+        (base, pow)
+    }
+
+    /// Round to `decimals` decimals, half away from zero. Values that already have at most that
+    /// many decimals are returned unchanged.
+    pub fn round_dp(&self, decimals: isize) -> BigIntFloat {
+        if self.pow <= decimals {
+            return self.clone();
+        }
+        let divisor = num_traits::pow(BigInt::from(10), (self.pow - decimals) as usize);
+        let mut base = &self.base / &divisor;
+        let remainder = &self.base % &divisor;
+        if remainder.abs() * 2 >= divisor {
+            base += self.base.signum();
+        }
+        BigIntFloat {
+            base,
+            pow: decimals,
+        }
+    }
+
+    /// Convert to an IntFloat with base type `T`, or None if the value can't be represented
+    /// exactly. Trailing zeros are dropped when needed to make the base fit.
+    pub fn to_intfloat<T: BaseInt>(&self) -> Option<IntFloat<T>> {
+        if let Some(base) = self.base.to_i128().and_then(num_traits::cast) {
+            return Some(IntFloat::new(base, self.pow));
+        }
+        let (base, pow) = self.canonical();
+        Some(IntFloat::new(
+            base.to_i128().and_then(num_traits::cast)?,
+            pow,
+        ))
+    }
+}
+
+impl<T: BaseInt> From<IntFloat<T>> for BigIntFloat {
+    fn from(value: IntFloat<T>) -> BigIntFloat {
+        let base = BigInt::from(value.base.unsigned_abs());
+        BigIntFloat {
+            base: if value.base.is_negative() {
+                -base
+            } else {
+                base
+            },
+            pow: value.pow,
+        }
+    }
+}
+
+impl From<BigInt> for BigIntFloat {
+    fn from(base: BigInt) -> BigIntFloat {
+        BigIntFloat { base, pow: 0 }
+    }
+}
+
+impl Display for BigIntFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rounded = match f.precision() {
+            Some(precision) => self.round_dp(precision as isize),
+            None => self.clone(),
+        };
+        let printed = place_point(
+            rounded.base.magnitude().to_string(),
+            rounded.base.is_zero(),
+            rounded.pow,
+            f.precision(),
+        );
+        f.pad_integral(!rounded.base.is_negative(), "", &printed)
+    }
+}
+
+impl Add<&BigIntFloat> for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn add(self, rhs: &BigIntFloat) -> BigIntFloat {
+        let (lhs_base, rhs_base, pow) = self.align(rhs);
+        BigIntFloat {
+            base: lhs_base + rhs_base,
+            pow,
+        }
+    }
+}
+
+impl Add for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn add(self, rhs: BigIntFloat) -> BigIntFloat {
+        &self + &rhs
+    }
+}
+
+impl AddAssign for BigIntFloat {
+    fn add_assign(&mut self, rhs: BigIntFloat) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl Neg for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn neg(self) -> BigIntFloat {
+        BigIntFloat {
+            base: -&self.base,
+            pow: self.pow,
+        }
+    }
+}
+
+impl Neg for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn neg(self) -> BigIntFloat {
+        BigIntFloat {
+            base: -self.base,
+            pow: self.pow,
+        }
+    }
+}
+
+impl Sub<&BigIntFloat> for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn sub(self, rhs: &BigIntFloat) -> BigIntFloat {
+        self + &-rhs
+    }
+}
+
+impl Sub for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn sub(self, rhs: BigIntFloat) -> BigIntFloat {
+        &self - &rhs
+    }
+}
+
+impl SubAssign for BigIntFloat {
+    fn sub_assign(&mut self, rhs: BigIntFloat) {
+        *self = &*self - &rhs;
+    }
+}
+
+impl Mul<&BigIntFloat> for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn mul(self, rhs: &BigIntFloat) -> BigIntFloat {
+        BigIntFloat {
+            base: &self.base * &rhs.base,
+            pow: self.pow + rhs.pow,
+        }
+    }
+}
+
+impl Mul for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn mul(self, rhs: BigIntFloat) -> BigIntFloat {
+        &self * &rhs
+    }
+}
+
+impl MulAssign for BigIntFloat {
+    fn mul_assign(&mut self, rhs: BigIntFloat) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl Div<&BigIntFloat> for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn div(self, rhs: &BigIntFloat) -> BigIntFloat {
+        BigIntFloat {
+            base: &self.base / &rhs.base,
+            pow: self.pow - rhs.pow,
+        }
+    }
+}
+
+impl Div for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn div(self, rhs: BigIntFloat) -> BigIntFloat {
+        &self / &rhs
+    }
+}
+
+impl Rem<&BigIntFloat> for &BigIntFloat {
+    type Output = BigIntFloat;
+
+    /// The exact remainder at the largest scale of both operands, with the sign of `self`.
+    fn rem(self, rhs: &BigIntFloat) -> BigIntFloat {
+        let (lhs_base, rhs_base, pow) = self.align(rhs);
+        BigIntFloat {
+            base: lhs_base % rhs_base,
+            pow,
+        }
+    }
+}
+
+impl Rem for BigIntFloat {
+    type Output = BigIntFloat;
+
+    fn rem(self, rhs: BigIntFloat) -> BigIntFloat {
+        &self % &rhs
+    }
+}
+
+impl Zero for BigIntFloat {
+    fn zero() -> Self {
+        BigIntFloat::new(0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.base.is_zero()
+    }
+}
+
+impl One for BigIntFloat {
+    fn one() -> Self {
+        BigIntFloat::new(1, 0)
+    }
+}
+
+impl PartialEq for BigIntFloat {
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs_base, rhs_base, _) = self.align(other);
+        lhs_base == rhs_base
+    }
+}
+
+impl Eq for BigIntFloat {}
+
+impl Hash for BigIntFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+impl PartialOrd for BigIntFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigIntFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (lhs_base, rhs_base, _) = self.align(other);
+        lhs_base.cmp(&rhs_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_arithmetic() {
+        let this = BigIntFloat::new(534, 2);
+        let that = BigIntFloat::new(1, 0);
+        assert_eq!(&this + &that, BigIntFloat::new(634, 2));
+        assert_eq!(&this - &that, BigIntFloat::new(434, 2));
+        assert_eq!(&this * &that, this);
+        assert_eq!(&this / &BigIntFloat::new(2, 0), BigIntFloat::new(267, 2));
+        assert_eq!(-this.clone(), BigIntFloat::new(-534, 2));
+        assert_eq!(
+            BigIntFloat::new(333, 1) % BigIntFloat::new(10, 0),
+            BigIntFloat::new(33, 1)
+        );
+        assert_eq!(
+            BigIntFloat::new(-75, 1) % BigIntFloat::new(2, 0),
+            BigIntFloat::new(-15, 1)
+        );
+
+        let mut total = BigIntFloat::zero();
+        total += this.clone();
+        total -= that.clone();
+        total *= BigIntFloat::new(2, 0);
+        assert_eq!(total, BigIntFloat::new(868, 2));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let this = BigIntFloat::from(IntFloat128::new(i128::MAX, 10));
+        let square = &this * &this;
+        assert_eq!(square.to_intfloat::<i128>(), None);
+        assert_eq!(
+            (square / this).round_dp(10).to_intfloat(),
+            Some(IntFloat128::new(i128::MAX, 10))
+        );
+    }
+
+    #[test]
+    fn test_round_dp() {
+        let this = BigIntFloat::new(12345, 3);
+        assert_eq!(this.round_dp(2), BigIntFloat::new(1235, 2));
+        assert_eq!((-this.clone()).round_dp(2), BigIntFloat::new(-1235, 2));
+        assert_eq!(this.round_dp(0), BigIntFloat::new(12, 0));
+        assert_eq!(this.round_dp(5).to_string(), "12.345");
+    }
+
+    #[test]
+    fn test_conversion() {
+        let this = IntFloat::new(-534, 2);
+        assert_eq!(BigIntFloat::from(this).to_intfloat(), Some(this));
+        assert_eq!(
+            BigIntFloat::from(IntFloat::new(isize::MIN, 0)).to_intfloat(),
+            Some(IntFloat::new(isize::MIN, 0))
+        );
+        assert_eq!(
+            BigIntFloat::from(IntFloat128::new(i128::MAX, 0)).to_intfloat::<isize>(),
+            None
+        );
+        let big = BigIntFloat::from(IntFloat128::new(5 * 10_i128.pow(30), 30));
+        assert_eq!(big.to_intfloat(), Some(IntFloat::new(5, 0)));
+        assert_eq!(BigIntFloat::from(BigInt::from(7)), BigIntFloat::new(7, 0));
+    }
+
+    #[test]
+    fn test_eq_ord_hash() {
+        let this = BigIntFloat::new(5, 0);
+        let that = BigIntFloat::new(500, 2);
+        assert_eq!(this, that);
+        assert!(BigIntFloat::new(51, 1) > that);
+        assert!(BigIntFloat::new(-5, -2) < that);
+
+        let set: HashSet<BigIntFloat> = [this, that, BigIntFloat::new(0, 3), BigIntFloat::zero()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigIntFloat::new(-534, 2).to_string(), "-5.34");
+        assert_eq!(BigIntFloat::new(5, -2).to_string(), "500");
+        assert_eq!(BigIntFloat::new(5, 3).to_string(), "0.005");
+        assert_eq!(format!("{:.1}", BigIntFloat::new(-1250, 3)), "-1.3");
+        assert_eq!(format!("{:+08.3}", BigIntFloat::new(125, 2)), "+001.250");
+        let this = BigIntFloat::from(IntFloat128::new(i128::MAX, 0));
+        assert_eq!(
+            (&this * &this).to_string(),
+            (BigInt::from(i128::MAX).pow(2)).to_string()
+        );
+    }
+}
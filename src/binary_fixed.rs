@@ -0,0 +1,373 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError, RoundingMode};
+use ::fixed::traits::Fixed;
+use ::fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use ::fixed::{FixedI128, FixedI16, FixedI32, FixedI64, FixedI8};
+use ::fixed::{FixedU128, FixedU16, FixedU32, FixedU64, FixedU8};
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// Beyond this power of ten a nonzero value of at most 128 bits either overflows or rounds the
+/// same way as for any larger power, so larger powers are clamped to it.
+const MAX_SHIFT: isize = 200;
+
+fn pow10(exponent: isize) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent.max(0) as usize)
+}
+
+/// The sign, the magnitude of the bits and the number of fractional bits of a fixed-point number.
+fn fixed_parts<F: Fixed>(value: F) -> (bool, u128, u32) {
+    let bits = value.to_bits();
+    match TryInto::<i128>::try_into(bits) {
+        Ok(bits) => (bits < 0, bits.unsigned_abs(), F::FRAC_NBITS),
+        Err(_) => {
+            let bits: u128 = bits.try_into().ok().expect("all bits fit in a u128");
+            (false, bits, F::FRAC_NBITS)
+        }
+    }
+}
+
+/// Divide the non-negative `numerator` by the positive `denominator`, rounded with `mode`. Also
+/// returns whether the division was exact.
+fn div_round(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    mode: RoundingMode,
+    is_negative: bool,
+) -> (BigInt, bool) {
+    let quotient = numerator / denominator;
+    let remainder = numerator - &quotient * denominator;
+    let half = (&remainder * 2_u32).cmp(denominator);
+    let is_exact = remainder.is_zero();
+    let away = mode.rounds_away(quotient.bit(0), is_exact, half, is_negative);
+    (quotient + u8::from(away), is_exact)
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Convert a binary fixed-point number from the fixed crate to exactly `decimals` decimals,
+    /// rounded with `mode`. The rounding is done in a single step on the exact value, so there's
+    /// no double rounding like when going through a float. Returns None if the result doesn't fit
+    /// in the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed::types::I32F32;
+    /// use intfloat::{IntFloat, RoundingMode};
+    /// let gain = I32F32::from_num(1.375);
+    /// let decimal = IntFloat::from_binary_fixed(gain, 2, RoundingMode::HalfEven);
+    /// assert_eq!(decimal, Some(IntFloat::new(138, 2)));
+    /// let decimal = IntFloat::from_binary_fixed(gain, 2, RoundingMode::TowardZero);
+    /// assert_eq!(decimal, Some(IntFloat::new(137, 2)));
+    /// ```
+    ///
+    /// There are also exact `TryFrom` conversions in both directions, which fail if the value
+    /// can't be represented exactly. The conversion to IntFloat uses as few decimals as possible.
+    ///
+    /// ```
+    /// use fixed::types::{I16F16, I32F32};
+    /// use intfloat::IntFloat;
+    /// let sample = I32F32::from_num(-0.8125);
+    /// assert_eq!(IntFloat::try_from(sample), Ok(IntFloat::new(-8125, 4)));
+    /// assert_eq!(I16F16::try_from(IntFloat::new(125, 3)), Ok(I16F16::from_num(0.125)));
+    /// assert!(I16F16::try_from(IntFloat::new(1, 1)).is_err());
+    /// ```
+    pub fn from_binary_fixed<F: Fixed>(
+        value: F,
+        decimals: isize,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let (is_negative, magnitude, frac) = fixed_parts(value);
+        if magnitude == 0 {
+            return Some(IntFloat::new(T::zero(), decimals));
+        }
+        if decimals > MAX_SHIFT {
+            return None;
+        }
+        let decimals_used = decimals.max(-MAX_SHIFT);
+        let numerator = BigInt::from(magnitude) * pow10(decimals_used);
+        let denominator = (BigInt::one() << frac) * pow10(-decimals_used);
+        let (magnitude, _) = div_round(&numerator, &denominator, mode, is_negative);
+        let base = if is_negative { -magnitude } else { magnitude };
+        let base = base.to_i128().and_then(num_traits::cast)?;
+        Some(IntFloat::new(base, decimals))
+    }
+
+    /// Convert to a binary fixed-point type of the fixed crate, rounded with `mode` to its number
+    /// of fractional bits. Returns None if the result doesn't fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed::types::{I32F32, U8F8};
+    /// use intfloat::{IntFloat, RoundingMode};
+    /// let value = IntFloat::new(1, 1);
+    /// let fixed: Option<U8F8> = value.to_binary_fixed(RoundingMode::Floor);
+    /// assert_eq!(fixed, Some(U8F8::from_bits(25)));
+    /// let fixed: Option<U8F8> = value.to_binary_fixed(RoundingMode::Ceiling);
+    /// assert_eq!(fixed, Some(U8F8::from_bits(26)));
+    /// let fixed: Option<I32F32> = IntFloat::new(-525, 2).to_binary_fixed(RoundingMode::HalfUp);
+    /// assert_eq!(fixed, Some(I32F32::from_num(-5.25)));
+    /// ```
+    pub fn to_binary_fixed<F: Fixed>(self, mode: RoundingMode) -> Option<F> {
+        let (bits, _) = self.binary_bits(F::FRAC_NBITS, mode)?;
+        let bits = match bits.to_i128() {
+            Some(bits) => bits.try_into().ok()?,
+            None => bits.to_u128()?.try_into().ok()?,
+        };
+        Some(F::from_bits(bits))
+    }
+
+    /// The value times 2^`frac`, rounded with `mode`, and whether that was exact. None for values
+    /// that are far too large for any fixed-point type.
+    fn binary_bits(self, frac: u32, mode: RoundingMode) -> Option<(BigInt, bool)> {
+        if self.base.is_zero() {
+            return Some((BigInt::zero(), true));
+        }
+        if self.pow < -MAX_SHIFT {
+            return None;
+        }
+        let is_negative = self.base.is_negative();
+        let pow = self.pow.min(MAX_SHIFT);
+        let numerator = (BigInt::from(self.base.unsigned_abs()) << frac) * pow10(-pow);
+        let (magnitude, is_exact) = div_round(&numerator, &pow10(pow), mode, is_negative);
+        Some((if is_negative { -magnitude } else { magnitude }, is_exact))
+    }
+
+    /// Exact conversion from a fixed-point number, with as few decimals as possible.
+    fn from_binary_fixed_exact<F: Fixed>(value: F) -> Option<Self> {
+        let (_, magnitude, frac) = fixed_parts(value);
+        let decimals = frac - magnitude.trailing_zeros().min(frac);
+        Self::from_binary_fixed(value, decimals as isize, RoundingMode::TowardZero)
+    }
+
+    fn to_binary_fixed_exact<F: Fixed>(self) -> Option<F> {
+        match self.binary_bits(F::FRAC_NBITS, RoundingMode::TowardZero)? {
+            (_, true) => self.to_binary_fixed(RoundingMode::TowardZero),
+            (_, false) => None,
+        }
+    }
+}
+
+// Exact conversions between IntFloat and the binary fixed-point types of the fixed crate. A
+// fixed-point number with f fractional bits needs up to f decimals, so the conversion from it fails
+// if the digits don't fit in the base. The conversion to it fails if the value isn't a multiple of
+// 2^-f or is out of range.
+macro_rules! impl_binary_fixed {
+    ($($fixed:ident: $frac:ident),*) => {
+        $(
+            impl<T: BaseInt, Frac: $frac> TryFrom<$fixed<Frac>> for IntFloat<T> {
+                type Error = OutOfRangeError;
+
+                fn try_from(value: $fixed<Frac>) -> Result<Self, Self::Error> {
+                    Self::from_binary_fixed_exact(value).ok_or(OutOfRangeError)
+                }
+            }
+
+            impl<T: BaseInt, Frac: $frac> TryFrom<IntFloat<T>> for $fixed<Frac> {
+                type Error = OutOfRangeError;
+
+                fn try_from(value: IntFloat<T>) -> Result<Self, Self::Error> {
+                    value.to_binary_fixed_exact().ok_or(OutOfRangeError)
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_fixed!(
+    FixedI8: LeEqU8,
+    FixedI16: LeEqU16,
+    FixedI32: LeEqU32,
+    FixedI64: LeEqU64,
+    FixedI128: LeEqU128,
+    FixedU8: LeEqU8,
+    FixedU16: LeEqU16,
+    FixedU32: LeEqU32,
+    FixedU64: LeEqU64,
+    FixedU128: LeEqU128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use ::fixed::types::{I0F8, I1F127, I32F32, I64F64, U0F128, U16F16, U8F8};
+
+    #[test]
+    fn test_from_binary_fixed() {
+        let value = I32F32::from_num(-1.375);
+        let convert = |decimals, mode| IntFloat::from_binary_fixed(value, decimals, mode);
+        assert_eq!(
+            convert(2, RoundingMode::HalfUp),
+            Some(IntFloat::new(-138, 2))
+        );
+        assert_eq!(
+            convert(2, RoundingMode::HalfDown),
+            Some(IntFloat::new(-137, 2))
+        );
+        assert_eq!(
+            convert(2, RoundingMode::HalfEven),
+            Some(IntFloat::new(-138, 2))
+        );
+        assert_eq!(
+            convert(2, RoundingMode::Floor),
+            Some(IntFloat::new(-138, 2))
+        );
+        assert_eq!(
+            convert(2, RoundingMode::Ceiling),
+            Some(IntFloat::new(-137, 2))
+        );
+        assert_eq!(
+            convert(5, RoundingMode::Ceiling),
+            Some(IntFloat::new(-137500, 5))
+        );
+        assert_eq!(
+            convert(-1, RoundingMode::AwayFromZero),
+            Some(IntFloat::new(-1, -1))
+        );
+        assert_eq!(
+            convert(-1000, RoundingMode::AwayFromZero),
+            Some(IntFloat::new(-1, -1000))
+        );
+        assert_eq!(
+            convert(-1000, RoundingMode::HalfUp),
+            Some(IntFloat::new(0, -1000))
+        );
+        assert_eq!(
+            convert(18, RoundingMode::HalfUp),
+            Some(IntFloat::new(-1375 * 10_isize.pow(15), 18))
+        );
+        assert_eq!(convert(19, RoundingMode::HalfUp), None);
+        assert_eq!(convert(isize::MAX, RoundingMode::HalfUp), None);
+
+        // 2^-32 has 32 significant decimals.
+        let epsilon = I32F32::from_bits(1);
+        assert_eq!(
+            IntFloat::from_binary_fixed(epsilon, 18, RoundingMode::HalfUp),
+            Some(IntFloat::new(232830644, 18))
+        );
+        assert_eq!(
+            IntFloat128::from_binary_fixed(epsilon, 32, RoundingMode::HalfUp),
+            Some(IntFloat128::new(23283064365386962890625, 32))
+        );
+        assert_eq!(
+            IntFloat32::from_binary_fixed(U0F128::MAX, 9, RoundingMode::TowardZero),
+            Some(IntFloat32::new(999999999, 9))
+        );
+        assert_eq!(
+            IntFloat32::from_binary_fixed(U0F128::MAX, 9, RoundingMode::HalfUp),
+            Some(IntFloat32::new(1000000000, 9))
+        );
+        assert_eq!(
+            IntFloat::from_binary_fixed(I32F32::ZERO, isize::MAX, RoundingMode::HalfUp),
+            Some(IntFloat::new(0, isize::MAX))
+        );
+    }
+
+    #[test]
+    fn test_to_binary_fixed() {
+        let tenth = IntFloat::new(1, 1);
+        assert_eq!(
+            tenth.to_binary_fixed(RoundingMode::HalfUp),
+            Some(U8F8::from_bits(26))
+        );
+        assert_eq!(
+            tenth.to_binary_fixed(RoundingMode::TowardZero),
+            Some(U8F8::from_bits(25))
+        );
+        assert_eq!(
+            (-tenth).to_binary_fixed(RoundingMode::Floor),
+            Some(I32F32::from_bits(-429496730))
+        );
+        assert_eq!(
+            (-tenth).to_binary_fixed(RoundingMode::Ceiling),
+            Some(I32F32::from_bits(-429496729))
+        );
+        assert_eq!(
+            (-tenth).to_binary_fixed::<U8F8>(RoundingMode::Ceiling),
+            None
+        );
+
+        // 1.5 / 256 is halfway between 1 / 256 and 2 / 256.
+        let half_step = IntFloat::new(5859375, 9);
+        assert_eq!(
+            half_step.to_binary_fixed(RoundingMode::HalfEven),
+            Some(U8F8::from_bits(2))
+        );
+        assert_eq!(
+            half_step.to_binary_fixed(RoundingMode::HalfDown),
+            Some(U8F8::from_bits(1))
+        );
+
+        assert_eq!(
+            IntFloat::new(256, 0).to_binary_fixed::<U8F8>(RoundingMode::HalfUp),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(25599, 2).to_binary_fixed(RoundingMode::TowardZero),
+            Some(U8F8::from_bits(65533))
+        );
+        assert_eq!(
+            IntFloat::new(1, -18).to_binary_fixed(RoundingMode::HalfUp),
+            Some(I64F64::from_num(1_000_000_000_000_000_000_u64))
+        );
+        assert_eq!(
+            IntFloat::new(-1, 0).to_binary_fixed(RoundingMode::HalfUp),
+            Some(I1F127::NEG_ONE)
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MAX).to_binary_fixed(RoundingMode::AwayFromZero),
+            Some(I0F8::from_bits(1))
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MIN).to_binary_fixed::<U0F128>(RoundingMode::HalfUp),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(0, isize::MIN).to_binary_fixed(RoundingMode::HalfUp),
+            Some(I0F8::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            IntFloat::try_from(I32F32::from_num(1.5)),
+            Ok(IntFloat::new(15, 1))
+        );
+        assert_eq!(
+            IntFloat::try_from(I32F32::from_num(-3)),
+            Ok(IntFloat::new(-3, 0))
+        );
+        assert_eq!(IntFloat::try_from(I32F32::ZERO), Ok(IntFloat::new(0, 0)));
+        assert_eq!(IntFloat::try_from(I1F127::MIN), Ok(IntFloat::new(-1, 0)));
+        assert_eq!(
+            IntFloat::try_from(I32F32::from_bits(1)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            IntFloat128::try_from(I32F32::from_bits(1)),
+            Ok(IntFloat128::new(23283064365386962890625, 32))
+        );
+        assert_eq!(IntFloat32::try_from(U16F16::MAX), Err(OutOfRangeError));
+
+        assert_eq!(
+            U8F8::try_from(IntFloat::new(125, 3)),
+            Ok(U8F8::from_num(0.125))
+        );
+        assert_eq!(U8F8::try_from(IntFloat::new(1, 1)), Err(OutOfRangeError));
+        assert_eq!(U8F8::try_from(IntFloat::new(-1, 0)), Err(OutOfRangeError));
+        assert_eq!(U8F8::try_from(IntFloat::new(256, 0)), Err(OutOfRangeError));
+        assert_eq!(I1F127::try_from(IntFloat::new(-1, 0)), Ok(I1F127::NEG_ONE));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for bits in [0, 1, -1, 12345678901, -123456789012345, i64::MIN] {
+            let value = I32F32::from_bits(bits);
+            let decimal = IntFloat128::try_from(value).unwrap();
+            assert_eq!(I32F32::try_from(decimal), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use bson::Decimal128;
+
+// A Decimal128 is an IEEE 754 decimal in the binary integer encoding: a sign bit, a 14-bit
+// exponent and a coefficient of at most 34 digits, with the value coefficient x 10^(exponent -
+// BIAS). So the pow of an IntFloat is BIAS - exponent.
+const BIAS: isize = 6176;
+const MAX_EXPONENT: isize = 12287;
+const MAX_COEFFICIENT: u128 = 10_u128.pow(34) - 1;
+const COEFFICIENT_BITS: u32 = 113;
+
+/// Exact conversion to a BSON Decimal128, keeping the number of decimals. Trailing zeros are
+/// dropped or added only when the coefficient or exponent is out of range, so this fails for
+/// values with more than 34 significant digits, or a pow outside -6111..=6176 after that.
+///
+/// # Examples
+///
+/// ```
+/// use bson::Decimal128;
+/// use intfloat::IntFloat;
+/// let price = Decimal128::try_from(IntFloat::new(1250, 2)).unwrap();
+/// assert_eq!(price.to_string(), "12.50");
+/// assert_eq!(IntFloat::try_from(price), Ok(IntFloat::new(1250, 2)));
+/// ```
+impl<T: BaseInt> TryFrom<IntFloat<T>> for Decimal128 {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: IntFloat<T>) -> Result<Self, Self::Error> {
+        let base = value.base.to_i128().ok_or(OutOfRangeError)?;
+        let mut coefficient = base.unsigned_abs();
+        let mut pow = value.pow;
+        if coefficient == 0 {
+            pow = pow.clamp(BIAS - MAX_EXPONENT, BIAS);
+        }
+        while (coefficient > MAX_COEFFICIENT || pow > BIAS) && coefficient % 10 == 0 {
+            coefficient /= 10;
+            pow = pow.checked_sub(1).ok_or(OutOfRangeError)?;
+        }
+        while pow < BIAS - MAX_EXPONENT && coefficient * 10 <= MAX_COEFFICIENT {
+            coefficient *= 10;
+            pow += 1;
+        }
+        if coefficient > MAX_COEFFICIENT || !(BIAS - MAX_EXPONENT..=BIAS).contains(&pow) {
+            return Err(OutOfRangeError);
+        }
+        let sign = ((base < 0) as u128) << 127;
+        let exponent = ((BIAS - pow) as u128) << COEFFICIENT_BITS;
+        Ok(Decimal128::from_bytes(
+            (sign | exponent | coefficient).to_le_bytes(),
+        ))
+    }
+}
+
+/// Exact conversion from a BSON Decimal128, keeping its number of decimals where the base allows.
+/// Fails for NaN and infinity, and if the value doesn't fit in the base, even without trailing
+/// zeros.
+impl<T: BaseInt> TryFrom<Decimal128> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: Decimal128) -> Result<Self, Self::Error> {
+        let bits = u128::from_le_bytes(value.bytes());
+        let negative = bits >> 127 == 1;
+        if (bits >> 122) & 0b11111 >= 0b11110 {
+            return Err(OutOfRangeError);
+        }
+        let (exponent, mut coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            // The coefficient of this form is always too large, which makes it zero.
+            ((bits >> (COEFFICIENT_BITS - 2)) & 0x3fff, 0)
+        } else {
+            let coefficient = bits & ((1 << COEFFICIENT_BITS) - 1);
+            let coefficient = if coefficient > MAX_COEFFICIENT {
+                0
+            } else {
+                coefficient
+            };
+            ((bits >> COEFFICIENT_BITS) & 0x3fff, coefficient)
+        };
+        let mut pow = BIAS - exponent as isize;
+        loop {
+            let base = if negative {
+                -(coefficient as i128)
+            } else {
+                coefficient as i128
+            };
+            if let Some(base) = num_traits::cast(base) {
+                return Ok(IntFloat::new(base, pow));
+            }
+            if coefficient % 10 != 0 {
+                return Err(OutOfRangeError);
+            }
+            coefficient /= 10;
+            pow -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generic, IntFloat, IntFloat128};
+    use std::str::FromStr;
+
+    fn decimal(str: &str) -> Decimal128 {
+        Decimal128::from_str(str).unwrap()
+    }
+
+    #[test]
+    fn test_to_decimal128() {
+        let to = |value: IntFloat| Decimal128::try_from(value).map(|value| value.to_string());
+        assert_eq!(to(IntFloat::new(1250, 2)), Ok("12.50".to_string()));
+        assert_eq!(to(IntFloat::new(-7, 3)), Ok("-0.007".to_string()));
+        assert_eq!(to(IntFloat::new(105, -1)), Ok("1.05E+3".to_string()));
+        assert_eq!(to(IntFloat::new(0, 2)), Ok("0.00".to_string()));
+        assert_eq!(
+            to(IntFloat::new(10, 6177)),
+            Ok(decimal("1E-6176").to_string())
+        );
+        assert_eq!(to(IntFloat::new(11, 6177)), Err(OutOfRangeError));
+        assert_eq!(to(IntFloat::new(1, isize::MIN)), Err(OutOfRangeError));
+        let wide = IntFloat128::new(10_i128.pow(35), 0);
+        assert_eq!(
+            Decimal128::try_from(wide).map(|value| value.to_string()),
+            Ok("1.000000000000000000000000000000000E+35".to_string())
+        );
+        let wide = IntFloat128::new(10_i128.pow(35) + 1, 0);
+        assert_eq!(Decimal128::try_from(wide), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_from_decimal128() {
+        assert_eq!(
+            IntFloat::try_from(decimal("12.50")),
+            Ok(IntFloat::new(1250, 2))
+        );
+        assert_eq!(
+            IntFloat::try_from(decimal("-1.05E+3")).map(|value| value.to_string()),
+            Ok("-1050".to_string())
+        );
+        assert_eq!(
+            generic::IntFloat::<i8>::try_from(decimal("1.000")),
+            Ok(generic::IntFloat::new(10, 1))
+        );
+        assert_eq!(
+            generic::IntFloat::<i8>::try_from(decimal("1.001")),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(IntFloat::try_from(decimal("NaN")), Err(OutOfRangeError));
+        assert_eq!(
+            IntFloat::try_from(decimal("-Infinity")),
+            Err(OutOfRangeError)
+        );
+        let max = decimal("9999999999999999999999999999999999");
+        assert_eq!(
+            IntFloat128::try_from(max),
+            Ok(IntFloat128::new(MAX_COEFFICIENT as i128, 0))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::intfloat::IntFloat;
+use crate::OutOfRangeError;
+
+// Fixed-width binary encoding, for storing IntFloats on disk or sending them over the network.
+// The layout is the base in little-endian two's complement with the width of the base type,
+// followed by the pow as a little-endian i64. The isize base is always stored as an i64, so the
+// encoding doesn't depend on the platform.
+macro_rules! impl_le_bytes {
+    ($($int:ident as $stored:ident: $len:literal),*) => {
+        $(
+            impl IntFloat<$int> {
+                #[doc = concat!(
+                    "Encode as ", stringify!($len), " bytes: the base as a little-endian ",
+                    stringify!($stored), ", followed by the pow as a little-endian i64. The ",
+                    "layout is the same on every platform.\n\n",
+                    "# Examples\n\n",
+                    "```\n",
+                    "use intfloat::generic::IntFloat;\n",
+                    "let value = IntFloat::<", stringify!($int), ">::new(-125, 2);\n",
+                    "let bytes = value.to_le_bytes();\n",
+                    "assert_eq!(bytes.len(), ", stringify!($len), ");\n",
+                    "assert_eq!(IntFloat::<", stringify!($int), ">::from_le_bytes(bytes), Ok(value));\n",
+                    "```"
+                )]
+                pub fn to_le_bytes(self) -> [u8; $len] {
+                    let mut bytes = [0; $len];
+                    let (base, pow) = bytes.split_at_mut($len - 8);
+                    base.copy_from_slice(&(self.base as $stored).to_le_bytes());
+                    pow.copy_from_slice(&(self.pow as i64).to_le_bytes());
+                    bytes
+                }
+
+                /// Decode the bytes of `to_le_bytes`. Fails only if the base or the pow doesn't
+                /// fit in an isize, which can happen on platforms where it is smaller than 64
+                /// bits.
+                pub fn from_le_bytes(bytes: [u8; $len]) -> Result<Self, OutOfRangeError> {
+                    let (base, pow) = bytes.split_at($len - 8);
+                    let base = $stored::from_le_bytes(base.try_into().unwrap());
+                    let pow = i64::from_le_bytes(pow.try_into().unwrap());
+                    Ok(IntFloat {
+                        base: base.try_into().map_err(|_| OutOfRangeError)?,
+                        pow: pow.try_into().map_err(|_| OutOfRangeError)?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_le_bytes!(
+    i8 as i8: 9,
+    i16 as i16: 10,
+    i32 as i32: 12,
+    i64 as i64: 16,
+    i128 as i128: 24,
+    isize as i64: 16
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::generic;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(
+            IntFloat::new(-2, 3).to_le_bytes(),
+            [254, 255, 255, 255, 255, 255, 255, 255, 3, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            IntFloat32::new(0x01020304, -1).to_le_bytes(),
+            [4, 3, 2, 1, 255, 255, 255, 255, 255, 255, 255, 255]
+        );
+        assert_eq!(
+            generic::IntFloat::<i8>::new(-128, 1).to_le_bytes(),
+            [128, 1, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            IntFloat::new(-2, 3).to_le_bytes(),
+            generic::IntFloat::<i64>::new(-2, 3).to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            IntFloat::new(0, 0),
+            IntFloat::new(isize::MIN, isize::MAX),
+            IntFloat::new(isize::MAX, isize::MIN),
+            IntFloat::new(123456789, 4),
+        ] {
+            assert_eq!(IntFloat::from_le_bytes(value.to_le_bytes()), Ok(value));
+        }
+        let wide = IntFloat128::new(i128::MIN + 1, -7);
+        let decoded = IntFloat128::from_le_bytes(wide.to_le_bytes()).unwrap();
+        assert_eq!((decoded.base, decoded.pow), (wide.base, wide.pow));
+        let bytes = IntFloat32::new(-7, 2).to_le_bytes();
+        assert_eq!(
+            IntFloat32::from_le_bytes(bytes).unwrap().to_string(),
+            "-0.07"
+        );
+    }
+}
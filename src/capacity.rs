@@ -0,0 +1,110 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+
+/// Checks before an operation, for aggregations that would rather not panic halfway. The `will_*`
+/// functions tell if the matching operator would overflow (or, for division, fail), without
+/// doing more work than the `checked_*` function they are based on.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// assert_eq!(IntFloat::max_value_at_scale(2).to_string(), "92233720368547758.07");
+/// let total = IntFloat::new(isize::MAX - 100, 2);
+/// assert_eq!(total.headroom(), IntFloat::new(100, 2));
+/// assert!(!total.will_add_overflow(IntFloat::new(1, 0)));
+/// assert!(total.will_add_overflow(IntFloat::new(101, 2)));
+/// assert!(total.will_add_overflow(IntFloat::new(1, 3)));
+/// assert_eq!(IntFloat::new(1250, 2).spare_decimals(), 15);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// The largest value with `pow` decimals: the maximum of the base.
+    pub fn max_value_at_scale(pow: isize) -> Self {
+        IntFloat::new(T::MAX, pow)
+    }
+
+    /// The smallest value with `pow` decimals: the minimum of the base.
+    pub fn min_value_at_scale(pow: isize) -> Self {
+        IntFloat::new(T::MIN, pow)
+    }
+
+    /// The largest value with the same pow that can be added without overflowing. Adding a
+    /// value with more decimals rescales `self` as well, so check that with `will_add_overflow`.
+    pub fn headroom(self) -> Self {
+        IntFloat::new(T::MAX.saturating_sub(self.base), self.pow)
+    }
+
+    /// The largest value with the same pow that can be subtracted without overflowing.
+    pub fn legroom(self) -> Self {
+        IntFloat::new(self.base.saturating_sub(T::MIN), self.pow)
+    }
+
+    /// How many decimals can be added by `with_scale` before the base overflows, e.g. 15 for
+    /// 12.50 with an i64 base. isize::MAX for zero.
+    pub fn spare_decimals(self) -> isize {
+        if self.base.is_zero() {
+            return isize::MAX;
+        }
+        let mut base = self.base;
+        let mut decimals = 0;
+        while let Some(rescaled) = base.checked_mul(&T::TEN) {
+            base = rescaled;
+            decimals += 1;
+        }
+        decimals
+    }
+
+    pub fn will_add_overflow(self, rhs: Self) -> bool {
+        self.checked_add(rhs).is_none()
+    }
+
+    pub fn will_sub_overflow(self, rhs: Self) -> bool {
+        self.checked_sub(rhs).is_none()
+    }
+
+    pub fn will_mul_overflow(self, rhs: Self) -> bool {
+        self.checked_mul(rhs).is_none()
+    }
+
+    /// True for a division by zero as well.
+    pub fn will_div_overflow(self, rhs: Self) -> bool {
+        self.checked_div(rhs).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generic, IntFloat, IntFloat64};
+
+    #[test]
+    fn test_capacity() {
+        let max = IntFloat64::max_value_at_scale(3);
+        assert_eq!((max.base, max.pow), (i64::MAX, 3));
+        let min = generic::IntFloat::<i8>::min_value_at_scale(-2);
+        assert_eq!((min.base, min.pow), (i8::MIN, -2));
+
+        let value = generic::IntFloat::<i8>::new(-100, 1);
+        assert_eq!(value.headroom(), generic::IntFloat::new(127, 1));
+        assert_eq!(value.legroom(), generic::IntFloat::new(28, 1));
+        assert_eq!(max.headroom(), IntFloat64::new(0, 3));
+        assert_eq!(value.spare_decimals(), 0);
+        assert_eq!(generic::IntFloat::<i8>::new(12, 0).spare_decimals(), 1);
+        assert_eq!(IntFloat64::new(-1, 0).spare_decimals(), 18);
+        assert_eq!(IntFloat::new(0, 5).spare_decimals(), isize::MAX);
+    }
+
+    #[test]
+    fn test_will_overflow() {
+        let value = generic::IntFloat::<i8>::new(100, 0);
+        assert!(!value.will_add_overflow(generic::IntFloat::new(27, 0)));
+        assert!(value.will_add_overflow(generic::IntFloat::new(28, 0)));
+        assert!(!value.will_sub_overflow(generic::IntFloat::new(127, 0)));
+        assert!(value.will_sub_overflow(generic::IntFloat::new(1, 1)));
+        assert!(!generic::IntFloat::<i8>::new(-1, 0)
+            .will_sub_overflow(generic::IntFloat::new(i8::MIN, 0)));
+        assert!(value.will_mul_overflow(generic::IntFloat::new(2, 0)));
+        assert!(!value.will_mul_overflow(generic::IntFloat::new(1, 0)));
+        assert!(value.will_div_overflow(generic::IntFloat::new(0, 0)));
+        assert!(!value.will_div_overflow(generic::IntFloat::new(3, 0)));
+    }
+}
@@ -0,0 +1,416 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloatError};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Multiply `base` by 10^`diff`, or None if that doesn't fit in the base. Zero always fits,
+    /// however large `diff` is.
+    pub(crate) fn checked_rescale(base: T, diff: isize) -> Option<T> {
+        if base.is_zero() {
+            return Some(base);
+        }
+        let factor = num_traits::checked_pow(T::TEN, usize::try_from(diff).ok()?)?;
+        base.checked_mul(&factor)
+    }
+}
+
+/// Arithmetic that returns None instead of panicking or silently overflowing when the base (or
+/// the power) doesn't fit in its integer type, or when dividing by zero.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(isize::MAX, 2);
+/// assert_eq!(a.checked_add(IntFloat::new(1, 2)), None);
+/// assert_eq!(a.checked_div(IntFloat::new(0, 0)), None);
+/// assert_eq!(
+///     IntFloat::new(150, 2).checked_mul(IntFloat::new(2, 0)),
+///     Some(IntFloat::new(3, 0))
+/// );
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if rhs.pow > self.pow {
+            Some(IntFloat {
+                base: Self::checked_rescale(self.base, rhs.pow.checked_sub(self.pow)?)?
+                    .checked_add(&rhs.base)?,
+                pow: rhs.pow,
+            })
+        } else {
+            Some(IntFloat {
+                base: Self::checked_rescale(rhs.base, self.pow.checked_sub(rhs.pow)?)?
+                    .checked_add(&self.base)?,
+                pow: self.pow,
+            })
+        }
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lhs_base, rhs_base, pow) = if rhs.pow > self.pow {
+            let base = Self::checked_rescale(self.base, rhs.pow.checked_sub(self.pow)?)?;
+            (base, rhs.base, rhs.pow)
+        } else {
+            let base = Self::checked_rescale(rhs.base, self.pow.checked_sub(rhs.pow)?)?;
+            (self.base, base, self.pow)
+        };
+        Some(IntFloat {
+            base: lhs_base.checked_sub(&rhs_base)?,
+            pow,
+        })
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(IntFloat {
+            base: self.base.checked_mul(&rhs.base)?,
+            pow: self.pow.checked_add(rhs.pow)?,
+        })
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(IntFloat {
+            base: self.base.checked_div(&rhs.base)?,
+            pow: self.pow.checked_sub(rhs.pow)?,
+        })
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let (lhs_base, rhs_base, pow) = if rhs.pow > self.pow {
+            let base = Self::checked_rescale(self.base, rhs.pow.checked_sub(self.pow)?)?;
+            (base, rhs.base, rhs.pow)
+        } else {
+            let base = Self::checked_rescale(rhs.base, self.pow.checked_sub(rhs.pow)?)?;
+            (self.base, base, self.pow)
+        };
+        Some(IntFloat {
+            base: lhs_base.checked_rem(&rhs_base)?,
+            pow,
+        })
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(IntFloat {
+            base: self.base.checked_neg()?,
+            pow: self.pow,
+        })
+    }
+}
+
+/// Arithmetic like the `checked_` versions, but with an IntFloatError that tells why it failed:
+/// dividing by zero, a base that doesn't fit (`Overflow`) or a power that doesn't fit
+/// (`ScaleTooLarge`).
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloatError};
+/// let a = IntFloat::new(isize::MAX, 2);
+/// assert_eq!(a.try_add(IntFloat::new(1, 2)), Err(IntFloatError::Overflow));
+/// assert_eq!(a.try_rem(IntFloat::new(0, 0)), Err(IntFloatError::DivideByZero));
+/// assert_eq!(
+///     IntFloat::new(1, 0).try_div_with_precision(IntFloat::new(3, 0), 2),
+///     Ok(IntFloat::new(33, 2))
+/// );
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn try_add(self, rhs: Self) -> Result<Self, IntFloatError> {
+        self.checked_add(rhs).ok_or(IntFloatError::Overflow)
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, IntFloatError> {
+        self.checked_sub(rhs).ok_or(IntFloatError::Overflow)
+    }
+
+    pub fn try_mul(self, rhs: Self) -> Result<Self, IntFloatError> {
+        let pow = self
+            .pow
+            .checked_add(rhs.pow)
+            .ok_or(IntFloatError::ScaleTooLarge)?;
+        let base = self
+            .base
+            .checked_mul(&rhs.base)
+            .ok_or(IntFloatError::Overflow)?;
+        Ok(IntFloat { base, pow })
+    }
+
+    /// Divide the bases like `/`, truncating their quotient.
+    pub fn try_div(self, rhs: Self) -> Result<Self, IntFloatError> {
+        if rhs.base.is_zero() {
+            return Err(IntFloatError::DivideByZero);
+        }
+        let pow = self
+            .pow
+            .checked_sub(rhs.pow)
+            .ok_or(IntFloatError::ScaleTooLarge)?;
+        let base = self
+            .base
+            .checked_div(&rhs.base)
+            .ok_or(IntFloatError::Overflow)?;
+        Ok(IntFloat { base, pow })
+    }
+
+    pub fn try_rem(self, rhs: Self) -> Result<Self, IntFloatError> {
+        if rhs.base.is_zero() {
+            return Err(IntFloatError::DivideByZero);
+        }
+        self.checked_rem(rhs).ok_or(IntFloatError::Overflow)
+    }
+
+    /// Like `div_with_precision`, rounding half away from zero to `decimals` decimals.
+    pub fn try_div_with_precision(self, rhs: Self, decimals: isize) -> Result<Self, IntFloatError> {
+        if rhs.base.is_zero() {
+            return Err(IntFloatError::DivideByZero);
+        }
+        self.checked_div_with_precision(rhs, decimals)
+            .ok_or(IntFloatError::Overflow)
+    }
+}
+
+impl<T: BaseInt> CheckedAdd for IntFloat<T> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        IntFloat::checked_add(*self, *v)
+    }
+}
+
+impl<T: BaseInt> CheckedSub for IntFloat<T> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        IntFloat::checked_sub(*self, *v)
+    }
+}
+
+impl<T: BaseInt> CheckedMul for IntFloat<T> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        IntFloat::checked_mul(*self, *v)
+    }
+}
+
+impl<T: BaseInt> CheckedDiv for IntFloat<T> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        IntFloat::checked_div(*self, *v)
+    }
+}
+
+impl<T: BaseInt> CheckedRem for IntFloat<T> {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        IntFloat::checked_rem(*self, *v)
+    }
+}
+
+impl<T: BaseInt> CheckedNeg for IntFloat<T> {
+    fn checked_neg(&self) -> Option<Self> {
+        IntFloat::checked_neg(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128};
+
+    #[test]
+    fn test_try_ops() {
+        let max = IntFloat::new(isize::MAX, 0);
+        let one = IntFloat::new(1, 0);
+        let zero = IntFloat::new(0, 3);
+        assert_eq!(one.try_add(IntFloat::new(5, 1)), Ok(IntFloat::new(15, 1)));
+        assert_eq!(max.try_add(one), Err(IntFloatError::Overflow));
+        assert_eq!(
+            IntFloat::new(isize::MIN, 0).try_sub(one),
+            Err(IntFloatError::Overflow)
+        );
+        assert_eq!(
+            max.try_mul(IntFloat::new(2, 0)),
+            Err(IntFloatError::Overflow)
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MAX).try_mul(IntFloat::new(1, 1)),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+        assert_eq!(one.try_div(zero), Err(IntFloatError::DivideByZero));
+        assert_eq!(
+            IntFloat::new(isize::MIN, 0).try_div(IntFloat::new(-1, 0)),
+            Err(IntFloatError::Overflow)
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MIN).try_div(IntFloat::new(1, 1)),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+        assert_eq!(
+            IntFloat::new(75, 1).try_rem(IntFloat::new(2, 0)),
+            Ok(IntFloat::new(15, 1))
+        );
+        assert_eq!(one.try_rem(zero), Err(IntFloatError::DivideByZero));
+        assert_eq!(
+            one.try_div_with_precision(zero, 2),
+            Err(IntFloatError::DivideByZero)
+        );
+        assert_eq!(
+            max.try_div_with_precision(one, 1),
+            Err(IntFloatError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let this = IntFloat::new(534, 0);
+        let that = IntFloat::new(100, 2);
+        assert_eq!(this.checked_add(that), Some(this + that));
+        assert_eq!(that.checked_add(this), Some(this + that));
+
+        let this = IntFloat::new(isize::MAX, 0);
+        assert_eq!(this.checked_add(IntFloat::new(1, 0)), None);
+        assert_eq!(this.checked_add(IntFloat::new(0, 1)), None);
+        assert_eq!(IntFloat::new(1, 0).checked_add(IntFloat::new(1, 19)), None);
+        assert_eq!(
+            IntFloat::new(1, 0).checked_add(IntFloat::new(1, isize::MAX)),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(1, -1).checked_add(IntFloat::new(1, isize::MAX)),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MAX).checked_add(IntFloat::new(1, -1)),
+            None
+        );
+
+        let this = IntFloat::new(0, 0)
+            .checked_add(IntFloat::new(5, 30))
+            .unwrap();
+        assert_eq!((this.base, this.pow), (5, 30));
+        let this = IntFloat::new(5, 30)
+            .checked_add(IntFloat::new(0, -30))
+            .unwrap();
+        assert_eq!((this.base, this.pow), (5, 30));
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let this = IntFloat::new(534, -2);
+        let that = IntFloat::new(1068, -2);
+        assert_eq!(that.checked_sub(this), Some(this));
+
+        let this = IntFloat::new(isize::MIN, 0);
+        assert_eq!(this.checked_sub(IntFloat::new(1, 0)), None);
+        assert_eq!(IntFloat::new(0, 0).checked_sub(this), None);
+        assert_eq!(
+            IntFloat::new(-1, 0).checked_sub(this),
+            Some(IntFloat::new(isize::MAX, 0))
+        );
+        assert_eq!(
+            IntFloat::new(0, 0).checked_sub(IntFloat::new(5, 30)),
+            Some(IntFloat::new(-5, 30))
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_checked_mul() {
+        let this = IntFloat::new(500, 2);
+        assert_eq!(this.checked_mul(this), Some(IntFloat::new(250000, 4)));
+
+        let this = IntFloat::new(3_000_000_000_000, 9);
+        assert_eq!(this.checked_mul(this), None);
+        let this = IntFloat::new(1, isize::MAX);
+        assert_eq!(this.checked_mul(this), None);
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let this = IntFloat::new(500, 2);
+        let that = IntFloat::new(250000, 4);
+        assert_eq!(that.checked_div(this), Some(this));
+        assert_eq!(that.checked_div(IntFloat::new(0, 3)), None);
+        assert_eq!(
+            IntFloat::new(isize::MIN, 0).checked_div(IntFloat::new(-1, 0)),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(1, isize::MIN).checked_div(IntFloat::new(1, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checked_rem() {
+        let this = IntFloat::new(499, -2);
+        let that = IntFloat::new(250000, -4);
+        assert_eq!(that.checked_rem(this), Some(that % this));
+        assert_eq!(that.checked_rem(IntFloat::new(0, 0)), None);
+        assert_eq!(
+            IntFloat::new(-75, 1).checked_rem(IntFloat::new(2, 0)),
+            Some(IntFloat::new(-15, 1))
+        );
+        assert_eq!(
+            IntFloat::new(isize::MIN, 0).checked_rem(IntFloat::new(-1, 0)),
+            None
+        );
+        assert_eq!(IntFloat::new(1, 0).checked_rem(IntFloat::new(1, 19)), None);
+        assert_eq!(
+            IntFloat::new(1, -1).checked_rem(IntFloat::new(1, isize::MAX)),
+            None
+        );
+        let this = IntFloat::new(0, 0)
+            .checked_rem(IntFloat::new(5, 30))
+            .unwrap();
+        assert_eq!((this.base, this.pow), (0, 30));
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        assert_eq!(
+            IntFloat::new(5, 1).checked_neg(),
+            Some(IntFloat::new(-5, 1))
+        );
+        assert_eq!(IntFloat::new(isize::MIN, 1).checked_neg(), None);
+    }
+
+    #[test]
+    fn test_checked_traits() {
+        fn sum_checked<T: CheckedAdd + Copy>(items: &[T]) -> Option<T> {
+            let mut total = items[0];
+            for item in &items[1..] {
+                total = total.checked_add(item)?;
+            }
+            Some(total)
+        }
+
+        let these = [
+            IntFloat::new(1, 1),
+            IntFloat::new(2, 2),
+            IntFloat::new(3, 0),
+        ];
+        assert_eq!(sum_checked(&these), Some(IntFloat::new(312, 2)));
+        let these = [IntFloat::new(isize::MAX, 0), IntFloat::new(1, 0)];
+        assert_eq!(sum_checked(&these), None);
+
+        let this = IntFloat::new(6, 1);
+        let that = IntFloat::new(4, 1);
+        assert_eq!(
+            CheckedSub::checked_sub(&this, &that),
+            Some(IntFloat::new(2, 1))
+        );
+        assert_eq!(
+            CheckedMul::checked_mul(&this, &that),
+            Some(IntFloat::new(24, 2))
+        );
+        assert_eq!(
+            CheckedDiv::checked_div(&this, &that),
+            Some(IntFloat::new(1, 0))
+        );
+        assert_eq!(
+            CheckedRem::checked_rem(&this, &that),
+            Some(IntFloat::new(2, 1))
+        );
+        assert_eq!(CheckedNeg::checked_neg(&this), Some(IntFloat::new(-6, 1)));
+    }
+
+    #[test]
+    fn test_checked_intfloat128() {
+        let this = IntFloat128::new(3_000_000_000_000, 9);
+        assert_eq!(this.checked_mul(this), Some(IntFloat128::new(9_000_000, 0)));
+        let this = IntFloat128::new(i128::MAX, 0);
+        assert_eq!(this.checked_add(IntFloat128::new(1, 0)), None);
+        assert_eq!(this.checked_div(IntFloat128::new(0, 0)), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_complex::Complex;
+
+/// Methods for num_complex::Complex numbers with IntFloat parts. Addition, subtraction,
+/// multiplication, negation, conjugation, parsing and formatting of `Complex<IntFloat>` already
+/// work through the num-traits implementations of IntFloat, and are exact. Division with `/`
+/// truncates like it does for IntFloat itself, so use `div_with_precision` instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{ComplexIntFloat, IntFloat};
+/// use num_complex::Complex;
+/// let a = Complex::new(IntFloat::new(15, 1), IntFloat::new(2, 0));
+/// let b = Complex::new(IntFloat::new(5, 1), IntFloat::new(-1, 0));
+/// assert_eq!((a * b).to_string(), "2.75-0.5i");
+/// assert_eq!(a.norm_sqr().to_string(), "6.25");
+/// assert_eq!(a.div_with_precision(b, 2).to_string(), "-1.00+2.00i");
+/// ```
+pub trait ComplexIntFloat: Sized {
+    /// Divide, rounding both parts of the quotient half away from zero to `decimals` decimals.
+    /// Panics if `rhs` is zero or if the result doesn't fit in the base.
+    fn div_with_precision(self, rhs: Self, decimals: isize) -> Self;
+
+    /// Like `div_with_precision`, but None if `rhs` is zero or the result doesn't fit.
+    fn checked_div_with_precision(self, rhs: Self, decimals: isize) -> Option<Self>;
+
+    /// Round both parts to `dp` decimals, half away from zero.
+    fn round_dp(self, dp: isize) -> Self;
+}
+
+impl<T: BaseInt> ComplexIntFloat for Complex<IntFloat<T>> {
+    fn div_with_precision(self, rhs: Self, decimals: isize) -> Self {
+        assert!(
+            !rhs.re.base.is_zero() || !rhs.im.base.is_zero(),
+            "attempt to divide by zero"
+        );
+        self.checked_div_with_precision(rhs, decimals)
+            .expect("attempt to divide with overflow")
+    }
+
+    fn checked_div_with_precision(self, rhs: Self, decimals: isize) -> Option<Self> {
+        let norm_sqr = rhs
+            .re
+            .checked_mul(rhs.re)?
+            .checked_add(rhs.im.checked_mul(rhs.im)?)?;
+        let re = self
+            .re
+            .checked_mul(rhs.re)?
+            .checked_add(self.im.checked_mul(rhs.im)?)?;
+        let im = self
+            .im
+            .checked_mul(rhs.re)?
+            .checked_sub(self.re.checked_mul(rhs.im)?)?;
+        Some(Complex::new(
+            re.checked_div_with_precision(norm_sqr, decimals)?,
+            im.checked_div_with_precision(norm_sqr, decimals)?,
+        ))
+    }
+
+    fn round_dp(self, dp: isize) -> Self {
+        Complex::new(self.re.round_dp(dp), self.im.round_dp(dp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+    use num_traits::{Num, One, Pow, Zero};
+
+    fn complex(re: &str, im: &str) -> Complex<IntFloat> {
+        Complex::new(re.parse().unwrap(), im.parse().unwrap())
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = complex("1.5", "2");
+        let b = complex("0.5", "-1");
+        assert_eq!(a + b, complex("2", "1"));
+        assert_eq!(a - b, complex("1", "3"));
+        assert_eq!(a * b, complex("2.75", "-0.5"));
+        assert_eq!(-a, complex("-1.5", "-2"));
+        assert_eq!(a.conj(), complex("1.5", "-2"));
+        assert_eq!(a * IntFloat::new(2, 0), complex("3", "4"));
+        assert_eq!(a.norm_sqr(), IntFloat::new(625, 2));
+        assert_eq!(a.l1_norm(), IntFloat::new(35, 1));
+        assert_eq!(a.powu(2), complex("-1.75", "6"));
+        assert_eq!(a.pow(3_u32), a * a * a);
+        assert_eq!(Complex::<IntFloat>::i().powu(2), -Complex::one());
+        assert_eq!(a - a, Complex::zero());
+        assert_eq!([a, b].iter().sum::<Complex<IntFloat>>(), complex("2", "1"));
+    }
+
+    #[test]
+    fn test_exact() {
+        // The parts keep all their digits, unlike with floats.
+        let a = complex("0.1", "0.2");
+        let b = complex("0.2", "0.1");
+        assert_eq!(a + b, complex("0.3", "0.3"));
+        assert_eq!(a * b, complex("0", "0.05"));
+        let small = Complex::new(IntFloat32::new(1, 9), IntFloat32::new(-3, 9));
+        assert_eq!((small * small).im, IntFloat32::new(-6, 18));
+    }
+
+    #[test]
+    fn test_div_with_precision() {
+        let a = complex("1", "2");
+        let b = complex("3", "4");
+        assert_eq!(a.div_with_precision(b, 2), complex("0.44", "0.08"));
+        assert_eq!(a.div_with_precision(b, 1), complex("0.4", "0.1"));
+        let third = complex("1", "0").div_with_precision(complex("0", "3"), 3);
+        assert_eq!(third, complex("0", "-0.333"));
+        assert_eq!(third.re.to_string(), "0.000");
+        assert_eq!(a.checked_div_with_precision(Complex::zero(), 2), None);
+        let large = Complex::new(IntFloat::new(isize::MAX, 0), IntFloat::new(1, 0));
+        assert_eq!(a.checked_div_with_precision(large, 2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_by_zero() {
+        complex("1", "2").div_with_precision(Complex::zero(), 2);
+    }
+
+    #[test]
+    fn test_round_dp() {
+        assert_eq!(
+            complex("1.255", "-0.005").round_dp(2),
+            complex("1.26", "-0.01")
+        );
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("1.5+2.25i".parse(), Ok(complex("1.5", "2.25")));
+        assert_eq!("-0.5i".parse(), Ok(complex("0", "-0.5")));
+        assert_eq!(
+            Complex::from_str_radix("1.5-2i", 10),
+            Ok(complex("1.5", "-2"))
+        );
+        assert_eq!(complex("1.50", "-2").to_string(), "1.50-2i");
+        assert_eq!(complex("0", "0.1").to_string(), "0+0.1i");
+    }
+}
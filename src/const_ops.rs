@@ -0,0 +1,237 @@
+use crate::intfloat::IntFloat;
+
+// Arithmetic as `const fn`, for building tables of decimal constants at compile time. These give
+// the same results as the operators, but are only implemented for the concrete base types,
+// because trait methods can't be called in a `const fn`. They panic on overflow, which makes the
+// compilation fail when used in a constant. See `IntFloat::new` for an example.
+
+/// Pi and e with 38 decimals, which are rounded to the requested number of decimals.
+const PI: u128 = 314159265358979323846264338327950288420;
+const E: u128 = 271828182845904523536028747135266249776;
+const DECIMALS: isize = 38;
+
+macro_rules! impl_const_ops {
+    ($($int:ident),*) => {
+        $(
+            impl IntFloat<$int> {
+                /// Bring both bases to the largest of both powers, in the order of the operands,
+                /// or None on overflow.
+                const fn const_align(self, rhs: Self) -> Option<($int, $int, isize)> {
+                    let (base, diff, pow) = if rhs.pow > self.pow {
+                        (self.base, rhs.pow.checked_sub(self.pow), rhs.pow)
+                    } else {
+                        (rhs.base, self.pow.checked_sub(rhs.pow), self.pow)
+                    };
+                    let factor = match diff {
+                        Some(diff) if diff as usize <= u32::MAX as usize => {
+                            (10 as $int).checked_pow(diff as u32)
+                        }
+                        _ => None,
+                    };
+                    let rescaled = match factor {
+                        _ if base == 0 => 0,
+                        Some(factor) => match base.checked_mul(factor) {
+                            Some(base) => base,
+                            None => return None,
+                        },
+                        None => return None,
+                    };
+                    if rhs.pow > self.pow {
+                        Some((rescaled, rhs.base, pow))
+                    } else {
+                        Some((self.base, rescaled, pow))
+                    }
+                }
+
+                /// A constant with 38 decimals, rounded half up to `decimals` decimals.
+                const fn const_constant(value: u128, decimals: isize) -> Self {
+                    if decimals < 0 || decimals > DECIMALS {
+                        panic!("decimals out of range");
+                    }
+                    let divisor = 10_u128.pow((DECIMALS - decimals) as u32);
+                    let round_up = value % divisor * 2 >= divisor;
+                    let base = value / divisor + round_up as u128;
+                    if base > $int::MAX as u128 {
+                        panic!("constant doesn't fit in the base");
+                    }
+                    IntFloat {
+                        base: base as $int,
+                        pow: decimals,
+                    }
+                }
+
+                /// Pi, rounded to `decimals` decimals.
+                ///
+                /// # Panics
+                ///
+                /// If `decimals` is negative or above 38, or if the result doesn't fit in the
+                /// base: pi fits with at most 1 decimal in an i8, 4 in an i16, 8 in an i32, 18 in
+                /// an i64 and 37 in an i128. An isize has the limit of the integer of its width.
+                pub const fn pi(decimals: isize) -> Self {
+                    Self::const_constant(PI, decimals)
+                }
+
+                /// Euler's number e, rounded to `decimals` decimals, like `pi`.
+                ///
+                /// # Panics
+                ///
+                /// Like `pi`, with the same number of decimals for each base: at most 1 in an i8,
+                /// 4 in an i16, 8 in an i32, 18 in an i64 and 37 in an i128.
+                pub const fn e(decimals: isize) -> Self {
+                    Self::const_constant(E, decimals)
+                }
+
+                /// `self + rhs` as a `const fn`.
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    let sum = match self.const_align(rhs) {
+                        Some((lhs, rhs, pow)) => match lhs.checked_add(rhs) {
+                            Some(base) => Some(IntFloat { base, pow }),
+                            None => None,
+                        },
+                        None => None,
+                    };
+                    match sum {
+                        Some(sum) => sum,
+                        None => panic!("attempt to add with overflow"),
+                    }
+                }
+
+                /// `self - rhs` as a `const fn`.
+                pub const fn const_sub(self, rhs: Self) -> Self {
+                    let difference = match self.const_align(rhs) {
+                        Some((lhs, rhs, pow)) => match lhs.checked_sub(rhs) {
+                            Some(base) => Some(IntFloat { base, pow }),
+                            None => None,
+                        },
+                        None => None,
+                    };
+                    match difference {
+                        Some(difference) => difference,
+                        None => panic!("attempt to subtract with overflow"),
+                    }
+                }
+
+                /// `self * rhs` as a `const fn`.
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    match (self.base.checked_mul(rhs.base), self.pow.checked_add(rhs.pow)) {
+                        (Some(base), Some(pow)) => IntFloat { base, pow },
+                        _ => panic!("attempt to multiply with overflow"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloat128, IntFloat32, IntFloat64};
+
+    const TICK_SIZES: [IntFloat; 4] = [
+        IntFloat::new(1, 2),
+        IntFloat::new(5, 2),
+        IntFloat::new(1, 2).const_mul(IntFloat::new(10, 0)),
+        IntFloat::new(25, 2).const_add(IntFloat::new(25, 2)),
+    ];
+
+    #[test]
+    fn test_const_table() {
+        assert_eq!(TICK_SIZES[2], IntFloat::new(1, 1));
+        assert_eq!(TICK_SIZES[3].to_string(), "0.50");
+    }
+
+    #[test]
+    fn test_const_ops() {
+        let (a, b) = (IntFloat::new(125, 2), IntFloat::new(-3, 0));
+        assert_eq!(a.const_add(b), a + b);
+        assert_eq!(a.const_add(b).to_string(), "-1.75");
+        assert_eq!(b.const_add(a).to_string(), "-1.75");
+        assert_eq!(a.const_sub(b), a - b);
+        assert_eq!(b.const_sub(a).to_string(), "-4.25");
+        assert_eq!(
+            IntFloat::new(-1, 0).const_sub(IntFloat::new(isize::MIN, 0)),
+            IntFloat::new(isize::MAX, 0)
+        );
+        assert_eq!(a.const_mul(b), a * b);
+        assert_eq!(
+            IntFloat::zero().const_add(IntFloat::new(1, 5)),
+            IntFloat::new(1, 5)
+        );
+        assert_eq!(
+            IntFloat::new(0, 0).const_add(IntFloat::new(1, isize::MAX)),
+            IntFloat::new(1, isize::MAX)
+        );
+        const WIDE: IntFloat128 = IntFloat128::new(i128::MAX / 10, 0).const_sub(IntFloat128::one());
+        assert_eq!(WIDE, IntFloat128::new(i128::MAX / 10 - 1, 0));
+        assert_eq!(
+            IntFloat32::new(1, 1).const_mul(IntFloat32::new(1, 1)),
+            IntFloat32::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_zero_one() {
+        const ZERO: IntFloat = IntFloat::zero();
+        const ONE: IntFloat32 = IntFloat32::one();
+        assert_eq!((ZERO.base, ZERO.pow), (0, 0));
+        assert_eq!((ONE.base, ONE.pow), (1, 0));
+    }
+
+    #[test]
+    fn test_constants() {
+        const TAU: IntFloat64 = IntFloat64::pi(18).const_mul(IntFloat64::new(2, 0));
+        assert_eq!(TAU.to_string(), "6.283185307179586476");
+        assert_eq!(IntFloat::pi(0), IntFloat::new(3, 0));
+        assert_eq!(IntFloat::pi(4), IntFloat::new(31416, 4));
+        assert_eq!(IntFloat32::pi(8), IntFloat32::new(314159265, 8));
+        assert_eq!(IntFloat::e(3), IntFloat::new(2718, 3));
+        assert_eq!(IntFloat::e(4), IntFloat::new(27183, 4));
+        assert_eq!(
+            IntFloat128::e(37).to_string(),
+            "2.7182818284590452353602874713526624978"
+        );
+        assert_eq!(crate::generic::IntFloat::<i8>::pi(1).to_string(), "3.1");
+        assert_eq!(crate::generic::IntFloat::<i16>::e(4).to_string(), "2.7183");
+        assert_eq!((IntFloat::TEN.base, IntFloat::TEN.pow), (10, 0));
+        assert_eq!(IntFloat::ZERO, IntFloat::zero());
+        assert_eq!(IntFloat32::ONE, IntFloat32::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "constant doesn't fit in the base")]
+    fn test_constant_overflow() {
+        IntFloat32::pi(9);
+    }
+
+    #[test]
+    #[should_panic(expected = "decimals out of range")]
+    fn test_constant_decimals() {
+        IntFloat::e(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_const_add_overflow() {
+        IntFloat::new(1, 0).const_add(IntFloat::new(1, 19));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_const_add_pow_diff_overflow() {
+        IntFloat::new(1, -1).const_add(IntFloat::new(1, isize::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_const_sub_overflow() {
+        IntFloat::new(1, 0).const_sub(IntFloat::new(isize::MIN, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_const_mul_overflow() {
+        IntFloat32::new(i32::MAX, 0).const_mul(IntFloat32::new(2, 0));
+    }
+}
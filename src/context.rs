@@ -0,0 +1,151 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, RoundingMode};
+use std::cell::Cell;
+
+thread_local! {
+    static CURRENT: Cell<Context> = const { Cell::new(Context::DEFAULT) };
+}
+
+/// Default number of decimals and rounding mode of the current thread, like the context of
+/// Python's decimal module. The `*_in_context` methods use it instead of taking `decimals` and a
+/// mode; all other methods ignore it, so code that passes them explicitly behaves the same
+/// whatever the context. Each thread starts with `Context::default()`: 6 decimals, rounded half
+/// away from zero.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{Context, IntFloat, RoundingMode};
+/// let third = IntFloat::one().div_in_context(IntFloat::new(3, 0));
+/// assert_eq!(third.to_string(), "0.333333");
+///
+/// let cents = Context::new(2, RoundingMode::HalfEven);
+/// let price = cents.scope(|| IntFloat::from_f64_in_context(2.675));
+/// assert_eq!(price.to_string(), "2.68");
+/// cents.scope(|| {
+///     assert_eq!(IntFloat::new(1, 0).to_string_in_context(), "1.00");
+///     assert_eq!(IntFloat::new(2125, 3).round_in_context().to_string(), "2.12");
+/// });
+/// assert_eq!(Context::current(), Context::default());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Context {
+    pub decimals: isize,
+    pub rounding: RoundingMode,
+}
+
+impl Context {
+    const DEFAULT: Context = Context {
+        decimals: 6,
+        rounding: RoundingMode::HalfUp,
+    };
+
+    pub const fn new(decimals: isize, rounding: RoundingMode) -> Self {
+        Context { decimals, rounding }
+    }
+
+    /// The context of the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Make this the context of the current thread, and return the previous one.
+    pub fn set(self) -> Self {
+        CURRENT.with(|current| current.replace(self))
+    }
+
+    /// Run `f` with this as the context of the current thread, and restore the previous context
+    /// afterwards, also if `f` panics.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Context);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                self.0.set();
+            }
+        }
+
+        let _restore = Restore(self.set());
+        f()
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::DEFAULT
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Round an f64 like `from_f64_with_rounding`, with the decimals and mode of the context.
+    pub fn from_f64_in_context(float: f64) -> Self {
+        let context = Context::current();
+        Self::from_f64_with_rounding(float, context.decimals, context.rounding)
+    }
+
+    /// Divide by `rhs`, with exactly the decimals of the context and rounded with its mode.
+    /// Panics if `rhs` is zero or if the quotient doesn't fit in the base.
+    pub fn div_in_context(self, rhs: Self) -> Self {
+        assert!(!rhs.base.is_zero(), "attempt to divide by zero");
+        let context = Context::current();
+        self.checked_div_with_mode(rhs, context.decimals, context.rounding)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Round to the decimals of the context with its mode, like `round_dp_with`.
+    pub fn round_in_context(self) -> Self {
+        let context = Context::current();
+        self.round_dp_with(context.decimals, context.rounding)
+    }
+
+    /// Print rounded to the decimals of the context, padded with zeros to exactly that many.
+    pub fn to_string_in_context(self) -> String {
+        let context = Context::current();
+        let rounded = self.round_dp_with(context.decimals, context.rounding);
+        format!("{:.*}", context.decimals.max(0) as usize, rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_context() {
+        assert_eq!(Context::current(), Context::new(6, RoundingMode::HalfUp));
+        let previous = Context::new(0, RoundingMode::Floor).set();
+        assert_eq!(previous, Context::default());
+        assert_eq!(
+            IntFloat::new(-15, 1).round_in_context(),
+            IntFloat::new(-2, 0)
+        );
+        assert_eq!(IntFloat::new(-15, 1).to_string_in_context(), "-2");
+        Context::new(-2, RoundingMode::HalfEven).scope(|| {
+            assert_eq!(IntFloat::new(1250, 0).to_string_in_context(), "1200");
+            assert_eq!(IntFloat::from_f64_in_context(1350.0), IntFloat::new(14, -2));
+        });
+        assert_eq!(Context::current().decimals, 0);
+        let other = std::thread::spawn(Context::current).join().unwrap();
+        assert_eq!(other, Context::default());
+        previous.set();
+    }
+
+    #[test]
+    fn test_div_in_context() {
+        let context = Context::new(3, RoundingMode::Ceiling);
+        let quotient = context.scope(|| IntFloat::new(-2, 0).div_in_context(IntFloat::new(3, 0)));
+        assert_eq!((quotient.base, quotient.pow), (-666, 3));
+        let quotient = IntFloat::new(2, 0).div_in_context(IntFloat::new(3, 0));
+        assert_eq!((quotient.base, quotient.pow), (666667, 6));
+    }
+
+    #[test]
+    fn test_scope_restores_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            Context::new(1, RoundingMode::Floor).scope(|| panic!("in scope"))
+        });
+        assert!(result.is_err());
+        assert_eq!(Context::current(), Context::default());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use rust_decimal::Decimal;
+
+/// Exact conversion to a rust_decimal Decimal, keeping the number of decimals. A Decimal holds at
+/// most 28 decimals and a 96-bit mantissa, so this fails for values that need more, after
+/// dropping trailing zeros beyond 28 decimals.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use rust_decimal::Decimal;
+/// let price = IntFloat::new(1250, 2);
+/// assert_eq!(Decimal::try_from(price).unwrap().to_string(), "12.50");
+/// assert!(Decimal::try_from(IntFloat::new(1, 29)).is_err());
+/// ```
+impl<T: BaseInt> TryFrom<IntFloat<T>> for Decimal {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: IntFloat<T>) -> Result<Self, Self::Error> {
+        if value.base.is_zero() {
+            return Ok(Decimal::new(
+                0,
+                value.pow.clamp(0, Decimal::MAX_SCALE as isize) as u32,
+            ));
+        }
+        let value = if value.pow > Decimal::MAX_SCALE as isize {
+            value.normalize()
+        } else {
+            value
+        };
+        let base = value.base.to_i128().ok_or(OutOfRangeError)?;
+        let (mantissa, scale) = if value.pow < 0 {
+            let factor = u32::try_from(value.pow.unsigned_abs())
+                .ok()
+                .and_then(|pow| 10_i128.checked_pow(pow));
+            (factor.and_then(|factor| base.checked_mul(factor)), 0)
+        } else {
+            (
+                Some(base),
+                u32::try_from(value.pow).map_err(|_| OutOfRangeError)?,
+            )
+        };
+        Decimal::try_from_i128_with_scale(mantissa.ok_or(OutOfRangeError)?, scale)
+            .map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Exact conversion from a rust_decimal Decimal, keeping its number of decimals where the base
+/// allows. Fails if the value doesn't fit in the base, even without trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloat32};
+/// use rust_decimal::Decimal;
+/// let price = Decimal::new(1250, 2);
+/// assert_eq!(IntFloat::try_from(price), Ok(IntFloat::new(1250, 2)));
+/// assert!(IntFloat32::try_from(Decimal::MAX).is_err());
+/// ```
+impl<T: BaseInt> TryFrom<Decimal> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let convert = |value: Decimal| {
+            let base = num_traits::cast(value.mantissa())?;
+            Some(IntFloat::new(base, value.scale() as isize))
+        };
+        convert(value)
+            .or_else(|| convert(value.normalize()))
+            .ok_or(OutOfRangeError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_decimal() {
+        let decimal = |value: IntFloat| Decimal::try_from(value).map(|value| value.to_string());
+        assert_eq!(decimal(IntFloat::new(1250, 2)), Ok("12.50".to_string()));
+        assert_eq!(decimal(IntFloat::new(-5, 0)), Ok("-5".to_string()));
+        assert_eq!(decimal(IntFloat::new(5, -3)), Ok("5000".to_string()));
+        assert_eq!(
+            decimal(IntFloat::new(0, 40)),
+            Ok("0.0000000000000000000000000000".to_string())
+        );
+        assert_eq!(decimal(IntFloat::new(0, -40)), Ok("0".to_string()));
+        assert_eq!(
+            decimal(IntFloat::new(isize::MAX, 28)),
+            Ok("0.0000000009223372036854775807".to_string())
+        );
+        assert_eq!(
+            decimal(IntFloat::new(100, 30)),
+            Ok("0.0000000000000000000000000001".to_string())
+        );
+        assert_eq!(decimal(IntFloat::new(1, 29)), Err(OutOfRangeError));
+        assert_eq!(decimal(IntFloat::new(1, -29)), Err(OutOfRangeError));
+        assert_eq!(decimal(IntFloat::new(1, isize::MIN)), Err(OutOfRangeError));
+        assert_eq!(decimal(IntFloat::new(1, isize::MAX)), Err(OutOfRangeError));
+        assert_eq!(
+            Decimal::try_from(IntFloat128::new(i128::MAX, 0)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            Decimal::try_from(IntFloat32::new(i32::MIN, 3)),
+            Ok(Decimal::new(i32::MIN as i64, 3))
+        );
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        let decimal = |str: &str| Decimal::from_str(str).unwrap();
+        assert_eq!(
+            IntFloat::try_from(decimal("12.50")),
+            Ok(IntFloat::new(1250, 2))
+        );
+        assert_eq!(
+            IntFloat::try_from(decimal("-0.001")),
+            Ok(IntFloat::new(-1, 3))
+        );
+        assert_eq!(
+            IntFloat::try_from(decimal("1.0000000000000000000000000000")),
+            Ok(IntFloat::new(1, 0))
+        );
+        assert_eq!(IntFloat::try_from(Decimal::MAX), Err(OutOfRangeError));
+        assert_eq!(
+            IntFloat128::try_from(Decimal::MAX),
+            Ok(IntFloat128::new(79228162514264337593543950335, 0))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            IntFloat::new(1234, 2),
+            IntFloat::new(-1, 28),
+            IntFloat::new(7, -5),
+        ] {
+            let decimal = Decimal::try_from(value).unwrap();
+            assert_eq!(IntFloat::try_from(decimal), Ok(value));
+        }
+    }
+}
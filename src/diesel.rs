@@ -0,0 +1,54 @@
+use crate::intfloat::IntFloat;
+use crate::pg_numeric::PgNumeric;
+use crate::BaseInt;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::pg::{Pg, PgValue};
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::Numeric;
+use std::io::Write;
+
+/// Stores as the binary NUMERIC with the pow as the scale, so the number of decimals is kept. A
+/// negative pow is rescaled to zero decimals. Fails if that overflows the base, or if the pow is
+/// above 16383, the largest scale of a NUMERIC.
+impl<T: BaseInt> ToSql<Numeric, Pg> for IntFloat<T> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&PgNumeric::from_intfloat(*self)?.to_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+/// Reads a NUMERIC with its scale as the number of decimals. Fails for NaN and infinity, and if
+/// the value doesn't fit in the base.
+impl<T: BaseInt> FromSql<Numeric, Pg> for IntFloat<T> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(PgNumeric::from_bytes(value.as_bytes())?.to_intfloat()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntFloat;
+    use ::diesel::deserialize::{FromSqlRow, Queryable};
+    use ::diesel::pg::Pg;
+    use ::diesel::sql_types::{Nullable, Numeric};
+    use ::diesel::{debug_query, select, IntoSql};
+
+    fn assert_queryable<T: Queryable<Numeric, Pg>>() {}
+    fn assert_nullable<T: FromSqlRow<Nullable<Numeric>, Pg>>() {}
+
+    #[test]
+    fn test_query() {
+        assert_queryable::<IntFloat>();
+        assert_nullable::<Option<IntFloat>>();
+        let price = IntFloat::new(1999, 2);
+        let query = select(price.into_sql::<Numeric>());
+        assert_eq!(
+            debug_query::<Pg, _>(&query).to_string(),
+            "SELECT $1 -- binds: [IntFloat { base: 1999, pow: 2 }]"
+        );
+        let query = select((&price).into_sql::<Nullable<Numeric>>());
+        assert!(debug_query::<Pg, _>(&query)
+            .to_string()
+            .starts_with("SELECT $1"));
+    }
+}
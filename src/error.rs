@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::num::{IntErrorKind, ParseIntError};
+
+/// Reason why parsing, converting or computing an IntFloat failed. Parsing returns it directly,
+/// the `try_` arithmetic distinguishes dividing by zero from overflowing with it, and the more
+/// specific errors of the conversions convert into it, so that `?` works across all of them.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloatError};
+/// assert_eq!("12.3x".parse::<IntFloat>(), Err(IntFloatError::InvalidDigit));
+/// assert_eq!("1e99999999999999999999".parse::<IntFloat>(), Err(IntFloatError::ScaleTooLarge));
+/// let one = IntFloat::new(1, 0);
+/// assert_eq!(one.try_div_with_precision(IntFloat::new(0, 0), 2), Err(IntFloatError::DivideByZero));
+///
+/// fn cost(price: &str, weight: f64) -> Result<IntFloat, IntFloatError> {
+///     let price: IntFloat = price.parse()?;
+///     let weight = IntFloat::try_from_f64(weight, 3)?;
+///     price.try_mul(weight)
+/// }
+/// assert_eq!(cost("2.50", 1.5).unwrap().to_string(), "3.75000");
+/// assert_eq!(cost("2.50", f64::NAN), Err(IntFloatError::NotFinite));
+/// assert_eq!(cost("2,50", 1.5), Err(IntFloatError::InvalidDigit));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IntFloatError {
+    /// The string to parse is empty, or only has a sign.
+    Empty,
+    /// The string to parse has a character that doesn't belong in a decimal number.
+    InvalidDigit,
+    /// The value doesn't fit in the base.
+    Overflow,
+    /// The power, i.e. the number of decimals, doesn't fit in an isize.
+    ScaleTooLarge,
+    /// The divisor is zero.
+    DivideByZero,
+    /// The value can't be represented exactly in the target type.
+    LossOfPrecision,
+    /// The float to convert is NaN or infinite.
+    NotFinite,
+}
+
+impl IntFloatError {
+    /// The error of parsing the digits of an IntFloat with the integer parser of its base.
+    pub(crate) fn from_digits(error: &ParseIntError) -> Self {
+        match error.kind() {
+            IntErrorKind::Empty => IntFloatError::Empty,
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => IntFloatError::Overflow,
+            _ => IntFloatError::InvalidDigit,
+        }
+    }
+
+    /// The error of parsing an exponent, which is only empty if the digits after an `e` are
+    /// missing.
+    pub(crate) fn from_exponent(error: &ParseIntError) -> Self {
+        match error.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => IntFloatError::ScaleTooLarge,
+            _ => IntFloatError::InvalidDigit,
+        }
+    }
+}
+
+impl Display for IntFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IntFloatError::Empty => "cannot parse IntFloat from empty string",
+            IntFloatError::InvalidDigit => "invalid digit found in string",
+            IntFloatError::Overflow => "value is too large for the base of the IntFloat",
+            IntFloatError::ScaleTooLarge => "number of decimals is too large",
+            IntFloatError::DivideByZero => "attempt to divide by zero",
+            IntFloatError::LossOfPrecision => {
+                "value can't be represented exactly in the target type"
+            }
+            IntFloatError::NotFinite => "cannot convert NaN or an infinite float to IntFloat",
+        })
+    }
+}
+
+impl Error for IntFloatError {}
+
+impl From<TryFromFloatError> for IntFloatError {
+    fn from(error: TryFromFloatError) -> Self {
+        match error {
+            TryFromFloatError::NaN | TryFromFloatError::Infinite => IntFloatError::NotFinite,
+            TryFromFloatError::Overflow => IntFloatError::Overflow,
+        }
+    }
+}
+
+impl From<OutOfRangeError> for IntFloatError {
+    fn from(_: OutOfRangeError) -> Self {
+        IntFloatError::LossOfPrecision
+    }
+}
+
+/// Reason why a float couldn't be converted to an IntFloat by `try_from_f64` or `TryFrom`.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, TryFromFloatError};
+/// assert_eq!(IntFloat::try_from(f64::NAN), Err(TryFromFloatError::NaN));
+/// assert_eq!(IntFloat::try_from_f64(1e300, 2), Err(TryFromFloatError::Overflow));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TryFromFloatError {
+    /// The float is NaN.
+    NaN,
+    /// The float is positive or negative infinity.
+    Infinite,
+    /// The float, scaled to the requested number of decimals, doesn't fit in the base.
+    Overflow,
+}
+
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TryFromFloatError::NaN => "cannot convert NaN to IntFloat",
+            TryFromFloatError::Infinite => "cannot convert an infinite float to IntFloat",
+            TryFromFloatError::Overflow => "float is too large for the base of the IntFloat",
+        })
+    }
+}
+
+impl Error for TryFromFloatError {}
+
+/// Error of the conversions between IntFloat and other number types: the value can't be
+/// represented exactly in the target type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct OutOfRangeError;
+
+impl Display for OutOfRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("value can't be represented exactly in the target type")
+    }
+}
+
+impl Error for OutOfRangeError {}
+
+/// Reason why an operation on Money failed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MoneyError {
+    /// The amounts are in different currencies.
+    CurrencyMismatch,
+    /// The amount has more decimals than the minor unit of its currency.
+    TooManyDecimals,
+    /// The amount doesn't fit in the base.
+    Overflow,
+}
+
+impl Display for MoneyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MoneyError::CurrencyMismatch => "amounts are in different currencies",
+            MoneyError::TooManyDecimals => "amount has more decimals than its currency allows",
+            MoneyError::Overflow => "amount is too large for the base",
+        })
+    }
+}
+
+impl Error for MoneyError {}
@@ -0,0 +1,137 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_traits::Euclid;
+
+/// Euclidean division, mirroring the primitive integers: `div_euclid` gives the integer `q` and
+/// `rem_euclid` the remainder `r` such that `self == q * rhs + r` with `0 <= r < |rhs|`. The
+/// remainder has the largest scale of both operands. Both panic if `rhs` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(-75, 1);
+/// let b = IntFloat::new(2, 0);
+/// assert_eq!(a.div_euclid(b), IntFloat::new(-4, 0));
+/// assert_eq!(a.rem_euclid(b), IntFloat::new(5, 1));
+/// assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (lhs_base, rhs_base, _) = self.align(rhs, "divide");
+        let quotient = lhs_base / rhs_base;
+        let base = if (lhs_base % rhs_base).is_negative() {
+            if rhs_base.is_positive() {
+                quotient - T::one()
+            } else {
+                quotient + T::one()
+            }
+        } else {
+            quotient
+        };
+        IntFloat::new(base, 0)
+    }
+
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let (lhs_base, rhs_base, pow) = self.align(rhs, "calculate the remainder");
+        let remainder = lhs_base % rhs_base;
+        let base = if remainder.is_negative() {
+            remainder + rhs_base.abs()
+        } else {
+            remainder
+        };
+        IntFloat::new(base, pow)
+    }
+}
+
+impl<T: BaseInt> Euclid for IntFloat<T> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        IntFloat::div_euclid(*self, *v)
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        IntFloat::rem_euclid(*self, *v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_div_rem_euclid() {
+        let cases = [
+            (
+                IntFloat::new(75, 1),
+                IntFloat::new(2, 0),
+                3,
+                IntFloat::new(15, 1),
+            ),
+            (
+                IntFloat::new(-75, 1),
+                IntFloat::new(2, 0),
+                -4,
+                IntFloat::new(5, 1),
+            ),
+            (
+                IntFloat::new(75, 1),
+                IntFloat::new(-2, 0),
+                -3,
+                IntFloat::new(15, 1),
+            ),
+            (
+                IntFloat::new(-75, 1),
+                IntFloat::new(-2, 0),
+                4,
+                IntFloat::new(5, 1),
+            ),
+            (
+                IntFloat::new(6, 0),
+                IntFloat::new(15, 1),
+                4,
+                IntFloat::new(0, 1),
+            ),
+            (
+                IntFloat::new(-6, 0),
+                IntFloat::new(15, 1),
+                -4,
+                IntFloat::new(0, 1),
+            ),
+            (
+                IntFloat::new(-1, 2),
+                IntFloat::new(1, -2),
+                -1,
+                IntFloat::new(9999, 2),
+            ),
+        ];
+        for (this, that, quotient, remainder) in cases {
+            assert_eq!(this.div_euclid(that), IntFloat::new(quotient, 0));
+            assert_eq!(this.rem_euclid(that), remainder);
+            assert_eq!(this.rem_euclid(that).pow, remainder.pow);
+            assert_eq!(this.div_euclid(that) * that + this.rem_euclid(that), this);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_euclid_by_zero() {
+        IntFloat::new(1, 0).div_euclid(IntFloat::new(0, 2));
+    }
+
+    #[test]
+    fn test_euclid_trait() {
+        fn bucket<T: Euclid>(value: &T, width: &T) -> T {
+            value.div_euclid(width)
+        }
+
+        assert_eq!(
+            bucket(&IntFloat::new(-1, 1), &IntFloat::new(5, 0)),
+            IntFloat::new(-1, 0)
+        );
+        assert_eq!(
+            Euclid::rem_euclid(&IntFloat::new(-1, 1), &IntFloat::new(5, 0)),
+            IntFloat::new(49, 1)
+        );
+    }
+}
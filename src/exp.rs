@@ -0,0 +1,89 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::BaseInt;
+
+/// The exponential function, rounded half away from zero to `decimals` decimals. It splits the
+/// value into a multiple of ln(10) and a remainder, and sums the Taylor series of the remainder
+/// in fixed point with 32 decimals, so about the first 30 significant digits are correct. The
+/// results only depend on integer arithmetic, so they are the same on every platform. Values are
+/// supported up to a magnitude of about 1.7 million.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// // 1000 at 5% interest, compounded continuously for three years.
+/// let growth = (IntFloat::new(5, 2) * IntFloat::new(3, 0)).exp(6);
+/// assert_eq!((IntFloat::new(1000, 0) * growth).round_dp(2).to_string(), "1161.83");
+/// assert_eq!(IntFloat::new(0, 0).exp(2).to_string(), "1.00");
+/// assert_eq!(IntFloat::new(100, 0).checked_exp(2), None);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// Panics if the result doesn't fit in the base.
+    pub fn exp(self, decimals: isize) -> Self {
+        self.checked_exp(decimals)
+            .expect("attempt to take the exponential with overflow")
+    }
+
+    /// Like `exp`, but None if the result doesn't fit in the base.
+    pub fn checked_exp(self, decimals: isize) -> Option<Self> {
+        let (mantissa, power) = math::exp(math::to_fixed(self)?)?;
+        math::from_fixed(mantissa, power, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat128, IntFloat64};
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(IntFloat64::new(0, 5).exp(0), IntFloat64::new(1, 0));
+        assert_eq!(
+            IntFloat64::new(1, 0).exp(18),
+            IntFloat64::new(2718281828459045235, 18)
+        );
+        assert_eq!(
+            IntFloat64::new(-1, 0).exp(18),
+            IntFloat64::new(367879441171442322, 18)
+        );
+        assert_eq!(
+            IntFloat64::new(40, 0).exp(0),
+            IntFloat64::new(235385266837019985, 0)
+        );
+        assert_eq!(IntFloat64::new(-20, 0).exp(12), IntFloat64::new(2061, 12));
+        assert_eq!(IntFloat64::new(-20, 0).exp(2), IntFloat64::new(0, 2));
+        assert_eq!(
+            IntFloat64::new(100, 0).exp(-30),
+            IntFloat64::new(26881171418161, -30)
+        );
+        assert_eq!(
+            IntFloat64::new(2302585092994045684, 18).exp(15),
+            IntFloat64::new(10000000000000000, 15)
+        );
+        assert_eq!(
+            IntFloat128::new(1, 0).exp(30),
+            IntFloat128::new(2718281828459045235360287471353, 30)
+        );
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let this = IntFloat64::new(12345, 2);
+        assert_eq!(this.ln(17).exp(10), IntFloat64::new(1234500000000, 10));
+    }
+
+    #[test]
+    fn test_checked_exp() {
+        assert_eq!(IntFloat64::new(44, 0).checked_exp(0), None);
+        assert_eq!(IntFloat64::new(2, 0).checked_exp(19), None);
+        assert_eq!(IntFloat64::new(1, -7).checked_exp(0), None);
+        assert_eq!(IntFloat64::new(-1, -7).checked_exp(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take the exponential with overflow")]
+    fn test_exp_overflow() {
+        IntFloat64::new(50, 0).exp(0);
+    }
+}
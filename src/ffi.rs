@@ -0,0 +1,229 @@
+//! A C API for IntFloat64, for using the same decimal arithmetic from C and C++. The functions are
+//! exported unmangled with the prefix `intfloat_`, and `include/intfloat.h` declares them. To link
+//! them into a C or C++ program, build a `staticlib` or `cdylib` crate that depends on intfloat
+//! with the `ffi` feature.
+//!
+//! IntFloat64 is `#[repr(C)]`, so it is passed by value as
+//! `struct { int64_t base; intptr_t pow; }` with the value base x 10^-pow. Functions that can fail
+//! write their result through an out pointer, or return false and leave it untouched. None of them
+//! allocate memory that the caller must free.
+//!
+//! ```
+//! use intfloat::ffi::*;
+//! use intfloat::IntFloat64;
+//! let mut sum = IntFloat64::default();
+//! let ok = unsafe { intfloat_add(intfloat_new(1250, 2), intfloat_new(5, 1), &mut sum) };
+//! assert!(ok);
+//! let mut buffer = [0; 16];
+//! let len = unsafe { intfloat_to_string(sum, buffer.as_mut_ptr(), buffer.len()) };
+//! assert_eq!(&buffer[..len], b"13.00".map(|byte| byte as std::ffi::c_char));
+//! ```
+
+use crate::IntFloat64;
+use std::cmp::Ordering;
+use std::ffi::{c_char, c_int, CStr};
+
+/// Write `result` through `out` if both are there, and tell if that happened.
+unsafe fn write(out: *mut IntFloat64, result: Option<IntFloat64>) -> bool {
+    match (out.as_mut(), result) {
+        (Some(out), Some(result)) => {
+            *out = result;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Create the IntFloat64 with value base x 10^-pow.
+#[no_mangle]
+pub extern "C" fn intfloat_new(base: i64, pow: isize) -> IntFloat64 {
+    IntFloat64::new(base, pow)
+}
+
+/// Parse a NUL-terminated decimal string like `"12.345"` or `"1.5e-3"` into `out`. Returns false
+/// if `str` isn't a valid number or doesn't fit.
+///
+/// # Safety
+///
+/// `str` must be null or point to a NUL-terminated string, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_parse(str: *const c_char, out: *mut IntFloat64) -> bool {
+    if str.is_null() {
+        return false;
+    }
+    let result = CStr::from_ptr(str)
+        .to_str()
+        .ok()
+        .and_then(|str| str.parse().ok());
+    write(out, result)
+}
+
+/// Write `lhs + rhs` to `out`, or return false if it overflows.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_add(
+    lhs: IntFloat64,
+    rhs: IntFloat64,
+    out: *mut IntFloat64,
+) -> bool {
+    write(out, lhs.checked_add(rhs))
+}
+
+/// Write `lhs - rhs` to `out`, or return false if it overflows.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_sub(
+    lhs: IntFloat64,
+    rhs: IntFloat64,
+    out: *mut IntFloat64,
+) -> bool {
+    write(out, lhs.checked_sub(rhs))
+}
+
+/// Write `lhs * rhs` to `out`, or return false if it overflows.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_mul(
+    lhs: IntFloat64,
+    rhs: IntFloat64,
+    out: *mut IntFloat64,
+) -> bool {
+    write(out, lhs.checked_mul(rhs))
+}
+
+/// Write `lhs / rhs` rounded to `decimals` decimals to `out`, like `div_with_precision`. Returns
+/// false when dividing by zero or if the result overflows.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_div(
+    lhs: IntFloat64,
+    rhs: IntFloat64,
+    decimals: isize,
+    out: *mut IntFloat64,
+) -> bool {
+    write(out, lhs.checked_div_with_precision(rhs, decimals))
+}
+
+/// Compare the values of `lhs` and `rhs`: -1 if `lhs` is smaller, 0 if they are equal, e.g. 12.50
+/// and 12.5, and 1 if `lhs` is larger.
+#[no_mangle]
+pub extern "C" fn intfloat_cmp(lhs: IntFloat64, rhs: IntFloat64) -> c_int {
+    match lhs.cmp(&rhs) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// Write the decimal string of `value`, e.g. `"12.50"`, to `buffer` as a NUL-terminated string,
+/// like `snprintf`: at most `len - 1` bytes are written before the NUL, so a longer string is cut
+/// off. Returns the length of the whole string without the NUL, so a return value of `len` or
+/// more means the buffer was too small.
+///
+/// # Safety
+///
+/// `buffer` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn intfloat_to_string(
+    value: IntFloat64,
+    buffer: *mut c_char,
+    len: usize,
+) -> usize {
+    let str = value.to_string();
+    if !buffer.is_null() && len > 0 {
+        let written = str.len().min(len - 1);
+        std::ptr::copy_nonoverlapping(str.as_ptr().cast(), buffer, written);
+        *buffer.add(written) = 0;
+    }
+    str.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+
+    fn to_string(value: IntFloat64) -> String {
+        let mut buffer = [0; 64];
+        let len = unsafe { intfloat_to_string(value, buffer.as_mut_ptr(), buffer.len()) };
+        let str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(str.to_bytes().len(), len);
+        str.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_parse_and_to_string() {
+        let mut value = IntFloat64::default();
+        assert!(unsafe { intfloat_parse(c"-12.050".as_ptr(), &mut value) });
+        assert_eq!(value, intfloat_new(-12050, 3));
+        assert_eq!(to_string(value), "-12.050");
+        assert!(!unsafe { intfloat_parse(c"1.2.3".as_ptr(), &mut value) });
+        assert!(!unsafe { intfloat_parse(null(), &mut value) });
+        assert!(!unsafe { intfloat_parse(c"1".as_ptr(), null_mut()) });
+        assert_eq!(value, intfloat_new(-12050, 3));
+
+        let mut buffer = [1; 4];
+        let len = unsafe { intfloat_to_string(value, buffer.as_mut_ptr(), buffer.len()) };
+        assert_eq!(len, 7);
+        assert_eq!(buffer, [b'-', b'1', b'2', 0].map(|byte| byte as c_char));
+        assert_eq!(unsafe { intfloat_to_string(value, null_mut(), 0) }, 7);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (intfloat_new(1250, 2), intfloat_new(3, 0));
+        let mut out = IntFloat64::default();
+        assert!(unsafe { intfloat_add(a, b, &mut out) });
+        assert_eq!(to_string(out), "15.50");
+        assert!(unsafe { intfloat_sub(a, b, &mut out) });
+        assert_eq!(to_string(out), "9.50");
+        assert!(unsafe { intfloat_mul(a, b, &mut out) });
+        assert_eq!(to_string(out), "37.50");
+        assert!(unsafe { intfloat_div(a, b, 4, &mut out) });
+        assert_eq!(to_string(out), "4.1667");
+        assert!(!unsafe { intfloat_div(a, intfloat_new(0, 0), 4, &mut out) });
+        assert!(!unsafe { intfloat_add(intfloat_new(i64::MAX, 0), b, &mut out) });
+        assert!(!unsafe { intfloat_mul(a, b, null_mut()) });
+        assert_eq!(to_string(out), "4.1667");
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert_eq!(intfloat_cmp(intfloat_new(1250, 2), intfloat_new(125, 1)), 0);
+        assert_eq!(
+            intfloat_cmp(intfloat_new(1249, 2), intfloat_new(125, 1)),
+            -1
+        );
+        assert_eq!(intfloat_cmp(intfloat_new(-1, 0), intfloat_new(0, 5)), -1);
+        assert_eq!(intfloat_cmp(intfloat_new(0, 0), intfloat_new(0, -5)), 0);
+        assert_eq!(
+            intfloat_cmp(intfloat_new(1, -40), intfloat_new(i64::MAX, 0)),
+            1
+        );
+        assert_eq!(
+            intfloat_cmp(intfloat_new(-1, -40), intfloat_new(i64::MIN, 0)),
+            -1
+        );
+        assert_eq!(
+            intfloat_cmp(intfloat_new(i64::MIN, 0), intfloat_new(-1, isize::MIN)),
+            1
+        );
+        assert_eq!(
+            intfloat_cmp(intfloat_new(1, isize::MIN), intfloat_new(1, isize::MAX)),
+            1
+        );
+    }
+}
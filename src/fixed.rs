@@ -0,0 +1,289 @@
+use crate::IntFloat;
+use num_traits::{One, Zero};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+/// Variant of IntFloat where the number of decimals is part of the type: the value is
+/// a x 10^-SCALE, and only a is stored. Mixing values with different scales is a compile error,
+/// and arithmetic never has to rescale its operands.
+///
+/// Multiplication and division round the result back to SCALE decimals, half away from zero. Just
+/// like the primitive integers, they panic when the result doesn't fit. SCALE can't be negative.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{FixedIntFloat, IntFloat};
+/// let price = FixedIntFloat::<2>::new(1999);
+/// let total = price + price;
+/// assert_eq!(total.to_string(), "39.98");
+/// let total: IntFloat = total.into();
+/// assert_eq!(total, IntFloat::new(3998, 2));
+/// assert_eq!(price * FixedIntFloat::new(50), FixedIntFloat::new(1000));
+/// ```
+///
+/// ```compile_fail
+/// use intfloat::FixedIntFloat;
+/// let price = FixedIntFloat::<2>::new(1999);
+/// let rate = FixedIntFloat::<6>::new(1_250_000);
+/// let _ = price + rate;
+/// ```
+pub struct FixedIntFloat<const SCALE: i32> {
+    base: isize,
+}
+
+/// Divide and round half away from zero.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder.abs() * 2 >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+impl<const SCALE: i32> FixedIntFloat<SCALE> {
+    const FACTOR: i128 = {
+        assert!(SCALE >= 0, "the scale of a FixedIntFloat can't be negative");
+        10_i128.pow(SCALE as u32)
+    };
+
+    /// Create the value `base` x 10^-SCALE.
+    pub fn new(base: isize) -> FixedIntFloat<SCALE> {
+        let _ = Self::FACTOR;
+        FixedIntFloat { base }
+    }
+
+    /// The stored integer.
+    pub fn base(self) -> isize {
+        self.base
+    }
+
+    /// Convert an IntFloat to SCALE decimals, rounding half away from zero. None if the result
+    /// doesn't fit.
+    pub fn from_intfloat(value: IntFloat) -> Option<FixedIntFloat<SCALE>> {
+        if value.base == 0 {
+            return Some(FixedIntFloat { base: 0 });
+        }
+        let diff = (SCALE as isize).checked_sub(value.pow)?;
+        let factor = u32::try_from(diff.unsigned_abs())
+            .ok()
+            .and_then(|diff| 10_i128.checked_pow(diff));
+        let base = match (diff >= 0, factor) {
+            (true, factor) => (value.base as i128).checked_mul(factor?)?,
+            (false, Some(factor)) => div_round(value.base as i128, factor),
+            (false, None) => 0,
+        };
+        Some(FixedIntFloat {
+            base: isize::try_from(base).ok()?,
+        })
+    }
+
+    fn from_wide(base: i128, operation: &str) -> FixedIntFloat<SCALE> {
+        FixedIntFloat {
+            base: isize::try_from(base)
+                .unwrap_or_else(|_| panic!("attempt to {} with overflow", operation)),
+        }
+    }
+}
+
+impl<const SCALE: i32> From<FixedIntFloat<SCALE>> for IntFloat {
+    fn from(value: FixedIntFloat<SCALE>) -> IntFloat {
+        IntFloat::new(value.base, SCALE as isize)
+    }
+}
+
+impl<const SCALE: i32> Display for FixedIntFloat<SCALE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&IntFloat::new(self.base, SCALE as isize), f)
+    }
+}
+
+impl<const SCALE: i32> Add for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        FixedIntFloat {
+            base: self.base + rhs.base,
+        }
+    }
+}
+
+impl<const SCALE: i32> AddAssign for FixedIntFloat<SCALE> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.base += rhs.base;
+    }
+}
+
+impl<const SCALE: i32> Neg for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        FixedIntFloat { base: -self.base }
+    }
+}
+
+impl<const SCALE: i32> Sub for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        FixedIntFloat {
+            base: self.base - rhs.base,
+        }
+    }
+}
+
+impl<const SCALE: i32> SubAssign for FixedIntFloat<SCALE> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.base -= rhs.base;
+    }
+}
+
+impl<const SCALE: i32> Mul for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let product = self.base as i128 * rhs.base as i128;
+        Self::from_wide(div_round(product, Self::FACTOR), "multiply")
+    }
+}
+
+impl<const SCALE: i32> Div for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let numerator = (self.base as i128)
+            .checked_mul(Self::FACTOR)
+            .expect("attempt to divide with overflow");
+        Self::from_wide(div_round(numerator, rhs.base as i128), "divide")
+    }
+}
+
+impl<const SCALE: i32> Rem for FixedIntFloat<SCALE> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        FixedIntFloat {
+            base: self.base % rhs.base,
+        }
+    }
+}
+
+impl<const SCALE: i32> Zero for FixedIntFloat<SCALE> {
+    fn zero() -> Self {
+        FixedIntFloat { base: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.base == 0
+    }
+}
+
+impl<const SCALE: i32> One for FixedIntFloat<SCALE> {
+    fn one() -> Self {
+        Self::from_wide(Self::FACTOR, "create one")
+    }
+}
+
+impl<const SCALE: i32> std::iter::Sum for FixedIntFloat<SCALE> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, item| total + item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Cents = FixedIntFloat<2>;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size_of::<Cents>(), size_of::<isize>());
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let this = Cents::new(534);
+        let that = Cents::new(100);
+        assert_eq!(this + that, Cents::new(634));
+        assert_eq!(this - that, Cents::new(434));
+        assert_eq!(-this, Cents::new(-534));
+
+        let mut total = this;
+        total += that;
+        total -= Cents::new(34);
+        assert_eq!(total, Cents::new(600));
+        assert_eq!(
+            [this, that, that].into_iter().sum::<Cents>(),
+            Cents::new(734)
+        );
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let this = Cents::new(150);
+        assert_eq!(this * Cents::new(200), Cents::new(300));
+        assert_eq!(this * Cents::new(1), Cents::new(2));
+        assert_eq!(-this * Cents::new(1), Cents::new(-2));
+        assert_eq!(this / Cents::new(400), Cents::new(38));
+        assert_eq!(-this / Cents::new(400), Cents::new(-38));
+        assert_eq!(Cents::new(100) / Cents::new(300), Cents::new(33));
+        assert_eq!(Cents::new(534) % Cents::new(100), Cents::new(34));
+        assert_eq!(Cents::one() * this, this);
+
+        let units = FixedIntFloat::<0>::new(3);
+        assert_eq!(units * units, FixedIntFloat::new(9));
+        assert_eq!(units / FixedIntFloat::new(2), FixedIntFloat::new(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_overflow() {
+        let _ = Cents::new(isize::MAX) * Cents::new(1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_overflow() {
+        let _ = FixedIntFloat::<30>::new(isize::MAX) / FixedIntFloat::<30>::new(1);
+    }
+
+    #[test]
+    fn test_conversion() {
+        assert_eq!(
+            <IntFloat as From<_>>::from(Cents::new(534)),
+            IntFloat::new(534, 2)
+        );
+        assert_eq!(
+            Cents::from_intfloat(IntFloat::new(5, 0)),
+            Some(Cents::new(500))
+        );
+        assert_eq!(
+            Cents::from_intfloat(IntFloat::new(-12345, 3)),
+            Some(Cents::new(-1235))
+        );
+        assert_eq!(
+            Cents::from_intfloat(IntFloat::new(1, isize::MAX)),
+            Some(Cents::new(0))
+        );
+        assert_eq!(Cents::from_intfloat(IntFloat::new(isize::MAX, 0)), None);
+        assert_eq!(Cents::from_intfloat(IntFloat::new(1, isize::MIN)), None);
+        assert_eq!(
+            Cents::from_intfloat(IntFloat::new(0, isize::MIN)),
+            Some(Cents::new(0))
+        );
+    }
+
+    #[test]
+    fn test_display_ord() {
+        assert_eq!(Cents::new(-5).to_string(), "-0.05");
+        assert_eq!(format!("{:.1}", Cents::new(125)), "1.3");
+        assert!(Cents::new(-5) < Cents::zero());
+    }
+}
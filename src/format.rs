@@ -0,0 +1,444 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, Locale, SymbolPosition};
+use std::fmt;
+use std::fmt::{Display, Formatter, LowerExp, UpperExp};
+
+/// The SI prefixes from quecto (10^-30) to quetta (10^30), in steps of a thousand.
+const SI_PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    "R", "Q",
+];
+
+/// Display wrapper that writes an IntFloat with an SI prefix, as returned by
+/// `IntFloat::si_prefixed`.
+#[derive(Clone, Copy, Debug)]
+pub struct SiPrefixed<T>(IntFloat<T>);
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Display the value with the SI prefix that leaves one to three digits before the decimal
+    /// point, such as "12.5k" for 12500 and "3.2µ" for 0.0000032. Without a precision all
+    /// significant digits are written, otherwise the value is rounded half away from zero to that
+    /// number of decimals. Values beyond the largest or smallest prefix use that prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(12500, 0).si_prefixed().to_string(), "12.5k");
+    /// assert_eq!(IntFloat::new(32, 7).si_prefixed().to_string(), "3.2µ");
+    /// assert_eq!(format!("{:.2}W", IntFloat::new(-1234567, 0).si_prefixed()), "-1.23MW");
+    /// assert_eq!(format!("{:.1}", IntFloat::new(999960, 0).si_prefixed()), "1.0M");
+    /// ```
+    pub fn si_prefixed(self) -> SiPrefixed<T> {
+        SiPrefixed(self)
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Split the value into a power of a thousand within `limit` groups of three digits, and the
+    /// absolute value of what remains printed with `precision`, which has one to three digits
+    /// before the point unless the limit is reached. Also returns whether the value is
+    /// nonnegative.
+    fn thousands(&self, precision: Option<usize>, limit: isize) -> (bool, String, isize) {
+        let (base, pow) = self.canonical();
+        if base.is_zero() {
+            let (_, printed) = IntFloat::new(base, 0).plain_string(precision);
+            return (true, printed, 0);
+        }
+        let exponent = (base.unsigned_abs().ilog10() as isize).saturating_sub(pow);
+        let mut group = exponent.div_euclid(3).clamp(-limit, limit);
+        loop {
+            let scaled = IntFloat::new(base, pow + 3 * group);
+            let (is_nonnegative, printed) = scaled.plain_string(precision);
+            // Rounding can carry into a fourth digit, like 999.96 with one decimal.
+            let integer_digits = printed.find('.').unwrap_or(printed.len());
+            if integer_digits > 3 && group < limit {
+                group += 1;
+                continue;
+            }
+            return (is_nonnegative, printed, group);
+        }
+    }
+}
+
+impl<T: BaseInt> Display for SiPrefixed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (is_nonnegative, printed, group) = self.0.thousands(f.precision(), 10);
+        let prefix = SI_PREFIXES[(group + 10) as usize];
+        f.pad_integral(is_nonnegative, "", &format!("{}{}", printed, prefix))
+    }
+}
+
+/// Display wrapper that writes an IntFloat in engineering notation, as returned by
+/// `IntFloat::engineering`. It formats with a lowercase "e" for `Display` and `LowerExp`, and
+/// an uppercase "E" for `UpperExp`.
+#[derive(Clone, Copy, Debug)]
+pub struct Engineering<T>(IntFloat<T>);
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Display the value in engineering notation: scientific notation with an exponent that is
+    /// a multiple of three, so "12.5e3" instead of "1.25e4". Without a precision all significant
+    /// digits are written, otherwise the mantissa is rounded half away from zero to that number
+    /// of decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(12500, 0).engineering().to_string(), "12.5e3");
+    /// assert_eq!(IntFloat::new(-47, 9).engineering().to_string(), "-47e-9");
+    /// assert_eq!(format!("{:.2E}", IntFloat::new(1234567, 0).engineering()), "1.23E6");
+    /// ```
+    pub fn engineering(self) -> Engineering<T> {
+        Engineering(self)
+    }
+}
+
+impl<T: BaseInt> Engineering<T> {
+    fn fmt_engineering(&self, f: &mut Formatter<'_>, exp_char: char) -> fmt::Result {
+        let (is_nonnegative, printed, group) = self.0.thousands(f.precision(), isize::MAX / 3);
+        f.pad_integral(
+            is_nonnegative,
+            "",
+            &format!("{}{}{}", printed, exp_char, 3 * group),
+        )
+    }
+}
+
+impl<T: BaseInt> Display for Engineering<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_engineering(f, 'e')
+    }
+}
+
+impl<T: BaseInt> LowerExp for Engineering<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_engineering(f, 'e')
+    }
+}
+
+impl<T: BaseInt> UpperExp for Engineering<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_engineering(f, 'E')
+    }
+}
+
+/// Display wrapper that writes an IntFloat with the separators of a locale, as returned by
+/// `IntFloat::format_with`.
+#[derive(Clone, Copy, Debug)]
+pub struct Localized<'a, T> {
+    value: IntFloat<T>,
+    locale: &'a Locale,
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Display the value with the decimal separator of `locale`, and its grouping separator
+    /// between each group of three digits before the decimal point. Like for `Display`, a
+    /// precision rounds the value half away from zero to that number of decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, Locale};
+    /// let value = IntFloat::new(123456789, 2);
+    /// assert_eq!(value.format_with(&Locale::EUROPEAN).to_string(), "1.234.567,89");
+    /// assert_eq!(value.format_with(&Locale::US).to_string(), "1,234,567.89");
+    /// assert_eq!(format!("{:.1}", value.format_with(&Locale::new(',', None))), "1234567,9");
+    /// ```
+    pub fn format_with(self, locale: &Locale) -> Localized<'_, T> {
+        Localized {
+            value: self,
+            locale,
+        }
+    }
+
+    /// The absolute value as a decimal string with the separators of `locale`, and whether the
+    /// value is nonnegative.
+    pub(crate) fn localized_string(
+        &self,
+        locale: &Locale,
+        precision: Option<usize>,
+    ) -> (bool, String) {
+        let (is_nonnegative, printed) = self.plain_string(precision);
+        let (integer, fraction) = match printed.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (printed.as_str(), None),
+        };
+        let mut localized = match locale.grouping_separator() {
+            Some(separator) => group_digits(integer, separator),
+            None => integer.to_string(),
+        };
+        if let Some(fraction) = fraction {
+            localized.push(locale.decimal_separator());
+            localized.push_str(fraction);
+        }
+        (is_nonnegative, localized)
+    }
+}
+
+/// Insert `separator` between each group of three digits, counted from the right.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+impl<T: BaseInt> Display for Localized<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (is_nonnegative, printed) = self.value.localized_string(self.locale, f.precision());
+        f.pad_integral(is_nonnegative, "", &printed)
+    }
+}
+
+/// Display wrapper that writes an IntFloat as an amount of money, as returned by
+/// `IntFloat::format_currency`.
+#[derive(Clone, Copy, Debug)]
+pub struct CurrencyFormatted<'a, T> {
+    value: IntFloat<T>,
+    symbol: &'a str,
+    locale: &'a Locale,
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Display the value as an amount of money, with the separators of `locale` and `symbol`
+    /// at the position the locale uses. A minus sign always comes first: "-$1,234.56" and
+    /// "-1.234,56 €". A symbol of letters such as "CHF" always gets a space: "CHF 1,234.56". Like
+    /// for `Display`, a precision rounds the value half away from zero to that number of
+    /// decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, Locale, SymbolPosition};
+    /// let value = IntFloat::new(-123456, 2);
+    /// assert_eq!(value.format_currency("$", &Locale::US).to_string(), "-$1,234.56");
+    /// assert_eq!(value.format_currency("€", &Locale::EUROPEAN).to_string(), "-1.234,56 €");
+    /// let french = Locale::new(',', Some(' ')).with_symbol_position(SymbolPosition::AfterWithSpace);
+    /// assert_eq!(format!("{:.0}", value.format_currency("€", &french)), "-1 235 €");
+    /// ```
+    pub fn format_currency<'a>(
+        self,
+        symbol: &'a str,
+        locale: &'a Locale,
+    ) -> CurrencyFormatted<'a, T> {
+        CurrencyFormatted {
+            value: self,
+            symbol,
+            locale,
+        }
+    }
+}
+
+/// Whether the symbol is a code of letters such as "CHF", which is never written against the
+/// number.
+fn is_code(symbol: &str) -> bool {
+    !symbol.is_empty() && symbol.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+impl<T: BaseInt> Display for CurrencyFormatted<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (is_nonnegative, number) = self.value.localized_string(self.locale, f.precision());
+        let printed = match self.locale.symbol_position() {
+            SymbolPosition::Before if !is_code(self.symbol) => {
+                format!("{}{}", self.symbol, number)
+            }
+            SymbolPosition::Before | SymbolPosition::BeforeWithSpace => {
+                format!("{} {}", self.symbol, number)
+            }
+            SymbolPosition::AfterWithSpace => format!("{} {}", number, self.symbol),
+        };
+        f.pad_integral(is_nonnegative, "", &printed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloat128, Locale, SymbolPosition};
+
+    fn si(value: IntFloat) -> String {
+        value.si_prefixed().to_string()
+    }
+
+    #[test]
+    fn test_si_prefixed() {
+        assert_eq!(si(IntFloat::new(0, 5)), "0");
+        assert_eq!(si(IntFloat::new(1, 0)), "1");
+        assert_eq!(si(IntFloat::new(999, 0)), "999");
+        assert_eq!(si(IntFloat::new(1000, 0)), "1k");
+        assert_eq!(si(IntFloat::new(12500, 2)), "125");
+        assert_eq!(si(IntFloat::new(-12345, -3)), "-12.345M");
+        assert_eq!(si(IntFloat::new(5, 1)), "500m");
+        assert_eq!(si(IntFloat::new(1, 3)), "1m");
+        assert_eq!(si(IntFloat::new(1, 4)), "100µ");
+        assert_eq!(si(IntFloat::new(47, 12)), "47p");
+        assert_eq!(si(IntFloat::new(1, -30)), "1Q");
+        assert_eq!(si(IntFloat::new(1, -33)), "1000Q");
+        assert_eq!(si(IntFloat::new(1, 31)), "0.1q");
+        assert_eq!(si(IntFloat::new(1, 33)), "0.001q");
+        assert_eq!(
+            IntFloat128::new(i128::MAX, 0).si_prefixed().to_string(),
+            "170141183.460469231731687303715884105727Q"
+        );
+    }
+
+    #[test]
+    fn test_si_prefixed_precision() {
+        let value = IntFloat::new(123456, 3).si_prefixed();
+        assert_eq!(format!("{:.2}", value), "123.46");
+        assert_eq!(format!("{:.0}", value), "123");
+        assert_eq!(
+            format!("{:.1}", IntFloat::new(99996, 8).si_prefixed()),
+            "1.0m"
+        );
+        assert_eq!(
+            format!("{:.1}", IntFloat::new(-99996, 2).si_prefixed()),
+            "-1.0k"
+        );
+        assert_eq!(
+            format!("{:.3}", IntFloat::new(15, -3).si_prefixed()),
+            "15.000k"
+        );
+        assert_eq!(format!("{:.1}", IntFloat::new(0, 0).si_prefixed()), "0.0");
+        assert_eq!(
+            format!("{:>8.1}", IntFloat::new(2500, 0).si_prefixed()),
+            "    2.5k"
+        );
+        assert_eq!(
+            format!("{:+}", IntFloat::new(2500, 0).si_prefixed()),
+            "+2.5k"
+        );
+    }
+
+    #[test]
+    fn test_format_with() {
+        let eu = |value: IntFloat| value.format_with(&Locale::EUROPEAN).to_string();
+        assert_eq!(eu(IntFloat::new(0, 0)), "0");
+        assert_eq!(eu(IntFloat::new(5, 1)), "0,5");
+        assert_eq!(eu(IntFloat::new(999, 0)), "999");
+        assert_eq!(eu(IntFloat::new(1000, 0)), "1.000");
+        assert_eq!(eu(IntFloat::new(-123456, 0)), "-123.456");
+        assert_eq!(eu(IntFloat::new(-1234567, 3)), "-1.234,567");
+        assert_eq!(eu(IntFloat::new(1, -7)), "10.000.000");
+        assert_eq!(eu(IntFloat::new(12345, 8)), "0,00012345");
+
+        let swiss = Locale::new('.', Some('\''));
+        let value = IntFloat::new(123456789, 2);
+        assert_eq!(value.format_with(&swiss).to_string(), "1'234'567.89");
+        assert_eq!(format!("{:.0}", value.format_with(&swiss)), "1'234'568");
+        assert_eq!(
+            format!("{:.4}", value.format_with(&Locale::US)),
+            "1,234,567.8900"
+        );
+        assert_eq!(
+            format!("{:>14}", value.format_with(&Locale::US)),
+            "  1,234,567.89"
+        );
+        assert_eq!(
+            format!("{:+}", value.format_with(&Locale::US)),
+            "+1,234,567.89"
+        );
+        assert_eq!(
+            IntFloat128::new(i128::MIN, 0)
+                .format_with(&Locale::US)
+                .to_string(),
+            "-170,141,183,460,469,231,731,687,303,715,884,105,728"
+        );
+    }
+
+    #[test]
+    fn test_format_parse_round_trip() {
+        for locale in [Locale::US, Locale::EUROPEAN, Locale::new(',', Some(' '))] {
+            let value = IntFloat::new(-987654321, 3);
+            let printed = value.format_with(&locale).to_string();
+            assert_eq!(IntFloat::parse_with_locale(&printed, &locale), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_format_currency() {
+        let value = IntFloat::new(123456789, 2);
+        let us = |value: IntFloat| value.format_currency("$", &Locale::US).to_string();
+        assert_eq!(us(value), "$1,234,567.89");
+        assert_eq!(us(-value), "-$1,234,567.89");
+        assert_eq!(us(IntFloat::new(5, 0)), "$5");
+        assert_eq!(us(IntFloat::new(-5, 2)), "-$0.05");
+        assert_eq!(us(IntFloat::new(0, 2)), "$0.00");
+
+        let eu = |value: IntFloat| value.format_currency("€", &Locale::EUROPEAN).to_string();
+        assert_eq!(eu(value), "1.234.567,89 €");
+        assert_eq!(eu(-value), "-1.234.567,89 €");
+        assert_eq!(
+            value.format_currency("USD", &Locale::US).to_string(),
+            "USD 1,234,567.89"
+        );
+        assert_eq!(
+            value.format_currency("US$", &Locale::US).to_string(),
+            "US$1,234,567.89"
+        );
+
+        let swiss =
+            Locale::new('.', Some('\'')).with_symbol_position(SymbolPosition::BeforeWithSpace);
+        assert_eq!(
+            value.format_currency("CHF", &swiss).to_string(),
+            "CHF 1'234'567.89"
+        );
+        assert_eq!(
+            format!("{:.1}", (-value).format_currency("CHF", &swiss)),
+            "-CHF 1'234'567.9"
+        );
+        assert_eq!(
+            format!(
+                "{:.2}",
+                IntFloat::new(7, 0).format_currency("$", &Locale::US)
+            ),
+            "$7.00"
+        );
+        assert_eq!(
+            format!(
+                "{:>12}",
+                IntFloat::new(-7, 0).format_currency("$", &Locale::US)
+            ),
+            "         -$7"
+        );
+    }
+
+    #[test]
+    fn test_engineering() {
+        let eng = |value: IntFloat| value.engineering().to_string();
+        assert_eq!(eng(IntFloat::new(0, 3)), "0e0");
+        assert_eq!(eng(IntFloat::new(1, 0)), "1e0");
+        assert_eq!(eng(IntFloat::new(999, 0)), "999e0");
+        assert_eq!(eng(IntFloat::new(1000, 0)), "1e3");
+        assert_eq!(eng(IntFloat::new(-125, 1)), "-12.5e0");
+        assert_eq!(eng(IntFloat::new(5, 1)), "500e-3");
+        assert_eq!(eng(IntFloat::new(1, 4)), "100e-6");
+        assert_eq!(eng(IntFloat::new(1, -40)), "10e39");
+        assert_eq!(eng(IntFloat::new(1, 100)), "100e-102");
+        assert_eq!(eng(IntFloat::new(120, -4)), "1.2e6");
+    }
+
+    #[test]
+    fn test_engineering_precision() {
+        let value = IntFloat::new(123456, 0).engineering();
+        assert_eq!(format!("{:.1}", value), "123.5e3");
+        assert_eq!(format!("{:.0e}", value), "123e3");
+        assert_eq!(format!("{:.4E}", value), "123.4560E3");
+        assert_eq!(
+            format!("{:.1}", IntFloat::new(-99996, 2).engineering()),
+            "-1.0e3"
+        );
+        assert_eq!(
+            format!("{:.2}", IntFloat::new(0, 0).engineering()),
+            "0.00e0"
+        );
+        assert_eq!(
+            format!("{:>8}", IntFloat::new(25, -2).engineering()),
+            "   2.5e3"
+        );
+    }
+}
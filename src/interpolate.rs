@@ -0,0 +1,128 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloat128, RoundingMode};
+
+fn wide<T: BaseInt>(value: IntFloat<T>) -> IntFloat128 {
+    value.cast().expect("every base fits in an i128")
+}
+
+/// Interpolation between two values, with exactly `decimals` decimals, rounded with `mode`. The
+/// intermediate results are exact, with an i128 base, so only the final rounding loses
+/// precision. Both panic if the result doesn't fit in the base; `checked_midpoint` and
+/// `checked_lerp` return None instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, RoundingMode};
+/// let (bid, ask) = (IntFloat::new(10001, 2), IntFloat::new(10004, 2));
+/// assert_eq!(bid.midpoint(ask, 3, RoundingMode::HalfEven).to_string(), "100.025");
+/// assert_eq!(bid.midpoint(ask, 2, RoundingMode::HalfEven).to_string(), "100.02");
+/// let quarter = IntFloat::new(25, 2);
+/// assert_eq!(bid.lerp(ask, quarter, 2, RoundingMode::HalfUp).to_string(), "100.02");
+/// assert_eq!(bid.lerp(ask, IntFloat::new(2, 0), 2, RoundingMode::HalfUp).to_string(), "100.07");
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// Halfway between `self` and `other`.
+    pub fn midpoint(self, other: Self, decimals: isize, mode: RoundingMode) -> Self {
+        self.checked_midpoint(other, decimals, mode)
+            .expect("attempt to interpolate with overflow")
+    }
+
+    /// `self + (other - self) * t`: `self` for a `t` of zero and `other` for one. A `t` outside
+    /// of [0, 1] extrapolates.
+    pub fn lerp(self, other: Self, t: Self, decimals: isize, mode: RoundingMode) -> Self {
+        self.checked_lerp(other, t, decimals, mode)
+            .expect("attempt to interpolate with overflow")
+    }
+
+    pub fn checked_midpoint(
+        self,
+        other: Self,
+        decimals: isize,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        wide(self)
+            .checked_add(wide(other))?
+            .checked_div_with_mode(IntFloat::new(2, 0), decimals, mode)?
+            .cast()
+    }
+
+    pub fn checked_lerp(
+        self,
+        other: Self,
+        t: Self,
+        decimals: isize,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let step = wide(other).checked_sub(wide(self))?.checked_mul(wide(t))?;
+        wide(self)
+            .checked_add(step)?
+            .checked_div_with_mode(IntFloat::new(1, 0), decimals, mode)?
+            .cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generic, IntFloat, RoundingMode};
+
+    #[test]
+    fn test_midpoint() {
+        let max = generic::IntFloat::<i8>::new(i8::MAX, 0);
+        assert_eq!(
+            max.midpoint(max, 0, RoundingMode::HalfUp),
+            generic::IntFloat::new(i8::MAX, 0)
+        );
+        let (a, b) = (IntFloat::new(-5, 0), IntFloat::new(2, 0));
+        assert_eq!(a.midpoint(b, 0, RoundingMode::HalfUp), IntFloat::new(-2, 0));
+        assert_eq!(a.midpoint(b, 0, RoundingMode::Floor), IntFloat::new(-2, 0));
+        assert_eq!(
+            a.midpoint(b, 0, RoundingMode::Ceiling),
+            IntFloat::new(-1, 0)
+        );
+        let this = a.midpoint(b, 3, RoundingMode::HalfUp);
+        assert_eq!((this.base, this.pow), (-1500, 3));
+        assert_eq!(
+            IntFloat::new(1, 2).midpoint(IntFloat::new(2, 0), 3, RoundingMode::TowardZero),
+            IntFloat::new(1005, 3)
+        );
+        assert_eq!(
+            IntFloat::new(1, 0).checked_midpoint(IntFloat::new(1, 0), 19, RoundingMode::TowardZero),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lerp() {
+        let (a, b) = (IntFloat::new(10, 0), IntFloat::new(20, 0));
+        let mode = RoundingMode::HalfEven;
+        assert_eq!(a.lerp(b, IntFloat::new(0, 0), 0, mode), a);
+        assert_eq!(a.lerp(b, IntFloat::new(1, 0), 0, mode), b);
+        let this = a.lerp(b, IntFloat::new(1, 1), 2, mode);
+        assert_eq!((this.base, this.pow), (1100, 2));
+        assert_eq!(
+            a.lerp(b, IntFloat::new(-5, 1), 0, mode),
+            IntFloat::new(5, 0)
+        );
+        assert_eq!(
+            a.lerp(b, IntFloat::new(333, 3), 1, mode),
+            IntFloat::new(133, 1)
+        );
+        let max = generic::IntFloat::<i8>::new(i8::MAX, 0);
+        let min = generic::IntFloat::<i8>::new(i8::MIN, 0);
+        assert_eq!(
+            min.lerp(max, generic::IntFloat::new(5, 1), 1, mode),
+            generic::IntFloat::new(-5, 1)
+        );
+        assert_eq!(
+            min.checked_lerp(max, generic::IntFloat::new(2, 0), 0, mode),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to interpolate with overflow")]
+    fn test_midpoint_overflow() {
+        IntFloat::new(1, 0).midpoint(IntFloat::new(1, 0), 19, RoundingMode::HalfUp);
+    }
+}
@@ -0,0 +1,370 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, RoundingMode};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug)]
+/// Closed interval [lo, hi] of IntFloats, for tracking a range of possible values such as a
+/// measurement with its uncertainty. Addition, subtraction and multiplication are exact, so the
+/// result contains every outcome of the operation on values in the operands. Division and
+/// `round_outward` have to round, and always do so outward: the lower bound is rounded down and
+/// the upper bound up, so the guarantee still holds.
+///
+/// Like for IntFloat, the operators panic if a bound doesn't fit in the base, and there are
+/// `checked_` versions that return None instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloatInterval};
+/// let length = IntFloatInterval::new(IntFloat::new(995, 2), IntFloat::new(1005, 2));
+/// let width = IntFloatInterval::new(IntFloat::new(49, 1), IntFloat::new(51, 1));
+/// let area = length * width;
+/// assert_eq!(area.to_string(), "[48.755, 51.255]");
+/// assert_eq!(area.round_outward(1).to_string(), "[48.7, 51.3]");
+/// let ratio = length.div_with_precision(width, 3);
+/// assert_eq!(ratio.to_string(), "[1.950, 2.052]");
+/// assert!(ratio.contains(IntFloat::new(2, 0)));
+/// ```
+pub struct IntFloatInterval<T> {
+    lo: IntFloat<T>,
+    hi: IntFloat<T>,
+}
+
+impl<T: BaseInt> IntFloatInterval<T> {
+    /// The interval from `lo` to `hi`, inclusive. Panics if `lo` is above `hi`.
+    pub fn new(lo: IntFloat<T>, hi: IntFloat<T>) -> Self {
+        Self::checked_new(lo, hi)
+            .expect("attempt to create an interval with its lower bound above its upper bound")
+    }
+
+    /// Like `new`, but None if `lo` is above `hi`.
+    pub fn checked_new(lo: IntFloat<T>, hi: IntFloat<T>) -> Option<Self> {
+        (lo <= hi).then_some(IntFloatInterval { lo, hi })
+    }
+
+    /// The interval that only contains `value`.
+    pub fn point(value: IntFloat<T>) -> Self {
+        IntFloatInterval {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    pub fn lo(self) -> IntFloat<T> {
+        self.lo
+    }
+
+    pub fn hi(self) -> IntFloat<T> {
+        self.hi
+    }
+
+    /// The distance between the bounds. Panics if it doesn't fit in the base.
+    pub fn width(self) -> IntFloat<T> {
+        self.hi
+            .checked_sub(self.lo)
+            .expect("attempt to subtract with overflow")
+    }
+
+    pub fn contains(self, value: IntFloat<T>) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Whether every value in `other` is also in this interval.
+    pub fn encloses(self, other: Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// The values in both intervals, or None if they don't overlap.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::checked_new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// The smallest interval that contains both intervals.
+    pub fn hull(self, other: Self) -> Self {
+        IntFloatInterval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    /// Round the lower bound down and the upper bound up to `dp` decimals, so that the result
+    /// still contains the whole interval.
+    pub fn round_outward(self, dp: isize) -> Self {
+        IntFloatInterval {
+            lo: self.lo.round_dp_with(dp, RoundingMode::Floor),
+            hi: self.hi.round_dp_with(dp, RoundingMode::Ceiling),
+        }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(IntFloatInterval {
+            lo: self.lo.checked_add(rhs.lo)?,
+            hi: self.hi.checked_add(rhs.hi)?,
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(IntFloatInterval {
+            lo: self.lo.checked_sub(rhs.hi)?,
+            hi: self.hi.checked_sub(rhs.lo)?,
+        })
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let products = [
+            self.lo.checked_mul(rhs.lo)?,
+            self.lo.checked_mul(rhs.hi)?,
+            self.hi.checked_mul(rhs.lo)?,
+            self.hi.checked_mul(rhs.hi)?,
+        ];
+        Some(IntFloatInterval {
+            lo: products.into_iter().min()?,
+            hi: products.into_iter().max()?,
+        })
+    }
+
+    /// Divide, with the bounds rounded outward to `decimals` decimals. Panics if `rhs` contains
+    /// zero, or if a bound doesn't fit in the base.
+    pub fn div_with_precision(self, rhs: Self, decimals: isize) -> Self {
+        assert!(
+            !rhs.contains(IntFloat::new(T::zero(), 0)),
+            "attempt to divide by an interval that contains zero"
+        );
+        self.checked_div_with_precision(rhs, decimals)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Like `div_with_precision`, but None if `rhs` contains zero or a bound doesn't fit.
+    pub fn checked_div_with_precision(self, rhs: Self, decimals: isize) -> Option<Self> {
+        if rhs.contains(IntFloat::new(T::zero(), 0)) {
+            return None;
+        }
+        let quotients = |mode| {
+            Some([
+                self.lo.checked_div_with_mode(rhs.lo, decimals, mode)?,
+                self.lo.checked_div_with_mode(rhs.hi, decimals, mode)?,
+                self.hi.checked_div_with_mode(rhs.lo, decimals, mode)?,
+                self.hi.checked_div_with_mode(rhs.hi, decimals, mode)?,
+            ])
+        };
+        Some(IntFloatInterval {
+            lo: quotients(RoundingMode::Floor)?.into_iter().min()?,
+            hi: quotients(RoundingMode::Ceiling)?.into_iter().max()?,
+        })
+    }
+}
+
+impl<T: BaseInt> PartialEq for IntFloatInterval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<T: BaseInt> Eq for IntFloatInterval<T> {}
+
+impl<T: BaseInt> Hash for IntFloatInterval<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lo.hash(state);
+        self.hi.hash(state);
+    }
+}
+
+impl<T: BaseInt> From<IntFloat<T>> for IntFloatInterval<T> {
+    fn from(value: IntFloat<T>) -> Self {
+        IntFloatInterval::point(value)
+    }
+}
+
+/// Formatted as "[lo, hi]".
+impl<T: BaseInt> Display for IntFloatInterval<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl<T: BaseInt> Add for IntFloatInterval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl<T: BaseInt> Sub for IntFloatInterval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl<T: BaseInt> Mul for IntFloatInterval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl<T: BaseInt> Neg for IntFloatInterval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        IntFloatInterval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloatInterval};
+
+    fn interval(lo: &str, hi: &str) -> IntFloatInterval {
+        IntFloatInterval::new(lo.parse().unwrap(), hi.parse().unwrap())
+    }
+
+    #[test]
+    fn test_new() {
+        let this = interval("1.5", "2");
+        assert_eq!(
+            (this.lo(), this.hi()),
+            (IntFloat::new(15, 1), IntFloat::new(2, 0))
+        );
+        assert_eq!(this.width(), IntFloat::new(5, 1));
+        assert_eq!(
+            IntFloatInterval::checked_new(IntFloat::new(2, 0), IntFloat::new(1, 0)),
+            None
+        );
+        let point = IntFloatInterval::from(IntFloat::new(3, 0));
+        assert_eq!(point, interval("3", "3.00"));
+        assert_eq!(point.width(), IntFloat::new(0, 0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempt to create an interval with its lower bound above its upper bound"
+    )]
+    fn test_new_reversed() {
+        interval("2", "1");
+    }
+
+    #[test]
+    fn test_contains() {
+        let this = interval("-1", "2.5");
+        assert!(this.contains(IntFloat::new(-1, 0)));
+        assert!(this.contains(IntFloat::new(250, 2)));
+        assert!(!this.contains(IntFloat::new(2501, 3)));
+        assert!(this.encloses(interval("0", "2.5")));
+        assert!(!this.encloses(interval("-2", "0")));
+        assert_eq!(
+            this.intersection(interval("2", "3")),
+            Some(interval("2", "2.5"))
+        );
+        assert_eq!(
+            this.intersection(interval("2.5", "3")),
+            Some(interval("2.5", "2.5"))
+        );
+        assert_eq!(this.intersection(interval("3", "4")), None);
+        assert_eq!(this.hull(interval("3", "4")), interval("-1", "4"));
+    }
+
+    #[test]
+    fn test_large_scale_gap() {
+        let (tiny, one, two) = (
+            IntFloat::new(1, 30),
+            IntFloat::new(1, 0),
+            IntFloat::new(2, 0),
+        );
+        let this = IntFloatInterval::new(tiny, one);
+        assert_eq!((this.lo(), this.hi()), (tiny, one));
+        assert_eq!(IntFloatInterval::checked_new(one, tiny), None);
+        assert!(this.contains(IntFloat::new(1, 20)));
+        assert!(!this.contains(IntFloat::new(1, 35)));
+        assert!(!this.contains(IntFloat::new(1, 31)));
+        assert!(this.encloses(IntFloatInterval::new(IntFloat::new(5, 30), one)));
+        let other = IntFloatInterval::new(IntFloat::new(-1, 50), IntFloat::new(1, 40));
+        assert_eq!(this.intersection(other), None);
+        assert_eq!(
+            this.hull(other),
+            IntFloatInterval::new(IntFloat::new(-1, 50), one)
+        );
+        assert_eq!(
+            this.checked_mul(IntFloatInterval::new(IntFloat::new(-1, 0), two)),
+            Some(IntFloatInterval::new(IntFloat::new(-1, 0), two))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (interval("1", "2"), interval("-0.5", "3"));
+        assert_eq!(a + b, interval("0.5", "5"));
+        assert_eq!(a - b, interval("-2", "2.5"));
+        assert_eq!(b - a, interval("-2.5", "2"));
+        assert_eq!(a * b, interval("-1", "6"));
+        assert_eq!(b * b, interval("-1.5", "9"));
+        assert_eq!(-b, interval("-3", "0.5"));
+        assert_eq!(
+            interval("-2", "-1") * interval("-3", "-2"),
+            interval("2", "6")
+        );
+        let large = IntFloatInterval::point(IntFloat::new(isize::MAX, 0));
+        assert_eq!(large.checked_add(large), None);
+        assert_eq!(large.checked_mul(interval("1", "2")), None);
+        assert_eq!(large.checked_sub(large), Some(interval("0", "0")));
+    }
+
+    #[test]
+    fn test_div_with_precision() {
+        let one = IntFloatInterval::point(IntFloat::new(1, 0));
+        assert_eq!(
+            one.div_with_precision(interval("3", "3"), 3),
+            interval("0.333", "0.334")
+        );
+        assert_eq!(
+            interval("-1", "2").div_with_precision(interval("3", "6"), 2),
+            interval("-0.34", "0.67")
+        );
+        assert_eq!(
+            interval("1", "2").div_with_precision(interval("-4", "-2"), 2),
+            interval("-1", "-0.25")
+        );
+        assert_eq!(
+            one.div_with_precision(interval("4", "8"), 2),
+            interval("0.12", "0.25")
+        );
+        assert_eq!(one.checked_div_with_precision(interval("-1", "1"), 2), None);
+        assert_eq!(one.checked_div_with_precision(interval("0", "1"), 2), None);
+        assert_eq!(one.checked_div_with_precision(interval("1", "2"), 19), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by an interval that contains zero")]
+    fn test_div_by_zero() {
+        interval("1", "2").div_with_precision(interval("-1", "1"), 2);
+    }
+
+    #[test]
+    fn test_round_outward() {
+        assert_eq!(
+            interval("-1.234", "5.678").round_outward(2),
+            interval("-1.24", "5.68")
+        );
+        assert_eq!(interval("1.2", "1.2").round_outward(0), interval("1", "2"));
+        assert_eq!(
+            interval("1.25", "1.5").round_outward(3),
+            interval("1.25", "1.5")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(interval("-1.50", "2").to_string(), "[-1.50, 2]");
+    }
+}
@@ -1,10 +1,16 @@
-use num_traits::{Num, One, Pow, ToPrimitive, Zero};
+use crate::{BaseInt, IntFloatError, Locale, RoundingMode, TryFromFloatError};
+use num_traits::{Bounded, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
 use std::fmt;
-use std::fmt::{Debug, Display, Formatter};
-use std::num::ParseIntError;
-use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign};
+use std::fmt::{Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+use std::hash::{Hash, Hasher};
+use std::num::TryFromIntError;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
 
-#[derive(Clone, Copy, Hash, Eq, PartialOrd, Default, Debug)]
+#[derive(Clone, Copy, Default, Debug)]
 /// Simplified alternative to rust_decimal::Decimal to that allows hashing. Standard
 /// library floats can't be hashed which is needed when using (for example) HashMaps.
 /// The number is converted to a x 10^-b, with a and b being integers (and thus Hashable).
@@ -12,6 +18,11 @@ use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign};
 /// Note that when accuracy is important, rust_decimal::Decimal is a safer alternative. But when
 /// speed is needed, this implementation is faster.
 ///
+/// The integer type of a can be any signed primitive integer. Usually, one of the aliases is used:
+/// IntFloat (isize), IntFloat32 (i32), IntFloat64 (i64) or IntFloat128 (i128).
+///
+/// The layout is `#[repr(C)]`: the base, followed by the pow.
+///
 /// # Examples
 ///
 /// ```
@@ -23,86 +34,658 @@ use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign};
 /// let c = IntFloat::from(5.2, 1);
 /// assert_ne!(b, c);
 /// ```
-pub struct IntFloat {
-    base: isize,
-    pow: isize,
+#[repr(C)]
+#[cfg_attr(
+    feature = "diesel-postgres",
+    derive(
+        ::diesel::expression::AsExpression,
+        ::diesel::deserialize::FromSqlRow
+    ),
+    diesel(sql_type = ::diesel::sql_types::Numeric)
+)]
+pub struct IntFloat<T> {
+    pub(crate) base: T,
+    pub(crate) pow: isize,
 }
 
-impl IntFloat {
-    pub fn new(base: isize, pow: isize) -> Self {
+impl<T: BaseInt> IntFloat<T> {
+    /// The smallest value at scale 0: the minimum of the base.
+    pub const MIN: Self = IntFloat {
+        base: T::MIN,
+        pow: 0,
+    };
+
+    /// The largest value at scale 0: the maximum of the base.
+    pub const MAX: Self = IntFloat {
+        base: T::MAX,
+        pow: 0,
+    };
+
+    /// Zero at scale 0. Together with `ONE`, `TEN` and the functions `pi` and `e`, which round
+    /// to a number of decimals, these are the common constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, IntFloat128};
+    /// const PI: IntFloat = IntFloat::pi(5);
+    /// assert_eq!(PI.to_string(), "3.14159");
+    /// let radius = IntFloat::new(25, 1);
+    /// assert_eq!((PI * radius * radius).round_dp(2).to_string(), "19.63");
+    /// assert_eq!(IntFloat128::pi(37).to_string(), "3.1415926535897932384626433832795028842");
+    /// assert_eq!(IntFloat::e(3) * IntFloat::TEN, IntFloat::new(2718, 2));
+    /// assert_eq!(IntFloat::ZERO + IntFloat::ONE, IntFloat::new(1, 0));
+    /// ```
+    pub const ZERO: Self = IntFloat::zero();
+
+    /// One at scale 0.
+    pub const ONE: Self = IntFloat::one();
+
+    /// Ten at scale 0.
+    pub const TEN: Self = IntFloat {
+        base: T::TEN,
+        pow: 0,
+    };
+
+    /// The value `base * 10^-pow`. Together with `const_add`, `const_sub` and `const_mul`, this
+    /// can build tables of constants at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// const TICK: IntFloat = IntFloat::new(25, 2);
+    /// const TICKS: [IntFloat; 3] = [
+    ///     TICK,
+    ///     TICK.const_mul(IntFloat::new(2, 0)),
+    ///     TICK.const_add(IntFloat::new(5, 1)),
+    /// ];
+    /// assert_eq!(TICKS[1], IntFloat::new(5, 1));
+    /// assert_eq!(TICKS[2].to_string(), "0.75");
+    /// const SPREAD: IntFloat = IntFloat::one().const_sub(IntFloat::new(999, 3));
+    /// assert_eq!(SPREAD.to_string(), "0.001");
+    /// ```
+    ///
+    /// Overflow in a constant is a compile error:
+    ///
+    /// ```compile_fail
+    /// use intfloat::IntFloat;
+    /// const TOO_LARGE: IntFloat = IntFloat::new(isize::MAX, 0).const_add(IntFloat::new(1, 0));
+    /// ```
+    pub const fn new(base: T, pow: isize) -> Self {
         IntFloat { base, pow }
     }
 
-    pub fn from(float: f32, decimals: isize) -> Self {
+    /// Zero at scale 0, like `Zero::zero` but usable in constants.
+    pub const fn zero() -> Self {
+        IntFloat {
+            base: T::ZERO,
+            pow: 0,
+        }
+    }
+
+    /// One at scale 0, like `One::one` but usable in constants.
+    pub const fn one() -> Self {
         IntFloat {
-            base: (float * isize::pow(10, decimals as u32) as f32).round() as isize,
+            base: T::ONE,
+            pow: 0,
+        }
+    }
+
+    /// The base, i.e. the value without its decimal point: 1250 for 12.50. Equal values can have
+    /// different mantissas, so use `normalize` first to compare them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// let price = IntFloat::new(1250, 2);
+    /// assert_eq!((price.mantissa(), price.scale()), (1250, 2));
+    /// assert_eq!(price.normalize().into_parts(), (125, 1));
+    /// let (base, pow) = price.into_parts();
+    /// assert_eq!(IntFloat::new(base, pow), price);
+    /// ```
+    pub const fn mantissa(self) -> T {
+        self.base
+    }
+
+    /// The pow, i.e. the number of decimals: the value is `mantissa() * 10^-scale()`. It is
+    /// negative for values like IntFloat::new(5, -2), which is 500.
+    pub const fn scale(self) -> isize {
+        self.pow
+    }
+
+    /// The mantissa and the scale, the inverse of `new`.
+    pub const fn into_parts(self) -> (T, isize) {
+        (self.base, self.pow)
+    }
+
+    /// Round an f32 to `decimals` decimals, half away from zero. An f32 only holds about 7
+    /// significant digits, so prefer `from_f64` when the input is (or was) a double.
+    pub fn from(float: f32, decimals: isize) -> Self {
+        Self::from_with_rounding(float, decimals, RoundingMode::HalfUp)
+    }
+
+    /// Round an f32 to `decimals` decimals like `from`, but with `mode`, e.g. half to even for
+    /// banker's rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, RoundingMode};
+    /// assert_eq!(IntFloat::from(2.5, 0), IntFloat::new(3, 0));
+    /// assert_eq!(IntFloat::from_with_rounding(2.5, 0, RoundingMode::HalfEven), IntFloat::new(2, 0));
+    /// assert_eq!(IntFloat::from_f64_with_rounding(0.125, 2, RoundingMode::HalfEven).to_string(), "0.12");
+    /// assert_eq!(IntFloat::from_f64_with_rounding(-0.125, 2, RoundingMode::Floor).to_string(), "-0.13");
+    /// ```
+    pub fn from_with_rounding(float: f32, decimals: isize, mode: RoundingMode) -> Self {
+        Self::saturate_float(
+            Self::try_from_float(format!("{:e}", float), Some((decimals, mode))),
+            float.is_sign_negative(),
+            decimals,
+        )
+    }
+
+    /// Round an f64 to `decimals` decimals, half away from zero.
+    ///
+    /// The rounding starts from the shortest decimal representation that converts back to the
+    /// same float (the one that Display prints), so 1.005 rounds to 1.01 even though the nearest
+    /// double is slightly below it. Values that don't fit saturate, and NaN becomes zero; use
+    /// `try_from_f64` to catch those cases instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::from_f64(1234567.891, 3), IntFloat::new(1234567891, 3));
+    /// assert_ne!(IntFloat::from(1234567.891, 3), IntFloat::new(1234567891, 3));
+    /// assert_eq!(IntFloat::from_f64(1.005, 2), IntFloat::new(101, 2));
+    /// ```
+    pub fn from_f64(float: f64, decimals: isize) -> Self {
+        Self::from_f64_with_rounding(float, decimals, RoundingMode::HalfUp)
+    }
+
+    /// Round an f64 to `decimals` decimals like `from_f64`, but with `mode`.
+    pub fn from_f64_with_rounding(float: f64, decimals: isize, mode: RoundingMode) -> Self {
+        Self::saturate_float(
+            Self::try_from_float(format!("{:e}", float), Some((decimals, mode))),
+            float.is_sign_negative(),
+            decimals,
+        )
+    }
+
+    /// Round an f64 to `decimals` decimals like `from_f64`, but fail if the float is NaN or
+    /// infinite, or if the result doesn't fit in the base.
+    pub fn try_from_f64(float: f64, decimals: isize) -> Result<Self, TryFromFloatError> {
+        Self::try_from_float(
+            format!("{:e}", float),
+            Some((decimals, RoundingMode::HalfUp)),
+        )
+    }
+
+    fn saturate_float(
+        result: Result<Self, TryFromFloatError>,
+        is_negative: bool,
+        decimals: isize,
+    ) -> Self {
+        match result {
+            Ok(this) => this,
+            Err(TryFromFloatError::NaN) => IntFloat::new(T::zero(), decimals),
+            Err(_) if is_negative => IntFloat::new(T::min_value(), decimals),
+            Err(_) => IntFloat::new(T::max_value(), decimals),
+        }
+    }
+
+    /// Round a float, printed in scientific notation, to a number of decimals with a rounding mode
+    /// using only integer arithmetic. Without those, the printed digits are kept exactly.
+    fn try_from_float(
+        printed: String,
+        rounding: Option<(isize, RoundingMode)>,
+    ) -> Result<Self, TryFromFloatError> {
+        let Some((mantissa, exponent)) = printed.split_once('e') else {
+            // Only NaN and the infinities are printed without exponent.
+            return Err(match printed.as_str() {
+                "NaN" => TryFromFloatError::NaN,
+                _ => TryFromFloatError::Infinite,
+            });
+        };
+        let is_negative = mantissa.starts_with('-');
+        let mantissa = mantissa.trim_start_matches('-');
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits: u128 = format!("{}{}", int_part, frac_part)
+            .parse()
+            .expect("a float has at most 17 significant digits");
+        let exponent: isize = exponent.parse().expect("a float has a small exponent");
+        let pow = frac_part.len() as isize - exponent;
+        let (decimals, mode) = rounding.unwrap_or((pow, RoundingMode::HalfUp));
+
+        let magnitude = if digits == 0 {
+            0
+        } else if pow > decimals {
+            let divisor = u32::try_from(pow - decimals)
+                .ok()
+                .and_then(|diff| 10_u128.checked_pow(diff));
+            mode.round_magnitude(digits, divisor, is_negative)
+        } else {
+            u32::try_from(decimals - pow)
+                .ok()
+                .and_then(|diff| 10_u128.checked_pow(diff))
+                .and_then(|factor| digits.checked_mul(factor))
+                .ok_or(TryFromFloatError::Overflow)?
+        };
+        let base = i128::try_from(magnitude)
+            .ok()
+            .map(|magnitude| if is_negative { -magnitude } else { magnitude })
+            .and_then(num_traits::cast)
+            .ok_or(TryFromFloatError::Overflow)?;
+        Ok(IntFloat {
+            base,
             pow: decimals,
+        })
+    }
+
+    /// Bring both bases to the largest of both powers, so that they can be combined directly.
+    /// Panics with "attempt to `operation` with overflow" if a rescaled base doesn't fit.
+    pub(crate) fn align(self, rhs: Self, operation: &str) -> (T, T, isize) {
+        let aligned = if rhs.pow > self.pow {
+            rhs.pow
+                .checked_sub(self.pow)
+                .and_then(|diff| Self::checked_rescale(self.base, diff))
+                .map(|base| (base, rhs.base, rhs.pow))
+        } else {
+            self.pow
+                .checked_sub(rhs.pow)
+                .and_then(|diff| Self::checked_rescale(rhs.base, diff))
+                .map(|base| (self.base, base, self.pow))
+        };
+        aligned.unwrap_or_else(|| panic!("attempt to {} with overflow", operation))
+    }
+
+    /// Strip trailing zeros from the base, so that all equal values share one representation.
+    pub(crate) fn canonical(&self) -> (T, isize) {
+        if self.base.is_zero() {
+            return (T::zero(), 0);
+        }
+        let (mut base, mut pow) = (self.base, self.pow);
+        while (base % T::TEN).is_zero() {
+            base = base / T::TEN;
+            pow -= 1;
+        }
+        (base, pow)
+    }
+
+    /// Strip trailing zeros from the base, so that (500, 2) becomes (5, 0). Keeps bases small,
+    /// and gives all equal values the same representation. Zero becomes (0, 0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(500, 2).normalize().to_string(), "5");
+    /// assert_eq!(IntFloat::new(-1230, 3).normalize().to_string(), "-1.23");
+    /// ```
+    pub fn normalize(self) -> Self {
+        let (base, pow) = self.canonical();
+        IntFloat { base, pow }
+    }
+
+    /// Create a value like `new`, but normalized right away.
+    pub fn new_normalized(base: T, pow: isize) -> Self {
+        IntFloat::new(base, pow).normalize()
+    }
+
+    /// True if the value is a whole number, whatever its scale: 12.00 is, 12.50 isn't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// let price = IntFloat::new(12500, 3);
+    /// assert!(!price.is_integer());
+    /// assert_eq!((price.digit_count(), price.precision()), (3, 1));
+    /// assert!(price.precision() <= 2);
+    /// let round = IntFloat::new(5, -2);
+    /// assert!(round.is_integer());
+    /// assert_eq!((round.digit_count(), round.precision()), (1, 0));
+    /// ```
+    pub fn is_integer(self) -> bool {
+        self.canonical().1 <= 0
+    }
+
+    /// The number of significant digits, i.e. of the normalized base: 3 for 12.50 and 1 for 500.
+    /// Zero has 1.
+    pub fn digit_count(self) -> u32 {
+        let (mut base, _) = self.canonical();
+        let mut digits = 1;
+        while !(base / T::TEN).is_zero() {
+            base = base / T::TEN;
+            digits += 1;
+        }
+        digits
+    }
+
+    /// The number of decimals after normalizing: 1 for 12.50, and 0 for whole numbers.
+    pub fn precision(self) -> isize {
+        self.canonical().1.max(0)
+    }
+
+    /// Parse the number at the start of `str` with the same rules as `str::parse`, and return it
+    /// with the number of bytes it took, so that the caller can go on with the rest: "12.50USD"
+    /// gives 12.50 and 5. A point or an exponent is only taken if digits follow it, so "5.-" stops
+    /// before the point and "2em" before the "e". Fails if `str` doesn't start with a number, or
+    /// if the number doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::parse_partial("12.50USD"), Ok((IntFloat::new(1250, 2), 5)));
+    /// assert_eq!(IntFloat::parse_partial("-1.5e3 m"), Ok((IntFloat::new(-1500, 0), 6)));
+    /// assert_eq!(IntFloat::parse_partial("7.kg"), Ok((IntFloat::new(7, 0), 1)));
+    /// assert!(IntFloat::parse_partial("USD 12.50").is_err());
+    /// ```
+    pub fn parse_partial(str: &str) -> Result<(Self, usize), IntFloatError> {
+        let bytes = str.as_bytes();
+        let digits_from = |start: usize| {
+            start
+                + bytes[start..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit())
+                    .count()
+        };
+        let sign = matches!(bytes.first(), Some(b'+' | b'-')) as usize;
+        let mut end = digits_from(sign);
+        if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit) {
+            end = digits_from(end + 1);
+        }
+        if end > sign && matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let exponent_sign = matches!(bytes.get(end + 1), Some(b'+' | b'-')) as usize;
+            let exponent_end = digits_from(end + 1 + exponent_sign);
+            if exponent_end > end + 1 + exponent_sign {
+                end = exponent_end;
+            }
+        }
+        Ok((str[..end].parse()?, end))
+    }
+
+    /// Parse a number in `radix` with an optional radix point, like "1A.8" in base 16, and round
+    /// it to `decimals` decimals with `mode`. Panics if `radix` is not in 2..=36, like
+    /// `from_str_radix` of the primitive integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, RoundingMode};
+    /// let third = IntFloat::from_str_radix_scaled("0.1", 3, 4, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(third.to_string(), "0.3333");
+    /// let value = IntFloat::from_str_radix_scaled("-1A.8", 16, 0, RoundingMode::HalfEven);
+    /// assert_eq!(value.unwrap().to_string(), "-26");
+    /// ```
+    pub fn from_str_radix_scaled(
+        str: &str,
+        radix: u32,
+        decimals: isize,
+        mode: RoundingMode,
+    ) -> Result<Self, IntFloatError> {
+        let (numerator, denominator) = Self::radix_fraction(str, radix)?;
+        IntFloat::new(numerator, 0)
+            .checked_div_with_mode(IntFloat::new(denominator, 0), decimals, mode)
+            .and_then(IntFloat::cast)
+            .ok_or(IntFloatError::Overflow)
+    }
+
+    /// The value of a number in `radix` as a numerator and a power of the radix as denominator,
+    /// and the number of digits after the radix point.
+    fn radix_fraction(str: &str, radix: u32) -> Result<(i128, i128), IntFloatError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]`, but is {}",
+            radix
+        );
+        let (is_negative, digits) = match str.as_bytes().first() {
+            Some(b'-') => (true, &str[1..]),
+            Some(b'+') => (false, &str[1..]),
+            _ => (false, str),
+        };
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(if digits.len() < str.len() {
+                IntFloatError::InvalidDigit
+            } else {
+                IntFloatError::Empty
+            });
+        }
+        let mut numerator: u128 = 0;
+        for c in int_part.chars().chain(frac_part.chars()) {
+            let digit = c.to_digit(radix).ok_or(IntFloatError::InvalidDigit)?;
+            numerator = numerator
+                .checked_mul(radix as u128)
+                .and_then(|numerator| numerator.checked_add(digit as u128))
+                .ok_or(IntFloatError::Overflow)?;
         }
+        let denominator = u32::try_from(frac_part.len())
+            .ok()
+            .and_then(|len| (radix as i128).checked_pow(len))
+            .ok_or(IntFloatError::Overflow)?;
+        let numerator = i128::try_from(numerator).map_err(|_| IntFloatError::Overflow)?;
+        Ok((
+            if is_negative { -numerator } else { numerator },
+            denominator,
+        ))
+    }
+
+    /// Parse a string formatted according to `locale`, such as "1.234,56" for Locale::EUROPEAN.
+    /// Grouping separators are skipped wherever they occur; after that, the same rules as for
+    /// `str::parse` apply.
+    pub fn parse_with_locale(str: &str, locale: &Locale) -> Result<Self, IntFloatError> {
+        let sanitized: String = str
+            .chars()
+            .filter(|&c| Some(c) != locale.grouping_separator())
+            .map(|c| match c {
+                c if c == locale.decimal_separator() => '.',
+                // A point that is not the decimal separator must not be read as one; a comma is
+                // rejected by the integer parser just like the point would be by this locale.
+                '.' => ',',
+                c => c,
+            })
+            .collect();
+        sanitized.parse()
     }
 
+    /// Exact decimal representation, keeping all decimals of the power: IntFloat::new(500, 2) is
+    /// printed as "5.00" and IntFloat::new(5, -2) as "500".
     pub fn print(self) -> String {
-        if self.pow >= 1 {
-            self.to_f32().unwrap().to_string()
+        let (is_nonnegative, printed) = self.plain_string(None);
+        if is_nonnegative {
+            printed
         } else {
-            self.to_i64().unwrap().to_string()
+            format!("-{}", printed)
+        }
+    }
+
+    /// Write the absolute value as a decimal string without going through floats, optionally
+    /// rounded half away from zero (or padded with zeros) to `precision` decimals. The returned
+    /// flag tells if the printed value is nonnegative.
+    pub(crate) fn plain_string(&self, precision: Option<usize>) -> (bool, String) {
+        let mut digits = self.base.unsigned_abs();
+        let mut pow = self.pow;
+        if let Some(precision) = precision {
+            if (precision as isize) < pow {
+                digits = RoundingMode::HalfUp.round_magnitude(
+                    digits,
+                    10_u128.checked_pow((pow - precision as isize) as u32),
+                    self.base.is_negative(),
+                );
+                pow = precision as isize;
+            }
+        }
+
+        let printed = place_point(digits.to_string(), digits == 0, pow, precision);
+        (!self.base.is_negative() || digits == 0, printed)
+    }
+}
+
+/// Turn the digits of an absolute base into a decimal string by placing the point `pow` digits from
+/// the right, and padding with zeros up to `precision` decimals. Shared by all base types.
+pub(crate) fn place_point(
+    mut printed: String,
+    is_zero: bool,
+    pow: isize,
+    precision: Option<usize>,
+) -> String {
+    if pow <= 0 {
+        if !is_zero {
+            printed.push_str(&"0".repeat(pow.unsigned_abs()));
+        }
+    } else {
+        let pow = pow as usize;
+        if printed.len() <= pow {
+            printed.insert_str(0, &"0".repeat(pow + 1 - printed.len()));
+        }
+        printed.insert(printed.len() - pow, '.');
+    }
+    if let Some(precision) = precision {
+        let decimals = pow.max(0) as usize;
+        if precision > decimals {
+            if decimals == 0 {
+                printed.push('.');
+            }
+            printed.push_str(&"0".repeat(precision - decimals));
         }
     }
+    printed
 }
 
-impl Display for IntFloat {
+impl<T: BaseInt> Display for IntFloat<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.print())
+        let (is_nonnegative, printed) = self.plain_string(f.precision());
+        f.pad_integral(is_nonnegative, "", &printed)
     }
 }
 
-impl Add<Self> for IntFloat {
-    type Output = IntFloat;
+impl<T: BaseInt> IntFloat<T> {
+    /// Write the value in scientific notation, straight from the base and power. Without a
+    /// precision all significant digits are written, otherwise the mantissa is rounded half away
+    /// from zero to that number of decimals.
+    fn fmt_exp(&self, f: &mut Formatter<'_>, exp_char: char) -> fmt::Result {
+        let (base, pow) = self.canonical();
+        let mut digits = base.unsigned_abs();
+        let mut n_digits = digits.to_string().len();
+        let mut exponent = n_digits as isize - 1 - pow;
 
-    fn add(self, rhs: Self) -> IntFloat {
-        if rhs.pow > self.pow {
-            IntFloat {
-                base: self.base * isize::pow(10, (rhs.pow - self.pow) as u32) + rhs.base,
-                pow: rhs.pow,
+        if let Some(precision) = f.precision() {
+            if precision + 1 < n_digits {
+                let divisor = 10_u128.pow((n_digits - precision - 1) as u32);
+                digits =
+                    RoundingMode::HalfUp.round_magnitude(digits, Some(divisor), base.is_negative());
+                n_digits = precision + 1;
+                if digits == 10_u128.pow(n_digits as u32) {
+                    digits /= 10;
+                    exponent += 1;
+                }
             }
-        } else {
-            IntFloat {
-                base: rhs.base * isize::pow(10, (self.pow - rhs.pow) as u32) + self.base,
-                pow: self.pow,
+        }
+
+        let digits = digits.to_string();
+        let mut mantissa = digits[..1].to_string();
+        let decimals = f.precision().unwrap_or(n_digits - 1);
+        if decimals > 0 {
+            mantissa.push('.');
+            mantissa.push_str(&digits[1..]);
+            mantissa.push_str(&"0".repeat(decimals + 1 - digits.len()));
+        }
+        let printed = format!("{}{}{}", mantissa, exp_char, exponent);
+        f.pad_integral(!self.base.is_negative(), "", &printed)
+    }
+}
+
+impl<T: BaseInt> LowerExp for IntFloat<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+impl<T: BaseInt> UpperExp for IntFloat<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'E')
+    }
+}
+
+macro_rules! impl_radix_fmt {
+    ($($trait:ident),*) => {$(
+        /// The raw base in another radix, for inspecting the representation: negative bases are
+        /// written in two's complement like the primitive integers do, and the flags apply to the
+        /// base. The alternate flag adds the prefix of the radix, and the pow after the base.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use intfloat::IntFloat;
+        /// let a = IntFloat::new(1250, 2);
+        /// assert_eq!(format!("{:x} {:X} {:o} {:b}", a, a, a, IntFloat::new(5, 0)), "4e2 4E2 2342 101");
+        /// assert_eq!(format!("{:#x}", a), "0x4e2 (pow 2)");
+        /// assert_eq!(format!("{:#010b}", IntFloat::new(5, -3)), "0b00000101 (pow -3)");
+        /// assert_eq!(format!("{:x}", IntFloat::new(-1, 1).cast::<i16>().unwrap()), "ffff");
+        /// ```
+        impl<T: BaseInt> $trait for IntFloat<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                $trait::fmt(&self.base, f)?;
+                if f.alternate() {
+                    write!(f, " (pow {})", self.pow)?;
+                }
+                Ok(())
             }
         }
+    )*};
+}
+
+impl_radix_fmt!(Binary, Octal, LowerHex, UpperHex);
+
+impl<T: BaseInt> Add<Self> for IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn add(self, rhs: Self) -> IntFloat<T> {
+        let (lhs_base, rhs_base, pow) = self.align(rhs, "add");
+        IntFloat {
+            base: lhs_base + rhs_base,
+            pow,
+        }
     }
 }
 
-impl Add<IntFloat> for &mut IntFloat {
-    type Output = IntFloat;
+impl<T: BaseInt> Add<IntFloat<T>> for &mut IntFloat<T> {
+    type Output = IntFloat<T>;
 
-    fn add(self, rhs: IntFloat) -> IntFloat {
+    fn add(self, rhs: IntFloat<T>) -> IntFloat<T> {
         *self + rhs
     }
 }
 
-impl AddAssign for IntFloat {
+impl<T: BaseInt> AddAssign for IntFloat<T> {
     fn add_assign(&mut self, rhs: Self) {
-        let new = self.clone() + rhs;
+        let new = *self + rhs;
         self.pow = new.pow;
         self.base = new.base;
     }
 }
 
-impl Zero for IntFloat {
+impl<T: BaseInt> Zero for IntFloat<T> {
     fn zero() -> Self {
-        IntFloat { base: 0, pow: 0 }
+        IntFloat {
+            base: T::zero(),
+            pow: 0,
+        }
     }
 
     fn is_zero(&self) -> bool {
-        self.base == 0
+        self.base.is_zero()
     }
 }
 
-impl Mul<Self> for IntFloat {
-    type Output = IntFloat;
+impl<T: BaseInt> Mul<Self> for IntFloat<T> {
+    type Output = IntFloat<T>;
 
-    fn mul(self, rhs: Self) -> IntFloat {
+    fn mul(self, rhs: Self) -> IntFloat<T> {
         IntFloat {
             base: self.base * rhs.base,
             pow: self.pow + rhs.pow,
@@ -110,36 +693,66 @@ impl Mul<Self> for IntFloat {
     }
 }
 
-impl One for IntFloat {
+impl<T: BaseInt> MulAssign for IntFloat<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: BaseInt> One for IntFloat<T> {
     fn one() -> Self {
-        IntFloat { base: 1, pow: 0 }
+        IntFloat {
+            base: T::one(),
+            pow: 0,
+        }
     }
 }
 
-impl Sub<Self> for IntFloat {
-    type Output = IntFloat;
+impl<T: BaseInt> Neg for IntFloat<T> {
+    type Output = IntFloat<T>;
 
-    fn sub(self, rhs: Self) -> IntFloat {
-        let new_rhs = IntFloat {
-            base: -rhs.base,
-            pow: rhs.pow,
-        };
-        self.add(new_rhs)
+    fn neg(self) -> IntFloat<T> {
+        IntFloat {
+            base: -self.base,
+            pow: self.pow,
+        }
+    }
+}
+
+impl<T: BaseInt> Neg for &IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn neg(self) -> IntFloat<T> {
+        -*self
+    }
+}
+
+impl<T: BaseInt> Sub<Self> for IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn sub(self, rhs: Self) -> IntFloat<T> {
+        let (lhs_base, rhs_base, pow) = self.align(rhs, "subtract");
+        IntFloat {
+            base: lhs_base - rhs_base,
+            pow,
+        }
     }
 }
 
-impl SubAssign for IntFloat {
+impl<T: BaseInt> SubAssign for IntFloat<T> {
     fn sub_assign(&mut self, rhs: Self) {
-        let new = self.clone() - rhs;
+        let new = *self - rhs;
         self.pow = new.pow;
         self.base = new.base;
     }
 }
 
-impl Div<Self> for IntFloat {
-    type Output = IntFloat;
+/// Divide the bases, truncating their quotient: 1 / 3 is 0. Use `div_with_precision` to keep a
+/// chosen number of decimals instead.
+impl<T: BaseInt> Div<Self> for IntFloat<T> {
+    type Output = IntFloat<T>;
 
-    fn div(self, rhs: Self) -> IntFloat {
+    fn div(self, rhs: Self) -> IntFloat<T> {
         IntFloat {
             base: self.base / rhs.base,
             pow: self.pow - rhs.pow,
@@ -147,51 +760,302 @@ impl Div<Self> for IntFloat {
     }
 }
 
-impl Rem<Self> for IntFloat {
-    type Output = IntFloat;
+/// The exact remainder of truncated division, like `%` on the primitive integers: the sign
+/// follows the dividend, and the scale is the largest of both operands. Panics if `rhs` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// assert_eq!((IntFloat::new(75, 1) % IntFloat::new(2, 0)).to_string(), "1.5");
+/// assert_eq!((IntFloat::new(-75, 1) % IntFloat::new(2, 0)).to_string(), "-1.5");
+/// assert_eq!((IntFloat::new(6, 0) % IntFloat::new(-150, 2)).to_string(), "0.00");
+/// ```
+impl<T: BaseInt> Rem<Self> for IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn rem(self, rhs: Self) -> IntFloat<T> {
+        let (lhs_base, rhs_base, pow) = self.align(rhs, "calculate the remainder");
+        IntFloat {
+            base: lhs_base % rhs_base,
+            pow,
+        }
+    }
+}
+
+impl<T: BaseInt> DivAssign for IntFloat<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: BaseInt> RemAssign for IntFloat<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+/// In base 10, strings are parsed like with `FromStr`, so "1.5" and "2e3" are accepted. Other
+/// radices accept a radix point too, but no exponent. Fractions in radices whose only prime
+/// factors are 2 and 5, such as 2, 8 and 16, have a finite decimal expansion, and are converted
+/// exactly with the fewest decimals: "1A.8" in base 16 becomes 26.5. Other radices are rounded
+/// half away from zero to one decimal per digit after the point for radices up to 10, and two
+/// for larger radices: "0.1" in base 3 becomes 0.3 and "0.1" in base 12 becomes 0.08. Fails with
+/// `Overflow` if the result doesn't fit in the base.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use num_traits::Num;
+/// assert_eq!(IntFloat::from_str_radix("1A.8", 16).unwrap().to_string(), "26.5");
+/// assert_eq!(IntFloat::from_str_radix("-0.011", 2).unwrap().to_string(), "-0.375");
+/// assert_eq!(IntFloat::from_str_radix("0.2", 3).unwrap().to_string(), "0.7");
+/// ```
+impl<T: BaseInt> Num for IntFloat<T> {
+    type FromStrRadixErr = IntFloatError;
 
-    fn rem(self, rhs: Self) -> IntFloat {
-        self - self.div(rhs).mul(rhs)
+    fn from_str_radix(str: &str, radix: u32) -> Result<IntFloat<T>, IntFloatError> {
+        if radix == 10 {
+            return str.parse();
+        }
+        let Some((_, frac_part)) = str.split_once('.') else {
+            let this_base = T::from_str_radix(str, radix)
+                .map_err(|error| IntFloatError::from_digits(&error))?;
+            return Ok(IntFloat {
+                base: this_base,
+                pow: 0,
+            });
+        };
+        // radix = 2^twos * 5^fives * rest, and a fraction with a denominator of radix^len has a
+        // finite decimal expansion with max(twos, fives) * len decimals if rest is 1.
+        let (mut rest, mut twos, mut fives) = (radix, 0, 0);
+        while rest % 2 == 0 {
+            (rest, twos) = (rest / 2, twos + 1);
+        }
+        while rest % 5 == 0 {
+            (rest, fives) = (rest / 5, fives + 1);
+        }
+        let digits_per_place = match rest {
+            1 => twos.max(fives),
+            _ if radix <= 10 => 1,
+            _ => 2,
+        };
+        let decimals = (frac_part.len() as isize)
+            .checked_mul(digits_per_place)
+            .ok_or(IntFloatError::ScaleTooLarge)?;
+        let this = Self::from_str_radix_scaled(str, radix, decimals, RoundingMode::HalfUp)?;
+        Ok(if rest == 1 { this.normalize() } else { this })
     }
 }
 
-impl Num for IntFloat {
-    type FromStrRadixErr = ParseIntError;
+/// Parse a decimal string such as "-12.345" exactly, with the number of digits after the decimal
+/// point as the power: "12.345" becomes IntFloat::new(12345, 3). Scientific notation is supported
+/// as well, so "1.5e-3" becomes IntFloat::new(15, 4) and "2E6" becomes IntFloat::new(2, -6).
+impl<T: BaseInt> FromStr for IntFloat<T> {
+    type Err = IntFloatError;
 
-    fn from_str_radix(str: &str, radix: u32) -> Result<IntFloat, ParseIntError> {
-        let this_base = isize::from_str_radix(str, radix);
-        let this_base = match this_base {
-            Err(parse_int_error) => return Err(parse_int_error),
-            Ok(num) => num,
+    fn from_str(str: &str) -> Result<IntFloat<T>, IntFloatError> {
+        let (mantissa, exponent) = match str.find(['e', 'E']) {
+            Some(index) => (
+                &str[..index],
+                isize::from_str(&str[index + 1..])
+                    .map_err(|error| IntFloatError::from_exponent(&error))?,
+            ),
+            None => (str, 0),
         };
-        Ok(IntFloat {
-            base: this_base,
-            pow: 0,
-        })
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let base = T::from_str(&format!("{}{}", int_part, frac_part))
+            .map_err(|error| IntFloatError::from_digits(&error))?;
+        let pow = (frac_part.len() as isize)
+            .checked_sub(exponent)
+            .ok_or(IntFloatError::ScaleTooLarge)?;
+        Ok(IntFloat { base, pow })
     }
 }
 
-impl ToPrimitive for IntFloat {
+impl<T: BaseInt> IntFloat<T> {
+    /// The sign and the magnitude of the value truncated toward zero, or None if that magnitude
+    /// doesn't fit in a u128.
+    fn truncated_integer(&self) -> Option<(bool, u128)> {
+        let digits = self.base.unsigned_abs();
+        let factor = u32::try_from(self.pow.unsigned_abs())
+            .ok()
+            .and_then(|pow| 10_u128.checked_pow(pow));
+        let magnitude = if digits == 0 {
+            0
+        } else if self.pow > 0 {
+            factor.map_or(0, |divisor| digits / divisor)
+        } else {
+            digits.checked_mul(factor?)?
+        };
+        Some((self.base.is_negative(), magnitude))
+    }
+
+    /// The value as an i64 if it is a whole number that fits, unlike `to_i64` which truncates:
+    /// 5.00 gives 5, but 5.40 gives None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// use num_traits::ToPrimitive;
+    /// assert_eq!(IntFloat::new(500, 2).to_i64_exact(), Some(5));
+    /// assert_eq!(IntFloat::new(540, 2).to_i64_exact(), None);
+    /// assert_eq!(IntFloat::new(540, 2).to_i64(), Some(5));
+    /// assert_eq!(IntFloat::new(1, -20).to_i64_exact(), None);
+    /// assert_eq!(IntFloat::new(1, -20).to_i128_exact(), Some(10_i128.pow(20)));
+    /// ```
+    pub fn to_i64_exact(&self) -> Option<i64> {
+        self.to_i128_exact()?.try_into().ok()
+    }
+
+    /// Like `to_i64_exact`, for an i128.
+    pub fn to_i128_exact(&self) -> Option<i128> {
+        if !self.is_integer() {
+            return None;
+        }
+        self.to_i128()
+    }
+}
+
+/// Integer conversions truncate toward zero with exact integer arithmetic, and return None if the
+/// truncated value doesn't fit (so negative values below -1 never convert to unsigned integers).
+/// Float conversions round the exact decimal value to the nearest float.
+impl<T: BaseInt> ToPrimitive for IntFloat<T> {
+    fn to_isize(&self) -> Option<isize> {
+        self.to_i128()?.try_into().ok()
+    }
+
     fn to_i64(&self) -> Option<i64> {
-        Option::from((self.base as f64 * 10_f64.pow(-self.pow as f64)) as i64)
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let (is_negative, magnitude) = self.truncated_integer()?;
+        if is_negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            magnitude.try_into().ok()
+        }
+    }
+
+    fn to_usize(&self) -> Option<usize> {
+        self.to_u128()?.try_into().ok()
     }
 
     fn to_u64(&self) -> Option<u64> {
-        Option::from((self.base as f64 * 10_f64.pow(-self.pow as f64)) as u64)
+        self.to_u128()?.try_into().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        match self.truncated_integer()? {
+            (true, magnitude) if magnitude != 0 => None,
+            (_, magnitude) => Some(magnitude),
+        }
     }
 
     fn to_f32(&self) -> Option<f32> {
-        Option::from(self.base as f32 * 10_f32.pow(-self.pow as f32))
+        format!("{:e}", self).parse().ok()
     }
 
     fn to_f64(&self) -> Option<f64> {
-        Option::from(self.base as f64 * 10_f64.pow(-self.pow as f64))
+        format!("{:e}", self).parse().ok()
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// An integer with the given sign and magnitude as IntFloat. Trailing zeros are moved to the
+    /// power if the integer itself doesn't fit in the base.
+    fn from_integer(is_negative: bool, magnitude: u128) -> Option<Self> {
+        let (mut magnitude, mut pow) = (magnitude, 0);
+        loop {
+            let signed = if is_negative {
+                (magnitude <= 1 << 127).then(|| (magnitude as i128).wrapping_neg())
+            } else {
+                i128::try_from(magnitude).ok()
+            };
+            if let Some(base) = signed.and_then(num_traits::cast) {
+                return Some(IntFloat { base, pow });
+            }
+            if magnitude % 10 != 0 {
+                return None;
+            }
+            magnitude /= 10;
+            pow -= 1;
+        }
     }
 }
 
-impl std::iter::Sum for IntFloat {
+/// Integers convert exactly with power 0 (or a negative power if they only fit without their
+/// trailing zeros). Floats convert exactly from their shortest decimal representation, like
+/// `IntFloat::try_from`; NaN, infinity or floats that don't fit give None.
+impl<T: BaseInt> FromPrimitive for IntFloat<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_i128(n as i128)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n as u128)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::from_integer(n.is_negative(), n.unsigned_abs())
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::from_integer(false, n)
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        IntFloat::try_from(n).ok()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        IntFloat::try_from(n).ok()
+    }
+}
+
+impl<T: BaseInt> NumCast for IntFloat<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        // Primitive integers always have a float value without fraction. A whole number that is
+        // too large for the integer path is still converted like a float if it is one, such as
+        // 1e25, but not if the float only approximates it, such as u128::MAX.
+        let float = n.to_f64()?;
+        if float.fract() == 0.0 {
+            if let Some(int) = n.to_i128() {
+                if let Some(value) = Self::from_i128(int) {
+                    return Some(value);
+                }
+                if float.to_i128() != Some(int) {
+                    return None;
+                }
+            } else if let Some(int) = n.to_u128() {
+                if let Some(value) = Self::from_u128(int) {
+                    return Some(value);
+                }
+                if float.to_u128() != Some(int) {
+                    return None;
+                }
+            }
+        }
+        <Self as FromPrimitive>::from_f64(float)
+    }
+}
+
+impl<T: BaseInt> Bounded for IntFloat<T> {
+    fn min_value() -> Self {
+        IntFloat::MIN
+    }
+
+    fn max_value() -> Self {
+        IntFloat::MAX
+    }
+}
+
+impl<T: BaseInt> std::iter::Sum for IntFloat<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut this = IntFloat::one();
+        let mut this = IntFloat::zero();
         for i in iter {
             this += i;
         }
@@ -199,21 +1063,161 @@ impl std::iter::Sum for IntFloat {
     }
 }
 
-impl PartialEq for IntFloat {
+impl<'a, T: BaseInt> std::iter::Sum<&'a IntFloat<T>> for IntFloat<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: BaseInt> std::iter::Product for IntFloat<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut this = IntFloat::one();
+        for i in iter {
+            this *= i;
+        }
+        this
+    }
+}
+
+impl<'a, T: BaseInt> std::iter::Product<&'a IntFloat<T>> for IntFloat<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<T: BaseInt> PartialEq for IntFloat<T> {
     fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: BaseInt> Eq for IntFloat<T> {}
+
+impl<T: BaseInt> Hash for IntFloat<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal values can have different (base, pow) pairs, so hash the canonical form
+        self.canonical().hash(state);
+    }
+}
+
+impl<T: BaseInt> PartialOrd for IntFloat<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Exact, whatever the scales: if aligning the pows overflows the base, the value that had to be
+/// rescaled is the largest in magnitude, so values like 1 and 10^-30 compare without panicking.
+/// `min`, `max` and `clamp` are exact as well. Of equal values, `min` returns `self` and `max`
+/// returns `other`, so the scale of the result depends on the order of the arguments.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let (lo, hi) = (IntFloat::new(995, 2), IntFloat::new(105, 1));
+/// assert_eq!(IntFloat::new(12, 0).clamp(lo, hi), hi);
+/// assert_eq!(IntFloat::new(10, 0).clamp(lo, hi), IntFloat::new(10, 0));
+/// assert!(IntFloat::new(1, 0) > IntFloat::new(1, 30));
+/// assert_eq!(IntFloat::new(1, -30).max(IntFloat::new(1, 30)), IntFloat::new(1, -30));
+/// ```
+impl<T: BaseInt> Ord for IntFloat<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let signs = self.base.signum().cmp(&other.base.signum());
+        if signs != Ordering::Equal || self.base.is_zero() {
+            return signs;
+        }
+        let larger = |base: T| {
+            if base.is_positive() {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        };
         if other.pow > self.pow {
-            other.base == self.base * isize::pow(10, (other.pow - self.pow) as u32)
+            match other
+                .pow
+                .checked_sub(self.pow)
+                .and_then(|diff| Self::checked_rescale(self.base, diff))
+            {
+                Some(base) => base.cmp(&other.base),
+                None => larger(self.base),
+            }
         } else {
-            self.base == other.base * isize::pow(10, (self.pow - other.pow) as u32)
+            match self
+                .pow
+                .checked_sub(other.pow)
+                .and_then(|diff| Self::checked_rescale(other.base, diff))
+            {
+                Some(base) => self.base.cmp(&base),
+                None => larger(other.base).reverse(),
+            }
+        }
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Convert to an IntFloat with another integer type as base, or None if the base doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, IntFloat32};
+    /// let a = IntFloat::new(534, 2);
+    /// assert_eq!(a.cast::<i32>(), Some(IntFloat32::new(534, 2)));
+    /// assert_eq!(IntFloat::new(isize::MAX, 2).cast::<i32>(), None);
+    /// ```
+    pub fn cast<U: BaseInt>(self) -> Option<IntFloat<U>> {
+        Some(IntFloat {
+            base: U::from(self.base)?,
+            pow: self.pow,
+        })
+    }
+}
+
+impl From<IntFloat<isize>> for IntFloat<i128> {
+    fn from(value: IntFloat<isize>) -> Self {
+        IntFloat {
+            base: value.base as i128,
+            pow: value.pow,
         }
     }
 }
 
-// TODO: test equality when pow is specificied differently, eg. (100,2) == (1,0)
+impl TryFrom<IntFloat<i128>> for IntFloat<isize> {
+    type Error = TryFromIntError;
+
+    /// Fails if the base of `value` doesn't fit in an isize.
+    fn try_from(value: IntFloat<i128>) -> Result<Self, Self::Error> {
+        Ok(IntFloat {
+            base: isize::try_from(value.base)?,
+            pow: value.pow,
+        })
+    }
+}
+
+/// Exact conversion of the shortest decimal representation of the float, e.g. 0.1 becomes
+/// (1, 1). Fails for NaN, infinity and floats with too many digits for the base.
+impl<T: BaseInt> TryFrom<f64> for IntFloat<T> {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from_float(format!("{:e}", value), None)
+    }
+}
+
+impl<T: BaseInt> TryFrom<f32> for IntFloat<T> {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_from_float(format!("{:e}", value), None)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32, IntFloat64};
 
     #[test]
     fn test_new() {
@@ -232,6 +1236,185 @@ mod tests {
         assert_eq!(this, that);
     }
 
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(IntFloat::from_f64(5.34234, 2), IntFloat::new(534, 2));
+        assert_eq!(IntFloat::from_f64(-5.345, 2), IntFloat::new(-535, 2));
+        assert_eq!(IntFloat::from_f64(1234.5, -2), IntFloat::new(12, -2));
+        assert_eq!(
+            IntFloat128::from_f64(123456789.1234567, 6),
+            IntFloat128::new(123456789123457, 6)
+        );
+        assert_eq!(IntFloat::from_f64(1e300, 0), IntFloat::new(isize::MAX, 0));
+    }
+
+    #[test]
+    fn test_from_with_rounding() {
+        let mode = RoundingMode::HalfEven;
+        assert_eq!(
+            IntFloat::from_with_rounding(0.5, 0, mode),
+            IntFloat::new(0, 0)
+        );
+        assert_eq!(
+            IntFloat::from_with_rounding(1.5, 0, mode),
+            IntFloat::new(2, 0)
+        );
+        assert_eq!(
+            IntFloat::from_with_rounding(-2.5, 0, mode),
+            IntFloat::new(-2, 0)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(1.005, 2, mode),
+            IntFloat::new(100, 2)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(1.015, 2, mode),
+            IntFloat::new(102, 2)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(-1.001, 2, RoundingMode::Floor),
+            IntFloat::new(-101, 2)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(1.999, 2, RoundingMode::TowardZero),
+            IntFloat::new(199, 2)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(f64::NAN, 2, mode),
+            IntFloat::new(0, 2)
+        );
+        assert_eq!(
+            IntFloat::from_f64_with_rounding(-1e300, 2, mode),
+            IntFloat::new(isize::MIN, 2)
+        );
+    }
+
+    #[test]
+    fn test_from_exact() {
+        assert_eq!(IntFloat::from(0.1, 9), IntFloat::new(100_000_000, 9));
+        assert_eq!(IntFloat::from_f64(0.1, 9), IntFloat::new(100_000_000, 9));
+        assert_eq!(IntFloat::from_f64(1.005, 2), IntFloat::new(101, 2));
+        assert_eq!(IntFloat::from_f64(-2.5, 0), IntFloat::new(-3, 0));
+        assert_eq!(IntFloat::from_f64(0.0004, 3), IntFloat::new(0, 3));
+        assert_eq!(
+            IntFloat::from_f64(1.5e-300, isize::MAX),
+            IntFloat::new(isize::MAX, isize::MAX)
+        );
+        assert_eq!(IntFloat::from_f64(1.5e-300, 0), IntFloat::new(0, 0));
+        assert_eq!(IntFloat::from_f64(-0.0, 5), IntFloat::new(0, 5));
+        assert_eq!(IntFloat::from_f64(f64::NAN, 2), IntFloat::new(0, 2));
+        assert_eq!(
+            IntFloat::from_f64(f64::INFINITY, 2),
+            IntFloat::new(isize::MAX, 2)
+        );
+        assert_eq!(
+            IntFloat::from(f32::NEG_INFINITY, 2),
+            IntFloat::new(isize::MIN, 2)
+        );
+        assert_eq!(IntFloat::from_f64(-1e300, 0), IntFloat::new(isize::MIN, 0));
+        assert_eq!(
+            IntFloat128::from_f64(f64::MAX, 0),
+            IntFloat128::new(i128::MAX, 0)
+        );
+        assert_eq!(IntFloat32::from_f64(1e10, 0), IntFloat32::new(i32::MAX, 0));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_try_from_float() {
+        assert_eq!(IntFloat::try_from_f64(5.345, 2), Ok(IntFloat::new(535, 2)));
+        assert_eq!(
+            IntFloat::try_from_f64(f64::NAN, 2),
+            Err(TryFromFloatError::NaN)
+        );
+        assert_eq!(
+            IntFloat::try_from_f64(f64::NEG_INFINITY, 2),
+            Err(TryFromFloatError::Infinite)
+        );
+        assert_eq!(
+            IntFloat::try_from_f64(1e20, 0),
+            Err(TryFromFloatError::Overflow)
+        );
+        assert_eq!(
+            IntFloat32::try_from_f64(-1e10, 0),
+            Err(TryFromFloatError::Overflow)
+        );
+
+        assert_eq!(IntFloat::try_from(0.1), Ok(IntFloat::new(1, 1)));
+        assert_eq!(
+            IntFloat::try_from(-1234.5_f32),
+            Ok(IntFloat::new(-12345, 1))
+        );
+        assert_eq!(IntFloat::try_from(1e300), Ok(IntFloat::new(1, -300)));
+        assert_eq!(IntFloat::try_from(0.0), Ok(IntFloat::new(0, 0)));
+        assert_eq!(
+            IntFloat::try_from(f32::INFINITY),
+            Err(TryFromFloatError::Infinite)
+        );
+        assert_eq!(
+            IntFloat::try_from(f64::MIN_POSITIVE).map(|this| this.base),
+            Ok(22250738585072014)
+        );
+        assert_eq!(
+            IntFloat32::try_from(f64::MIN_POSITIVE),
+            Err(TryFromFloatError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let this = IntFloat::new(500, 2).normalize();
+        assert_eq!((this.base, this.pow), (5, 0));
+        let this = IntFloat::new(-500, -2).normalize();
+        assert_eq!((this.base, this.pow), (-5, -4));
+        let this = IntFloat::new(0, 7).normalize();
+        assert_eq!((this.base, this.pow), (0, 0));
+        let this = IntFloat::new(123, 2).normalize();
+        assert_eq!((this.base, this.pow), (123, 2));
+        let this = IntFloat::new_normalized(isize::MIN, 0);
+        assert_eq!((this.base, this.pow), (isize::MIN, 0));
+        let this = IntFloat::new_normalized(1_000_000, 3);
+        assert_eq!((this.base, this.pow), (1, -3));
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        let (a, b) = (IntFloat::new(150, 2), IntFloat::new(15, 1));
+        assert_eq!(a.min(b).pow, 2);
+        assert_eq!(a.max(b).pow, 1);
+        assert_eq!(IntFloat::new(-1, 0).min(a), IntFloat::new(-1, 0));
+        assert_eq!(IntFloat::new(-1, 0).max(a), a);
+        let (tiny, huge) = (IntFloat::new(1, 30), IntFloat::new(-1, -30));
+        assert_eq!((tiny.min(huge), tiny.max(huge)), (huge, tiny));
+        assert_eq!(a.clamp(IntFloat::new(2, 0), IntFloat::new(3, 0)).base, 2);
+        assert_eq!(a.clamp(IntFloat::new(0, 0), IntFloat::new(1, 0)).base, 1);
+        assert_eq!(a.clamp(b, b).pow, 2);
+        let this = IntFloat::new(1, 40).clamp(huge, IntFloat::new(0, 0));
+        assert_eq!((this.base, this.pow), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: min <= max")]
+    fn test_clamp_panics() {
+        let _ = IntFloat::new(1, 0).clamp(IntFloat::new(2, 0), IntFloat::new(1, 0));
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert!(IntFloat::new(0, 5).is_integer());
+        assert!(IntFloat::new(-1200, 2).is_integer());
+        assert!(!IntFloat::new(-1201, 2).is_integer());
+        assert!(!IntFloat::new(1, 20).is_integer());
+        assert_eq!(IntFloat::new(0, 5).digit_count(), 1);
+        assert_eq!(IntFloat::new(-1201, 2).digit_count(), 4);
+        assert_eq!(IntFloat64::new(i64::MIN, 0).digit_count(), 19);
+        assert_eq!(IntFloat128::new(i128::MAX, 0).digit_count(), 39);
+        assert_eq!(IntFloat::new(0, 5).precision(), 0);
+        assert_eq!(IntFloat::new(-1201, 2).precision(), 2);
+        assert_eq!(IntFloat::new(10, 20).precision(), 19);
+        assert_eq!(IntFloat::new(10, -20).precision(), 0);
+    }
+
     #[test]
     fn test_print() {
         // Accuracy of conversion will be tested in respective conversion function
@@ -258,6 +1441,30 @@ mod tests {
         let this_too = IntFloat::new(100, 2);
         let that = IntFloat::new(53500, 2);
         assert_eq!(this + this_too, that);
+
+        assert_eq!(
+            IntFloat::new(0, 0) + IntFloat::new(5, 30),
+            IntFloat::new(5, 30)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_overflow() {
+        let _ = IntFloat::new(1, 0) + IntFloat::new(1, isize::MAX);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_pow_diff_truncation() {
+        let _ = IntFloat::new(1, 0) + IntFloat::new(1, (1 << 32) + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_pow_diff_overflow() {
+        let _ = IntFloat::new(1, -1) + IntFloat::new(1, isize::MAX);
     }
 
     #[test]
@@ -269,6 +1476,19 @@ mod tests {
         assert_eq!(this, that);
     }
 
+    #[test]
+    fn test_neg() {
+        let this = IntFloat::new(534, 2);
+        let that = IntFloat::new(-534, 2);
+        assert_eq!(-this, that);
+        assert_eq!(-that, this);
+        assert_eq!(-&this, that);
+        assert_eq!(this + -this, IntFloat::zero());
+
+        let this = IntFloat::new(0, 3);
+        assert_eq!(-this, this);
+    }
+
     #[test]
     fn test_sub() {
         // Accuracy of conversion will be tested in respective conversion function
@@ -284,6 +1504,11 @@ mod tests {
         let this_too = IntFloat::new(100, 2);
         let that = IntFloat::new(53500, 2);
         assert_eq!(that - this, this_too);
+
+        assert_eq!(
+            IntFloat::new(-1, 0) - IntFloat::new(isize::MIN, 0),
+            IntFloat::new(isize::MAX, 0)
+        );
     }
 
     #[test]
@@ -295,6 +1520,64 @@ mod tests {
         assert_eq!(this, that);
     }
 
+    #[test]
+    fn test_mul_div_assign() {
+        let mut this = IntFloat::new(534, 2);
+        this *= IntFloat::new(2, 1);
+        assert_eq!((this.base, this.pow), (1068, 3));
+        this /= IntFloat::new(2, 0);
+        assert_eq!((this.base, this.pow), (534, 3));
+    }
+
+    #[test]
+    fn test_num_assign() {
+        fn accumulate<T: num_traits::NumAssign + Copy>(items: &[T]) -> T {
+            let two = T::one() + T::one();
+            let mut total = T::zero();
+            for item in items {
+                total += *item;
+            }
+            total *= two;
+            total /= two + two;
+            total %= two + T::one();
+            total -= T::one();
+            total
+        }
+
+        let these = [IntFloat::new(15, 1), IntFloat::new(25, 1)];
+        assert_eq!(accumulate(&these), IntFloat::new(1, 0));
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(IntFloat::MIN, IntFloat::new(isize::MIN, 0));
+        assert_eq!(IntFloat::MAX, IntFloat::new(isize::MAX, 0));
+        assert_eq!(IntFloat32::MAX, IntFloat32::new(i32::MAX, 0));
+        assert_eq!(<IntFloat128 as Bounded>::min_value(), IntFloat128::MIN);
+        assert_eq!(<IntFloat as Bounded>::max_value(), IntFloat::MAX);
+        assert_eq!(IntFloat::MAX.checked_add(IntFloat::new(1, 0)), None);
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let these = [
+            IntFloat::new(15, 1),
+            IntFloat::new(2, 0),
+            IntFloat::new(-1, 2),
+        ];
+        assert_eq!(these.into_iter().sum::<IntFloat>(), IntFloat::new(349, 2));
+        assert_eq!(these.iter().sum::<IntFloat>(), IntFloat::new(349, 2));
+        assert_eq!(
+            these.into_iter().product::<IntFloat>(),
+            IntFloat::new(-3, 2)
+        );
+        assert_eq!(these.iter().product::<IntFloat>(), IntFloat::new(-3, 2));
+
+        let empty: [IntFloat; 0] = [];
+        assert_eq!(empty.iter().sum::<IntFloat>(), IntFloat::new(0, 0));
+        assert_eq!(empty.iter().product::<IntFloat>(), IntFloat::new(1, 0));
+    }
+
     #[test]
     fn test_mul() {
         // Accuracy of conversion will be tested in respective conversion function
@@ -333,6 +1616,45 @@ mod tests {
         assert_eq!(that - (that / this) * this, such);
     }
 
+    #[test]
+    fn test_rem_semantics() {
+        // (dividend, divisor, remainder), with the remainder at the expected scale.
+        let cases = [
+            ((75, 1), (2, 0), (15, 1)),
+            ((-75, 1), (2, 0), (-15, 1)),
+            ((75, 1), (-2, 0), (15, 1)),
+            ((-75, 1), (-2, 0), (-15, 1)),
+            ((7, 0), (25, 1), (20, 1)),
+            ((-7, 0), (25, 1), (-20, 1)),
+            ((7, 0), (-25, 1), (20, 1)),
+            ((-7, 0), (-25, 1), (-20, 1)),
+            ((534, 2), (1, 0), (34, 2)),
+            ((534, 2), (534, 2), (0, 2)),
+            ((1, 2), (5, 0), (1, 2)),
+            ((-1, 2), (5, 0), (-1, 2)),
+            ((12345, 0), (1, -2), (45, 0)),
+            ((5, -2), (3, 1), (2, 1)),
+            ((0, 3), (7, 1), (0, 3)),
+        ];
+        for ((a, p), (b, q), (r, s)) in cases {
+            let result = IntFloat::new(a, p) % IntFloat::new(b, q);
+            assert_eq!((result.base, result.pow), (r, s), "({a}, {p}) % ({b}, {q})");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_by_zero() {
+        let _ = IntFloat::new(1, 0) % IntFloat::new(0, 2);
+    }
+
+    #[test]
+    fn test_rem_assign() {
+        let mut this = IntFloat::new(-75, 1);
+        this %= IntFloat::new(2, 0);
+        assert_eq!((this.base, this.pow), (-15, 1));
+    }
+
     #[test]
     fn test_to_int() {
         let this = IntFloat::new(499, -2);
@@ -345,6 +1667,93 @@ mod tests {
     }
 
     #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_to_int_exact() {
+        let this = IntFloat::new(-499, 2);
+        assert_eq!(this.to_i64(), Some(-4));
+        assert_eq!(this.to_i8(), Some(-4));
+        assert_eq!(this.to_u64(), None);
+        assert_eq!(IntFloat::new(-99, 2).to_u64(), Some(0));
+        assert_eq!(IntFloat::new(-100, 2).to_u64(), None);
+
+        let this = IntFloat::new(isize::MAX, 0);
+        assert_eq!(this.to_isize(), Some(isize::MAX));
+        assert_eq!(this.to_usize(), Some(isize::MAX as usize));
+        assert_eq!(this.to_i32(), None);
+        assert_eq!(IntFloat::new(isize::MIN, 0).to_isize(), Some(isize::MIN));
+
+        let this = IntFloat::new(9_007_199_254_740_993, 0);
+        assert_eq!(this.to_i64(), Some(9_007_199_254_740_993));
+        let this = IntFloat::new(1, -30);
+        assert_eq!(this.to_i128(), Some(10_i128.pow(30)));
+        assert_eq!(this.to_u128(), Some(10_u128.pow(30)));
+        assert_eq!(this.to_i64(), None);
+        assert_eq!(IntFloat::new(1, -39).to_u128(), None);
+        assert_eq!(IntFloat::new(-18, -37).to_i128(), None);
+        assert_eq!(IntFloat128::MIN.to_i128(), Some(i128::MIN));
+        assert_eq!(IntFloat::new(1, isize::MAX).to_i64(), Some(0));
+        assert_eq!(IntFloat::new(1, isize::MIN).to_i64(), None);
+        assert_eq!(IntFloat::new(0, isize::MIN).to_i64(), Some(0));
+    }
+
+    #[test]
+    fn test_to_int_whole() {
+        assert_eq!(IntFloat::new(-500, 2).to_i64_exact(), Some(-5));
+        assert_eq!(IntFloat::new(-501, 2).to_i64_exact(), None);
+        assert_eq!(IntFloat::new(-501, 2).to_i128_exact(), None);
+        assert_eq!(IntFloat::new(0, 7).to_i64_exact(), Some(0));
+        assert_eq!(IntFloat64::new(i64::MAX, 0).to_i64_exact(), Some(i64::MAX));
+        assert_eq!(IntFloat128::MIN.to_i128_exact(), Some(i128::MIN));
+        assert_eq!(IntFloat128::MIN.to_i64_exact(), None);
+        assert_eq!(IntFloat::new(1, -39).to_i128_exact(), None);
+        assert_eq!(IntFloat::new(1, isize::MAX).to_i64_exact(), None);
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(IntFloat::from_i64(-534), Some(IntFloat::new(-534, 0)));
+        assert_eq!(IntFloat::from_u64(534), Some(IntFloat::new(534, 0)));
+        assert_eq!(IntFloat::from_u64(u64::MAX), None);
+        assert_eq!(IntFloat::from_i128(i128::MIN), None);
+        assert_eq!(IntFloat128::from_i128(i128::MIN), Some(IntFloat128::MIN));
+        assert_eq!(IntFloat128::from_u128(u128::MAX), None);
+        let this = IntFloat32::from_u128(10_u128.pow(30)).unwrap();
+        assert_eq!((this.base, this.pow), (1_000_000_000, -21));
+        let this = IntFloat32::from_i64(-5_000_000_000).unwrap();
+        assert_eq!((this.base, this.pow), (-500_000_000, -1));
+        assert_eq!(
+            <IntFloat as FromPrimitive>::from_f64(0.1),
+            Some(IntFloat::new(1, 1))
+        );
+        assert_eq!(IntFloat::from_f32(-2.5), Some(IntFloat::new(-25, 1)));
+        assert_eq!(<IntFloat as FromPrimitive>::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_num_cast() {
+        fn convert<N: ToPrimitive>(n: N) -> Option<IntFloat> {
+            <IntFloat as NumCast>::from(n)
+        }
+
+        assert_eq!(convert(5_u8), Some(IntFloat::new(5, 0)));
+        assert_eq!(convert(-5_i128), Some(IntFloat::new(-5, 0)));
+        assert_eq!(convert(u128::MAX), None);
+        assert_eq!(convert(10_u128.pow(30)), Some(IntFloat::new(1, -30)));
+        assert_eq!(convert(2.5_f64), Some(IntFloat::new(25, 1)));
+        assert_eq!(convert(-3.0_f32), Some(IntFloat::new(-3, 0)));
+        assert_eq!(convert(1e300), Some(IntFloat::new(1, -300)));
+        assert_eq!(convert(1e25_f64), Some(IntFloat::new(1, -25)));
+        assert_eq!(convert(-1e25_f64), Some(IntFloat::new(-1, -25)));
+        assert_eq!(convert(2_u128.pow(100) + 1), None);
+        assert_eq!(convert(f64::INFINITY), None);
+        assert_eq!(
+            convert(IntFloat::new(499, -2)),
+            Some(IntFloat::new(49900, 0))
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
     fn test_to_float() {
         let this = IntFloat::new(499, -2);
         assert_eq!(this.to_f32().unwrap(), 49900.0);
@@ -353,6 +1762,14 @@ mod tests {
         let this = IntFloat::new(499, 2);
         assert_eq!(this.to_f32().unwrap(), 4.99);
         assert_eq!(this.to_f64().unwrap(), 4.99);
+
+        assert_eq!(IntFloat::new(1, 1).to_f64(), Some(0.1));
+        assert_eq!(IntFloat::new(-3, 300).to_f64(), Some(-3e-300));
+        assert_eq!(IntFloat::new(1, -400).to_f64(), Some(f64::INFINITY));
+        assert_eq!(
+            IntFloat::new(9_007_199_254_740_993, 0).to_f64(),
+            Some(9_007_199_254_740_992.0)
+        );
     }
 
     #[test]
@@ -363,4 +1780,475 @@ mod tests {
         assert_eq!(this, that);
         assert_eq!(such, that);
     }
+
+    #[test]
+    fn test_ord_large_scale_gap() {
+        let one = IntFloat::new(1, 0);
+        let tiny = IntFloat::new(1, 30);
+        let huge = IntFloat::new(-1, -30);
+        assert!(one > tiny);
+        assert!(tiny < one);
+        assert_eq!(huge.cmp(&one), Ordering::Less);
+        assert!(IntFloat::new(-1, 30) > huge);
+        assert_ne!(one, tiny);
+        assert_eq!(IntFloat::new(0, -40), IntFloat::new(0, 40));
+        assert_eq!(one.max(tiny), one);
+        assert!(IntFloat::new(1, isize::MIN) > IntFloat::new(1, isize::MAX));
+        assert!(IntFloat::new(-1, isize::MAX) > IntFloat::new(-1, isize::MIN));
+
+        let mut these = vec![tiny, huge, one, IntFloat::new(0, 50), IntFloat::new(-1, 35)];
+        these.sort();
+        assert_eq!(
+            these,
+            vec![huge, IntFloat::new(-1, 35), IntFloat::new(0, 0), tiny, one]
+        );
+        let map: std::collections::BTreeMap<_, _> = these.iter().map(|v| (*v, ())).collect();
+        assert_eq!(map.keys().next(), Some(&huge));
+    }
+
+    #[test]
+    fn test_ord() {
+        let this = IntFloat::new(5, 0);
+        let that = IntFloat::new(499, 2);
+        assert!(this > that);
+        assert!(that < this);
+        assert_eq!(this.cmp(&IntFloat::new(500, 2)), Ordering::Equal);
+
+        let this = IntFloat::new(-5, 0);
+        let that = IntFloat::new(-499, 2);
+        assert!(this < that);
+
+        let this = IntFloat::new(5, -2);
+        let that = IntFloat::new(499, 0);
+        assert!(this > that);
+
+        let mut these = vec![
+            IntFloat::new(499, 2),
+            IntFloat::new(5, 0),
+            IntFloat::new(-1, -1),
+            IntFloat::new(51, 1),
+        ];
+        these.sort();
+        assert_eq!(
+            these,
+            vec![
+                IntFloat::new(-1, -1),
+                IntFloat::new(499, 2),
+                IntFloat::new(5, 0),
+                IntFloat::new(51, 1),
+            ]
+        );
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(IntFloat::new(5, 0), "five");
+        map.insert(IntFloat::new(499, 2), "just under five");
+        assert_eq!(map.keys().next(), Some(&IntFloat::new(499, 2)));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of(x: IntFloat) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            x.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(IntFloat::new(500, 2)), hash_of(IntFloat::new(5, 0)));
+        assert_eq!(
+            hash_of(IntFloat::new(5, -2)),
+            hash_of(IntFloat::new(500, 0))
+        );
+        assert_eq!(hash_of(IntFloat::new(0, 3)), hash_of(IntFloat::new(0, -1)));
+        assert_ne!(hash_of(IntFloat::new(5, 0)), hash_of(IntFloat::new(5, 1)));
+
+        let mut map = HashMap::new();
+        map.insert(IntFloat::new(500, 2), 1);
+        *map.entry(IntFloat::new(5, 0)).or_insert(0) += 1;
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&IntFloat::new(50, 1)], 2);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let this: IntFloat = "12.345".parse().unwrap();
+        assert_eq!(this.base, 12345);
+        assert_eq!(this.pow, 3);
+
+        let this: IntFloat = "-0.50".parse().unwrap();
+        assert_eq!((this.base, this.pow), (-50, 2));
+
+        let this: IntFloat = "42".parse().unwrap();
+        assert_eq!((this.base, this.pow), (42, 0));
+
+        let this: IntFloat = ".5".parse().unwrap();
+        assert_eq!((this.base, this.pow), (5, 1));
+
+        let this: IntFloat = "+7.".parse().unwrap();
+        assert_eq!((this.base, this.pow), (7, 0));
+
+        assert!("".parse::<IntFloat>().is_err());
+        assert!(".".parse::<IntFloat>().is_err());
+        assert!("1.2.3".parse::<IntFloat>().is_err());
+        assert!("1.-2".parse::<IntFloat>().is_err());
+        assert!("abc".parse::<IntFloat>().is_err());
+        assert!("99999999999999999999.9".parse::<IntFloat>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let parse = |str: &str| str.parse::<IntFloat>().unwrap_err();
+        assert_eq!(parse(""), IntFloatError::Empty);
+        assert_eq!(parse("-"), IntFloatError::InvalidDigit);
+        assert_eq!(parse("."), IntFloatError::Empty);
+        assert_eq!(parse("1.2.3"), IntFloatError::InvalidDigit);
+        assert_eq!(parse("12a"), IntFloatError::InvalidDigit);
+        assert_eq!(parse("1e"), IntFloatError::InvalidDigit);
+        assert_eq!(parse("1e1.5"), IntFloatError::InvalidDigit);
+        assert_eq!(parse("99999999999999999999.9"), IntFloatError::Overflow);
+        assert_eq!(parse("-99999999999999999999"), IntFloatError::Overflow);
+        assert_eq!(
+            parse("1e99999999999999999999"),
+            IntFloatError::ScaleTooLarge
+        );
+        assert_eq!(
+            parse("1.5e-9223372036854775808"),
+            IntFloatError::ScaleTooLarge
+        );
+        assert_eq!(
+            IntFloat::from_str_radix("zz", 16),
+            Err(IntFloatError::InvalidDigit)
+        );
+        assert_eq!(
+            IntFloat::parse_with_locale("1.5", &Locale::new(',', None)),
+            Err(IntFloatError::InvalidDigit)
+        );
+        assert_eq!(
+            parse("1e99999999999999999999").to_string(),
+            "number of decimals is too large"
+        );
+    }
+
+    #[test]
+    fn test_from_str_scientific() {
+        let this: IntFloat = "1.5e-3".parse().unwrap();
+        assert_eq!((this.base, this.pow), (15, 4));
+        assert_eq!(this, IntFloat::new(15, 4));
+
+        let this: IntFloat = "2E6".parse().unwrap();
+        assert_eq!((this.base, this.pow), (2, -6));
+        assert_eq!(this, IntFloat::new(2000000, 0));
+
+        let this: IntFloat = "-3.25e+2".parse().unwrap();
+        assert_eq!(this, IntFloat::new(-325, 0));
+
+        let this: IntFloat = "12.5e1".parse().unwrap();
+        assert_eq!((this.base, this.pow), (125, 0));
+
+        assert!("1e".parse::<IntFloat>().is_err());
+        assert!("e5".parse::<IntFloat>().is_err());
+        assert!("1e2e3".parse::<IntFloat>().is_err());
+        assert!("1e2.5".parse::<IntFloat>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        let this = IntFloat::from_str_radix("-12.5", 10).unwrap();
+        assert_eq!((this.base, this.pow), (-125, 1));
+        assert_eq!(
+            IntFloat::from_str_radix("ff", 16),
+            Ok(IntFloat::new(255, 0))
+        );
+        assert_eq!(
+            IntFloat::from_str_radix("f.8", 16),
+            Ok(IntFloat::new(155, 1))
+        );
+        let parse =
+            |str, radix| IntFloat::from_str_radix(str, radix).map(|this| (this.base, this.pow));
+        assert_eq!(parse("1A.8", 16), Ok((265, 1)));
+        assert_eq!(parse("+1a.0", 16), Ok((26, 0)));
+        assert_eq!(parse("-.01", 16), Ok((-390625, 8)));
+        assert_eq!(parse("0.1", 2), Ok((5, 1)));
+        assert_eq!(parse("7.", 8), Ok((7, 0)));
+        assert_eq!(parse("0.4", 5), Ok((8, 1)));
+        assert_eq!(parse("0.1", 20), Ok((5, 2)));
+        assert_eq!(parse("0.1", 3), Ok((3, 1)));
+        assert_eq!(parse("0.12", 3), Ok((56, 2)));
+        assert_eq!(parse("-0.2", 3), Ok((-7, 1)));
+        assert_eq!(parse("0.1", 12), Ok((8, 2)));
+        assert_eq!(parse("z.z", 36), Ok((3597, 2)));
+        assert_eq!(parse("0.ffffffff", 16), Err(IntFloatError::Overflow));
+        assert_eq!(parse("1.2.3", 16), Err(IntFloatError::InvalidDigit));
+        assert_eq!(parse("1.g", 16), Err(IntFloatError::InvalidDigit));
+        assert_eq!(parse(".", 16), Err(IntFloatError::Empty));
+        assert_eq!(parse("-.", 16), Err(IntFloatError::InvalidDigit));
+        assert_eq!(parse("", 16), Err(IntFloatError::Empty));
+
+        let parse = |str, radix, decimals, mode| {
+            IntFloat::from_str_radix_scaled(str, radix, decimals, mode).map(|this| this.to_string())
+        };
+        assert_eq!(
+            parse("0.1", 3, 6, RoundingMode::HalfUp),
+            Ok("0.333333".to_string())
+        );
+        assert_eq!(
+            parse("0.2", 3, 2, RoundingMode::Floor),
+            Ok("0.66".to_string())
+        );
+        assert_eq!(
+            parse("-0.2", 3, 2, RoundingMode::Floor),
+            Ok("-0.67".to_string())
+        );
+        assert_eq!(
+            parse("ff", 16, 2, RoundingMode::HalfUp),
+            Ok("255.00".to_string())
+        );
+        assert_eq!(
+            parse("0.1", 2, 0, RoundingMode::HalfEven),
+            Ok("0".to_string())
+        );
+        assert_eq!(
+            parse(
+                "ffffffffffffffffffffffffffffffffff",
+                16,
+                0,
+                RoundingMode::HalfUp
+            ),
+            Err(IntFloatError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must lie in the range")]
+    fn test_from_str_radix_scaled_panics() {
+        let _ = IntFloat::from_str_radix_scaled("1.5", 37, 2, RoundingMode::HalfUp);
+    }
+
+    #[test]
+    fn test_parse_with_locale() {
+        let that = IntFloat::new(123456, 2);
+
+        let this = IntFloat::parse_with_locale("1,234.56", &Locale::US).unwrap();
+        assert_eq!((this.base, this.pow), (that.base, that.pow));
+        let this = IntFloat::parse_with_locale("1.234,56", &Locale::EUROPEAN).unwrap();
+        assert_eq!((this.base, this.pow), (that.base, that.pow));
+        let this = IntFloat::parse_with_locale("1 234,56", &Locale::new(',', Some(' '))).unwrap();
+        assert_eq!((this.base, this.pow), (that.base, that.pow));
+
+        let this = IntFloat::parse_with_locale("-1.000.000", &Locale::EUROPEAN).unwrap();
+        assert_eq!((this.base, this.pow), (-1000000, 0));
+        let this = IntFloat::parse_with_locale("2,5e3", &Locale::EUROPEAN).unwrap();
+        assert_eq!(this, IntFloat::new(2500, 0));
+
+        assert!(IntFloat::parse_with_locale("1,5", &Locale::new(',', None)).is_ok());
+        assert!(IntFloat::parse_with_locale("1.5", &Locale::new(',', None)).is_err());
+        assert!(IntFloat::parse_with_locale("1,5,0", &Locale::EUROPEAN).is_err());
+        assert!(IntFloat::parse_with_locale("1.234.56", &Locale::US).is_err());
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let parse =
+            |str| IntFloat::parse_partial(str).map(|(this, len)| (this.base, this.pow, len));
+        assert_eq!(parse("12.50USD"), Ok((1250, 2, 5)));
+        assert_eq!(parse("42"), Ok((42, 0, 2)));
+        assert_eq!(parse("+.5,"), Ok((5, 1, 3)));
+        assert_eq!(parse("-3.25e+2x"), Ok((-325, 0, 8)));
+        assert_eq!(parse("1.5E-3"), Ok((15, 4, 6)));
+        assert_eq!(parse("5.-"), Ok((5, 0, 1)));
+        assert_eq!(parse("2em"), Ok((2, 0, 1)));
+        assert_eq!(parse("2e+"), Ok((2, 0, 1)));
+        assert_eq!(parse("1.2.3"), Ok((12, 1, 3)));
+        assert_eq!(parse("0012 apples"), Ok((12, 0, 4)));
+        assert_eq!(parse("7€"), Ok((7, 0, 1)));
+
+        assert!(parse("").is_err());
+        assert!(parse("-").is_err());
+        assert!(parse(".5").is_ok());
+        assert!(parse(".e5").is_err());
+        assert!(parse("abc").is_err());
+        assert!(parse("€7").is_err());
+        assert!(parse("99999999999999999999.9 big").is_err());
+        assert!(parse("1e99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let this = IntFloat::new(1234, 2);
+        assert_eq!(format!("{}", this), "12.34");
+        assert_eq!(format!("{:8}", this), "   12.34");
+        assert_eq!(format!("{:<8}|", this), "12.34   |");
+        assert_eq!(format!("{:^9}", this), "  12.34  ");
+        assert_eq!(format!("{:*>8}", this), "***12.34");
+        assert_eq!(format!("{:08}", this), "00012.34");
+        assert_eq!(format!("{:+}", this), "+12.34");
+        assert_eq!(format!("{:.1}", this), "12.3");
+        assert_eq!(format!("{:.4}", this), "12.3400");
+        assert_eq!(format!("{:>10.1}", this), "      12.3");
+        assert_eq!(format!("{:010.3}", this), "000012.340");
+
+        let this = IntFloat::new(-1234, 2);
+        assert_eq!(format!("{:08}", this), "-0012.34");
+        assert_eq!(format!("{:>8}", this), "  -12.34");
+        assert_eq!(format!("{:.0}", this), "-12");
+
+        let this = IntFloat::new(5, -2);
+        assert_eq!(format!("{:6}", this), "   500");
+        assert_eq!(format!("{:.2}", this), "500.00");
+    }
+
+    #[test]
+    fn test_parts() {
+        let this = IntFloat::new(-534, 2);
+        assert_eq!(this.mantissa(), -534);
+        assert_eq!(this.scale(), 2);
+        assert_eq!(this.into_parts(), (-534, 2));
+        assert_eq!(IntFloat::new(5, -2).into_parts(), (5, -2));
+        let this: IntFloat = "12.50".parse().unwrap();
+        assert_eq!(this.into_parts(), (1250, 2));
+        let (base, pow) = IntFloat32::new(i32::MIN, isize::MAX).into_parts();
+        assert_eq!((base, pow), (i32::MIN, isize::MAX));
+        const PARTS: (isize, isize) = IntFloat::new(7, 1).into_parts();
+        assert_eq!(PARTS, (7, 1));
+    }
+
+    #[test]
+    fn test_radix_fmt() {
+        let this = IntFloat::new(255, 2);
+        assert_eq!(format!("{:x}", this), "ff");
+        assert_eq!(format!("{:X}", this), "FF");
+        assert_eq!(format!("{:o}", this), "377");
+        assert_eq!(format!("{:b}", this), "11111111");
+        assert_eq!(format!("{:#X}", this), "0xFF (pow 2)");
+        assert_eq!(format!("{:#o}", IntFloat::new(8, -1)), "0o10 (pow -1)");
+        assert_eq!(format!("{:>6x}|", this), "    ff|");
+        assert_eq!(format!("{:04x}", this), "00ff");
+        assert_eq!(format!("{:x}", IntFloat64::new(-1, 0)), "ffffffffffffffff");
+        assert_eq!(
+            format!("{:b}", IntFloat32::new(-2, 3)),
+            format!("{:b}", -2_i32)
+        );
+        // Equal values with another pow have another base.
+        assert_ne!(
+            format!("{:#x}", IntFloat::new(5, 1)),
+            format!("{:#x}", IntFloat::new(50, 2))
+        );
+    }
+
+    #[test]
+    fn test_display_exact() {
+        assert_eq!(IntFloat::new(534, 2).to_string(), "5.34");
+        assert_eq!(IntFloat::new(500, 2).to_string(), "5.00");
+        assert_eq!(IntFloat::new(5, 3).to_string(), "0.005");
+        assert_eq!(IntFloat::new(-5, 3).to_string(), "-0.005");
+        assert_eq!(IntFloat::new(5, -2).to_string(), "500");
+        assert_eq!(IntFloat::new(0, -2).to_string(), "0");
+        assert_eq!(IntFloat::new(0, 2).to_string(), "0.00");
+        assert_eq!(IntFloat::new(1, 20).to_string(), "0.00000000000000000001");
+        assert_eq!(
+            IntFloat::new(isize::MAX, 9).to_string(),
+            format!(
+                "{}.{}",
+                isize::MAX / 1_000_000_000,
+                isize::MAX % 1_000_000_000
+            )
+        );
+        assert_eq!(
+            IntFloat::new(123456789, 9).to_string(),
+            IntFloat::new(123456789, 9).print()
+        );
+        assert_eq!(IntFloat::new(1, 1).to_string(), "0.1");
+        assert_eq!(IntFloat::new(99999999, 9).to_string(), "0.099999999");
+
+        assert_eq!(format!("{:.2}", IntFloat::new(12345, 3)), "12.35");
+        assert_eq!(format!("{:.2}", IntFloat::new(-12345, 3)), "-12.35");
+        assert_eq!(format!("{:.1}", IntFloat::new(-4, 2)), "0.0");
+        assert_eq!(format!("{:.0}", IntFloat::new(5, 1)), "1");
+        assert_eq!(format!("{:.3}", IntFloat::new(5, 60)), "0.000");
+        assert_eq!(format!("{:.3}", IntFloat::new(5, 0)), "5.000");
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(format!("{:e}", IntFloat::new(12345, 1)), "1.2345e3");
+        assert_eq!(format!("{:E}", IntFloat::new(12345, 1)), "1.2345E3");
+        assert_eq!(format!("{:e}", IntFloat::new(15, 4)), "1.5e-3");
+        assert_eq!(format!("{:e}", IntFloat::new(-2, -6)), "-2e6");
+        assert_eq!(format!("{:e}", IntFloat::new(500, 2)), "5e0");
+        assert_eq!(format!("{:e}", IntFloat::new(0, 3)), "0e0");
+
+        assert_eq!(format!("{:.2e}", IntFloat::new(12345, 1)), "1.23e3");
+        assert_eq!(format!("{:.3e}", IntFloat::new(12345, 1)), "1.235e3");
+        assert_eq!(format!("{:.6e}", IntFloat::new(12345, 1)), "1.234500e3");
+        assert_eq!(format!("{:.0e}", IntFloat::new(-15, 0)), "-2e1");
+        assert_eq!(format!("{:.1e}", IntFloat::new(9999, 2)), "1.0e2");
+        assert_eq!(format!("{:.2e}", IntFloat::new(0, 0)), "0.00e0");
+
+        assert_eq!(format!("{:>10e}", IntFloat::new(15, 4)), "    1.5e-3");
+        assert_eq!(format!("{:+e}", IntFloat::new(15, 4)), "+1.5e-3");
+        assert_eq!(
+            format!("{:e}", IntFloat64::new(i64::MIN, 0)),
+            "-9.223372036854775808e18"
+        );
+    }
+
+    #[test]
+    fn test_intfloat128() {
+        let this = IntFloat128::new(3_000_000_000_000, 9);
+        let that = this * this;
+        assert_eq!(
+            (that.base, that.pow),
+            (9_000_000_000_000_000_000_000_000, 18)
+        );
+        assert_eq!(that, IntFloat128::new(9_000_000, 0));
+        assert_eq!(that.to_string(), "9000000.000000000000000000");
+
+        let this: IntFloat128 = "170141183460469231731687303715884105.727".parse().unwrap();
+        assert_eq!((this.base, this.pow), (i128::MAX, 3));
+        assert_eq!(-(-this), this);
+        assert_eq!(this - this, IntFloat128::zero());
+        assert_eq!(format!("{:.2e}", this), "1.70e35");
+
+        let this = IntFloat128::new(1000, 3) + IntFloat128::new(5, 1);
+        assert_eq!(this, IntFloat128::new(15, 1));
+        assert_eq!(this / IntFloat128::new(5, 1), IntFloat128::new(3, 0));
+        assert_eq!(
+            IntFloat128::new(15, 1) % IntFloat128::new(4, 1),
+            IntFloat128::new(3, 1)
+        );
+        assert_eq!(this.to_f64(), Some(1.5));
+    }
+
+    #[test]
+    fn test_intfloat128_conversion() {
+        let this = IntFloat::new(-534, 2);
+        let that: IntFloat128 = this.into();
+        assert_eq!((that.base, that.pow), (-534, 2));
+        assert_eq!(IntFloat::try_from(that), Ok(this));
+
+        let that = IntFloat128::new(i128::MAX, 2);
+        assert!(IntFloat::try_from(that).is_err());
+    }
+
+    #[test]
+    fn test_generic() {
+        let this = IntFloat32::new(534, 2);
+        let that = IntFloat32::new(100, 0);
+        assert_eq!(this + that, IntFloat32::new(10534, 2));
+        assert_eq!((this * that).to_string(), "534.00");
+        assert_eq!(this.cast::<isize>(), Some(IntFloat::new(534, 2)));
+        assert_eq!("5.34".parse(), Ok(this));
+
+        type IntFloat8 = super::IntFloat<i8>;
+        let this = IntFloat8::new(-128, 1);
+        assert_eq!(this.to_string(), "-12.8");
+        assert_eq!(format!("{:e}", this), "-1.28e1");
+        assert_eq!(this.checked_neg(), None);
+        assert_eq!(this.cast::<i8>(), Some(this));
+        assert_eq!(IntFloat32::new(300, 1).cast::<i8>(), None);
+        assert!(this < IntFloat8::new(-127, 1));
+        assert!("12.8".parse::<IntFloat8>().is_err());
+    }
 }
@@ -1,3 +1,170 @@
+mod accumulator;
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod atomic;
+mod base_int;
+#[cfg(feature = "bigdecimal")]
+mod big_decimal;
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "fixed")]
+mod binary_fixed;
+#[cfg(feature = "bson")]
+mod bson;
+mod bytes;
+mod capacity;
+mod checked;
+#[cfg(feature = "complex")]
+mod complex;
+mod const_ops;
+mod context;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "diesel-postgres")]
+mod diesel;
+mod error;
+mod euclid;
+mod exp;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+mod format;
+mod interpolate;
+mod interval;
 mod intfloat;
+mod literal;
+mod locale;
+mod log;
+mod math;
+mod mixed;
+mod money;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+mod overflowing;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+mod parts;
+#[cfg(any(
+    feature = "diesel-postgres",
+    feature = "sqlx-postgres",
+    feature = "tokio-postgres"
+))]
+mod pg_numeric;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "tokio-postgres")]
+mod postgres;
+mod pow;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+#[cfg(feature = "rand")]
+mod rand;
+mod range;
+mod ratio;
+mod root;
+mod rounding;
+mod saturating;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod signed;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx;
+pub mod stats;
+mod trig;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+mod wrapping;
 
-pub use intfloat::IntFloat;
+#[cfg(feature = "rand")]
+pub use crate::rand::{UniformIntFloat, UnitIntFloat};
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedIntFloat;
+pub use atomic::AtomicIntFloat;
+pub use base_int::BaseInt;
+#[cfg(feature = "bigint")]
+pub use bigint::BigIntFloat;
+#[cfg(feature = "complex")]
+pub use complex::ComplexIntFloat;
+pub use context::Context;
+pub use error::{IntFloatError, MoneyError, OutOfRangeError, TryFromFloatError};
+pub use fixed::FixedIntFloat;
+pub use format::{CurrencyFormatted, Engineering, Localized, SiPrefixed};
+pub use locale::{Locale, SymbolPosition};
+pub use money::{Currency, Money};
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelIntFloat;
+pub use parts::IntFloatBuilder;
+pub use range::IntFloatRange;
+pub use rounding::RoundingMode;
+
+/// The generic IntFloat struct, with any signed primitive integer as base. Usually one of the
+/// aliases in the crate root is more convenient.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::generic::IntFloat;
+/// let a: IntFloat<i16> = IntFloat::new(534, 2);
+/// assert_eq!(a + a, IntFloat::new(1068, 2));
+/// ```
+pub mod generic {
+    pub use crate::accumulator::IntFloatAccumulator;
+    pub use crate::interval::IntFloatInterval;
+    pub use crate::intfloat::IntFloat;
+    pub use crate::ratio::IntRatio;
+}
+
+/// IntFloat with an isize as base: the default choice.
+pub type IntFloat = intfloat::IntFloat<isize>;
+
+/// IntFloat with an i32 as base, for when memory is tight.
+pub type IntFloat32 = intfloat::IntFloat<i32>;
+
+/// IntFloat with an i64 as base, independent of the platform.
+pub type IntFloat64 = intfloat::IntFloat<i64>;
+
+/// Wide variant of IntFloat, with an i128 instead of an isize as base. It supports the same
+/// operations, but can hold (intermediate) values that would overflow an IntFloat, such as the
+/// product of two values with 9 decimals.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloat128};
+/// let a = IntFloat128::new(123_456_789_123_456_789, 9);
+/// assert_eq!((a * a).to_string(), "15241578780673678.515622620750190521");
+///
+/// let b: IntFloat128 = IntFloat::new(534, 2).into();
+/// assert_eq!(IntFloat::try_from(b + b), Ok(IntFloat::new(1068, 2)));
+/// ```
+pub type IntFloat128 = intfloat::IntFloat<i128>;
+
+/// Exact ratio of two isizes, the rational companion of IntFloat. Other widths are available as
+/// `generic::IntRatio`.
+pub type IntRatio = ratio::IntRatio<isize>;
+
+/// Interval of IntFloats with an isize base. Other widths are available as
+/// `generic::IntFloatInterval`.
+pub type IntFloatInterval = interval::IntFloatInterval<isize>;
+
+/// Overflow-safe running sum of IntFloats with an isize base. Other widths are available as
+/// `generic::IntFloatAccumulator`.
+pub type IntFloatAccumulator = accumulator::IntFloatAccumulator<isize>;
@@ -0,0 +1,288 @@
+use crate::intfloat::IntFloat;
+
+/// An IntFloat from a decimal literal, parsed at compile time. The literal can be a number or a
+/// string, like `1.25`, `-0.5`, `"3.1416"` or `1.5e-3`, and keeps its decimals like `parse`
+/// does. An optional second argument fixes the number of decimals: the value is padded with
+/// zeros, or trailing zeros are dropped, but digits are never rounded away. A literal that
+/// doesn't fit in the base, or that would lose digits, fails to compile, so unlike `from` with a
+/// float there is no rounding error to worry about.
+///
+/// The result is an IntFloat with an isize base, unless another base is given first, as in
+/// `intfloat!(i128; 1.5)`.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{intfloat, IntFloat, IntFloat128};
+/// assert_eq!(intfloat!(3.14159), IntFloat::new(314159, 5));
+/// assert_eq!(intfloat!("3.1416", 4), IntFloat::new(31416, 4));
+/// assert_eq!(intfloat!(2.5, 3).to_string(), "2.500");
+/// assert_eq!(intfloat!(-1.5e-3), IntFloat::new(-15, 4));
+/// const RATES: [IntFloat128; 2] = [intfloat!(i128; 0.0125), intfloat!(i128; 1_000.5, 2)];
+/// assert_eq!(RATES[1].to_string(), "1000.50");
+/// ```
+///
+/// ```compile_fail
+/// use intfloat::intfloat;
+/// let _ = intfloat!(3.14159, 4); // would lose a digit
+/// ```
+///
+/// ```compile_fail
+/// use intfloat::intfloat;
+/// let _ = intfloat!(i8; 1.28); // 128 doesn't fit in an i8
+/// ```
+#[macro_export]
+macro_rules! intfloat {
+    (@parse $int:ident, $text:expr, $negative:expr,) => {
+        const {
+            match $crate::generic::IntFloat::<$int>::__from_literal($text, $negative, None) {
+                Ok(value) => value,
+                Err(message) => panic!("{}", message),
+            }
+        }
+    };
+    (@parse $int:ident, $text:expr, $negative:expr, $decimals:expr) => {
+        const {
+            match $crate::generic::IntFloat::<$int>::__from_literal($text, $negative, Some($decimals))
+            {
+                Ok(value) => value,
+                Err(message) => panic!("{}", message),
+            }
+        }
+    };
+    ($int:ident; - $lit:literal $(, $decimals:expr)?) => {
+        $crate::intfloat!(@parse $int, stringify!($lit), true, $($decimals)?)
+    };
+    ($int:ident; $lit:literal $(, $decimals:expr)?) => {
+        $crate::intfloat!(@parse $int, stringify!($lit), false, $($decimals)?)
+    };
+    (- $lit:literal $(, $decimals:expr)?) => {
+        $crate::intfloat!(isize; - $lit $(, $decimals)?)
+    };
+    ($lit:literal $(, $decimals:expr)?) => {
+        $crate::intfloat!(isize; $lit $(, $decimals)?)
+    };
+}
+
+const INVALID: &str = "invalid IntFloat literal";
+const OVERFLOW: &str = "IntFloat literal doesn't fit in the base";
+const TOO_MANY_DECIMALS: &str = "IntFloat literal has more decimals than requested";
+
+/// The sign, digits and pow of a decimal literal, which may be wrapped in quotes and may contain
+/// underscores and an exponent.
+const fn parse_literal(text: &str, negative: bool) -> Result<(bool, u128, isize), &'static str> {
+    let bytes = text.as_bytes();
+    let (mut index, mut end) = (0, bytes.len());
+    if end >= 2 && bytes[0] == b'"' && bytes[end - 1] == b'"' {
+        index = 1;
+        end -= 1;
+    }
+    let mut negative = negative;
+    if index < end && (bytes[index] == b'-' || bytes[index] == b'+') {
+        if negative {
+            return Err(INVALID);
+        }
+        negative = bytes[index] == b'-';
+        index += 1;
+    }
+
+    let (mut digits, mut pow, mut has_digits, mut has_dot) = (0_u128, 0_isize, false, false);
+    while index < end {
+        match bytes[index] {
+            digit @ b'0'..=b'9' => {
+                digits = match digits.checked_mul(10) {
+                    Some(shifted) => match shifted.checked_add((digit - b'0') as u128) {
+                        Some(digits) => digits,
+                        None => return Err(OVERFLOW),
+                    },
+                    None => return Err(OVERFLOW),
+                };
+                if has_dot {
+                    pow += 1;
+                }
+                has_digits = true;
+            }
+            b'.' if !has_dot => has_dot = true,
+            b'_' => {}
+            b'e' | b'E' => break,
+            _ => return Err(INVALID),
+        }
+        index += 1;
+    }
+    if !has_digits {
+        return Err(INVALID);
+    }
+
+    if index < end {
+        index += 1;
+        let mut exponent_negative = false;
+        if index < end && (bytes[index] == b'-' || bytes[index] == b'+') {
+            exponent_negative = bytes[index] == b'-';
+            index += 1;
+        }
+        let (mut exponent, mut has_exponent) = (0_isize, false);
+        while index < end {
+            match bytes[index] {
+                digit @ b'0'..=b'9' => {
+                    exponent = match exponent.checked_mul(10) {
+                        Some(shifted) => match shifted.checked_add((digit - b'0') as isize) {
+                            Some(exponent) => exponent,
+                            None => return Err(OVERFLOW),
+                        },
+                        None => return Err(OVERFLOW),
+                    };
+                    has_exponent = true;
+                }
+                b'_' => {}
+                _ => return Err(INVALID),
+            }
+            index += 1;
+        }
+        if !has_exponent {
+            return Err(INVALID);
+        }
+        pow = match if exponent_negative {
+            pow.checked_add(exponent)
+        } else {
+            pow.checked_sub(exponent)
+        } {
+            Some(pow) => pow,
+            None => return Err(OVERFLOW),
+        };
+    }
+    Ok((negative, digits, pow))
+}
+
+/// Bring the digits to `decimals` decimals, or fail if that would drop a non-zero digit.
+const fn rescale_literal(
+    digits: u128,
+    pow: isize,
+    decimals: isize,
+) -> Result<(u128, isize), &'static str> {
+    let mut digits = digits;
+    let mut pow = pow;
+    while pow > decimals {
+        if !digits.is_multiple_of(10) {
+            return Err(TOO_MANY_DECIMALS);
+        }
+        digits /= 10;
+        pow -= 1;
+    }
+    if pow < decimals && digits != 0 {
+        let diff = decimals.abs_diff(pow);
+        if diff > u32::MAX as usize {
+            return Err(OVERFLOW);
+        }
+        digits = match 10_u128.checked_pow(diff as u32) {
+            Some(factor) => match digits.checked_mul(factor) {
+                Some(digits) => digits,
+                None => return Err(OVERFLOW),
+            },
+            None => return Err(OVERFLOW),
+        };
+    }
+    Ok((digits, decimals))
+}
+
+macro_rules! impl_from_literal {
+    ($($int:ident),*) => {
+        $(
+            impl IntFloat<$int> {
+                // Used by the `intfloat!` macro.
+                #[doc(hidden)]
+                pub const fn __from_literal(
+                    text: &str,
+                    negative: bool,
+                    decimals: Option<isize>,
+                ) -> Result<Self, &'static str> {
+                    let (negative, digits, pow) = match parse_literal(text, negative) {
+                        Ok(parts) => parts,
+                        Err(message) => return Err(message),
+                    };
+                    let (digits, pow) = match decimals {
+                        Some(decimals) => match rescale_literal(digits, pow, decimals) {
+                            Ok(parts) => parts,
+                            Err(message) => return Err(message),
+                        },
+                        None => (digits, pow),
+                    };
+                    if digits > $int::MAX as u128 + negative as u128 {
+                        return Err(OVERFLOW);
+                    }
+                    let base = if negative {
+                        (digits as $int).wrapping_neg()
+                    } else {
+                        digits as $int
+                    };
+                    Ok(IntFloat { base, pow })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_literal!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::{INVALID, OVERFLOW, TOO_MANY_DECIMALS};
+    use crate::generic;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    #[test]
+    fn test_intfloat() {
+        assert_eq!(intfloat!(1.25), IntFloat::new(125, 2));
+        assert_eq!(intfloat!(-0.5), IntFloat::new(-5, 1));
+        assert_eq!(intfloat!(3), IntFloat::new(3, 0));
+        assert_eq!(intfloat!("-3.10"), IntFloat::new(-310, 2));
+        assert_eq!(intfloat!(1.5e3), IntFloat::new(15, -2));
+        assert_eq!(intfloat!(1_000.5), IntFloat::new(10005, 1));
+        assert_eq!(intfloat!(2.5, 3), IntFloat::new(2500, 3));
+        assert_eq!(intfloat!(-2.50, 1), IntFloat::new(-25, 1));
+        assert_eq!(intfloat!(1200, -2), IntFloat::new(12, -2));
+        assert_eq!(intfloat!(i32; 0.001), IntFloat32::new(1, 3));
+        assert_eq!(intfloat!(i32; -1, 2), IntFloat32::new(-100, 2));
+        assert_eq!(
+            intfloat!(i128; "-170141183460469231731687303715884105728"),
+            IntFloat128::new(i128::MIN, 0)
+        );
+        let decimals = intfloat!(i8; 1.27).pow;
+        assert_eq!(decimals, 2);
+    }
+
+    #[test]
+    fn test_from_literal() {
+        let parse = generic::IntFloat::<i8>::__from_literal;
+        assert_eq!(
+            parse("-1.28", false, None),
+            Ok(generic::IntFloat::new(-128, 2))
+        );
+        assert_eq!(parse("1.28", false, None), Err(OVERFLOW));
+        assert_eq!(
+            parse("1.2", false, Some(2)),
+            Ok(generic::IntFloat::new(120, 2))
+        );
+        assert_eq!(parse("1.3", false, Some(2)), Err(OVERFLOW));
+        assert_eq!(
+            parse("0", false, Some(1000)),
+            Ok(generic::IntFloat::new(0, 1000))
+        );
+        assert_eq!(parse("1.25", false, Some(1)), Err(TOO_MANY_DECIMALS));
+        assert_eq!(
+            parse("\"1.5\"", true, None),
+            Ok(generic::IntFloat::new(-15, 1))
+        );
+        assert_eq!(parse("-1", true, None), Err(INVALID));
+        for invalid in [
+            "", ".", "1.2.3", "1e", "1e+", "1.5f64", "0x10", "\"1", "1 .5",
+        ] {
+            assert_eq!(parse(invalid, false, None), Err(INVALID), "{invalid}");
+        }
+        let wide = generic::IntFloat::<i128>::__from_literal;
+        assert_eq!(
+            wide("340282366920938463463374607431768211456", false, None),
+            Err(OVERFLOW)
+        );
+        assert_eq!(wide("1", false, Some(39)), Err(OVERFLOW));
+    }
+}
@@ -0,0 +1,85 @@
+/// Separators used to read (and write) numbers in a locale-specific format, such as "1,234.56"
+/// in the US or "1.234,56" in much of Europe.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, Locale};
+/// let us = IntFloat::parse_with_locale("1,234.56", &Locale::US).unwrap();
+/// let eu = IntFloat::parse_with_locale("1.234,56", &Locale::EUROPEAN).unwrap();
+/// assert_eq!(us, eu);
+///
+/// let swiss = Locale::new('.', Some('\''));
+/// assert_eq!(IntFloat::parse_with_locale("1'234.56", &swiss).unwrap(), us);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Locale {
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+    symbol_position: SymbolPosition,
+}
+
+/// Where a locale puts the currency symbol when formatting amounts of money.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum SymbolPosition {
+    /// Right before the number: "$1.00". A code of letters still gets a space: "CHF 1.00".
+    #[default]
+    Before,
+    /// Before the number, separated by a space: "CHF 1.00".
+    BeforeWithSpace,
+    /// After the number, separated by a space: "1,00 €".
+    AfterWithSpace,
+}
+
+impl Locale {
+    /// Point as decimal separator, comma to group thousands: "1,234.56". Currency symbols go in
+    /// front: "$1,234.56".
+    pub const US: Locale = Locale::new('.', Some(','));
+
+    /// Comma as decimal separator, point to group thousands: "1.234,56". Currency symbols go
+    /// after the number: "1.234,56 €".
+    pub const EUROPEAN: Locale =
+        Locale::new(',', Some('.')).with_symbol_position(SymbolPosition::AfterWithSpace);
+
+    /// Create a locale from a decimal separator and an optional grouping separator. Panics if
+    /// both separators are the same character.
+    pub const fn new(decimal_separator: char, grouping_separator: Option<char>) -> Self {
+        if let Some(grouping) = grouping_separator {
+            assert!(
+                grouping != decimal_separator,
+                "decimal and grouping separator must differ"
+            );
+        }
+        Locale {
+            decimal_separator,
+            grouping_separator,
+            symbol_position: SymbolPosition::Before,
+        }
+    }
+
+    /// The same locale, with currency symbols at `position`.
+    pub const fn with_symbol_position(self, position: SymbolPosition) -> Self {
+        Locale {
+            symbol_position: position,
+            ..self
+        }
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    pub fn grouping_separator(&self) -> Option<char> {
+        self.grouping_separator
+    }
+
+    pub fn symbol_position(&self) -> SymbolPosition {
+        self.symbol_position
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::US
+    }
+}
@@ -0,0 +1,177 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::BaseInt;
+
+/// Logarithms, rounded half away from zero to `decimals` decimals. They are computed in fixed
+/// point with 32 decimals, with an error below 10^-30 before the final rounding, so results with
+/// up to 30 decimals are off by at most one in the last decimal, and only for values that lie
+/// almost exactly halfway. For `log`, that error grows by a factor 1 / |ln(base)|, which matters
+/// for a base close to one.
+///
+/// The panicking versions panic if the value isn't positive, on an invalid base, or if the result
+/// doesn't fit in the base.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let price = IntFloat::new(10250, 2);
+/// let previous = IntFloat::new(10000, 2);
+/// let log_return = price.div_with_precision(previous, 6).ln(8);
+/// assert_eq!(log_return.to_string(), "0.02469261");
+/// assert_eq!(IntFloat::new(1000, 0).log10(2).to_string(), "3.00");
+/// assert_eq!(IntFloat::new(1, 0).log2(0).to_string(), "0");
+/// assert_eq!(IntFloat::new(81, 0).log(IntFloat::new(3, 0), 1).to_string(), "4.0");
+/// assert_eq!(IntFloat::new(-1, 0).checked_ln(2), None);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// The natural logarithm as a fixed-point number, or None if the value isn't positive.
+    fn ln_fixed(self) -> Option<i128> {
+        if !self.base.is_positive() {
+            return None;
+        }
+        math::ln(self.base.unsigned_abs(), self.pow)
+    }
+
+    fn log_fixed(self, ln_base: i128, decimals: isize) -> Option<Self> {
+        math::from_fixed(math::div(self.ln_fixed()?, ln_base)?, 0, decimals)
+    }
+
+    fn expect_log(self, result: Option<Self>) -> Self {
+        if !self.base.is_positive() {
+            panic!("attempt to take the logarithm of a non-positive number");
+        }
+        result.expect("attempt to take the logarithm with overflow")
+    }
+
+    pub fn ln(self, decimals: isize) -> Self {
+        self.expect_log(self.checked_ln(decimals))
+    }
+
+    pub fn log10(self, decimals: isize) -> Self {
+        self.expect_log(self.checked_log10(decimals))
+    }
+
+    pub fn log2(self, decimals: isize) -> Self {
+        self.expect_log(self.checked_log2(decimals))
+    }
+
+    /// The logarithm with respect to `base`, which must be positive and not one.
+    pub fn log(self, base: Self, decimals: isize) -> Self {
+        if base.ln_fixed().is_none_or(|ln_base| ln_base == 0) {
+            panic!("attempt to take the logarithm with an invalid base");
+        }
+        self.expect_log(self.checked_log(base, decimals))
+    }
+
+    pub fn checked_ln(self, decimals: isize) -> Option<Self> {
+        math::from_fixed(self.ln_fixed()?, 0, decimals)
+    }
+
+    pub fn checked_log10(self, decimals: isize) -> Option<Self> {
+        self.log_fixed(math::ln(10, 0)?, decimals)
+    }
+
+    pub fn checked_log2(self, decimals: isize) -> Option<Self> {
+        self.log_fixed(math::ln(2, 0)?, decimals)
+    }
+
+    pub fn checked_log(self, base: Self, decimals: isize) -> Option<Self> {
+        self.log_fixed(base.ln_fixed()?, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat128, IntFloat32, IntFloat64};
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(IntFloat64::new(1, 0).ln(5), IntFloat64::new(0, 5));
+        assert_eq!(
+            IntFloat64::new(2, 0).ln(18),
+            IntFloat64::new(693147180559945309, 18)
+        );
+        assert_eq!(
+            IntFloat64::new(5, 1).ln(18),
+            IntFloat64::new(-693147180559945309, 18)
+        );
+        assert_eq!(IntFloat64::new(1, 100).ln(4), IntFloat64::new(-2302585, 4));
+        assert_eq!(
+            IntFloat64::new(i64::MAX, -50).ln(3),
+            IntFloat64::new(158798, 3)
+        );
+        assert_eq!(IntFloat32::new(3, 0).ln(9), IntFloat32::new(1098612289, 9));
+        assert_eq!(
+            IntFloat128::new(271828182845904523536028747135266, 32).ln(30),
+            IntFloat128::new(1000000000000000000000000000000, 30)
+        );
+        assert_eq!(IntFloat64::new(2, 0).ln(-1), IntFloat64::new(0, -1));
+    }
+
+    #[test]
+    fn test_log10_log2() {
+        assert_eq!(IntFloat64::new(1, -6).log10(3), IntFloat64::new(6000, 3));
+        assert_eq!(IntFloat64::new(1, 6).log10(3), IntFloat64::new(-6000, 3));
+        assert_eq!(
+            IntFloat64::new(2, 0).log10(10),
+            IntFloat64::new(3010299957, 10)
+        );
+        assert_eq!(
+            IntFloat64::new(1024, 0).log2(15),
+            IntFloat64::new(10000000000000000, 15)
+        );
+        assert_eq!(
+            IntFloat64::new(3, 0).log2(10),
+            IntFloat64::new(15849625007, 10)
+        );
+        assert_eq!(IntFloat64::new(125, 3).log2(0), IntFloat64::new(-3, 0));
+    }
+
+    #[test]
+    fn test_log() {
+        let this = IntFloat64::new(8, 0);
+        assert_eq!(
+            this.log(IntFloat64::new(2, 0), 6),
+            IntFloat64::new(3000000, 6)
+        );
+        assert_eq!(
+            this.log(IntFloat64::new(5, 1), 6),
+            IntFloat64::new(-3000000, 6)
+        );
+        assert_eq!(this.log(IntFloat64::new(10, 0), 6), this.log10(6));
+        assert_eq!(this.checked_log(IntFloat64::new(1, 0), 6), None);
+        assert_eq!(this.checked_log(IntFloat64::new(0, 0), 6), None);
+        assert_eq!(this.checked_log(IntFloat64::new(-2, 0), 6), None);
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(IntFloat64::new(0, 0).checked_ln(2), None);
+        assert_eq!(IntFloat64::new(-1, 0).checked_log10(2), None);
+        assert_eq!(IntFloat64::new(-1, 0).checked_log2(2), None);
+        assert_eq!(IntFloat64::new(1, -100).checked_ln(17), None);
+        assert_eq!(
+            IntFloat64::new(1, -100).checked_ln(16),
+            Some(IntFloat64::new(2302585092994045684, 16))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take the logarithm of a non-positive number")]
+    fn test_ln_zero() {
+        IntFloat64::new(0, 0).ln(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take the logarithm with an invalid base")]
+    fn test_log_base_one() {
+        IntFloat64::new(2, 0).log(IntFloat64::new(10, 1), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take the logarithm with overflow")]
+    fn test_ln_overflow() {
+        IntFloat64::new(1, -100).ln(17);
+    }
+}
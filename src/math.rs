@@ -0,0 +1,330 @@
+use crate::intfloat::IntFloat;
+use crate::rounding::RoundingMode;
+use crate::BaseInt;
+
+// Fixed-point arithmetic for the transcendental functions. The numbers are i128s with WORK
+// decimals, which leaves a few guard digits beyond the 30 that the results promise, and room for
+// values up to about 1.7 million. Every operation rounds half away from zero.
+
+/// Decimals of the internal fixed-point numbers.
+pub(crate) const WORK: u32 = 32;
+
+/// One as a fixed-point number.
+pub(crate) const ONE: i128 = 10_i128.pow(WORK);
+
+/// ln(10), ln(2) and pi / 2 with 38 decimals, to scale exactly by large multiples.
+const LN10: u128 = 230258509299404568401799145468436420760;
+const LN2: u128 = 69314718055994530941723212145817656808;
+const HALF_PI: u128 = 157079632679489661923132169163975144210;
+
+/// The factor between the 38 decimals of the constants and WORK.
+const EXTRA: u128 = 10_u128.pow(38 - WORK);
+
+/// The full 256-bit product of `a` and `b`, as its high and low halves.
+pub(crate) fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+    let (middle, middle_carry) = (a_low * b_high).overflowing_add(a_high * b_low);
+    let (low, low_carry) = (a_low * b_low).overflowing_add(middle << 64);
+    let high =
+        a_high * b_high + (middle >> 64) + ((middle_carry as u128) << 64) + low_carry as u128;
+    (high, low)
+}
+
+/// `a * b / c` rounded half up, without overflowing in the product. None if `c` is zero or the
+/// quotient doesn't fit in a u128.
+pub(crate) fn wide_mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+    if c == 0 {
+        return None;
+    }
+    let (high, low) = wide_mul(a, b);
+    let (low, carry) = low.overflowing_add(c / 2);
+    let high = high + carry as u128;
+    if high >= c {
+        return None;
+    }
+    // Long division, one bit of the low half at a time. The remainder stays below c.
+    let mut remainder = high;
+    let mut quotient = 0;
+    for bit in (0..128).rev() {
+        let overflow = remainder >> 127 == 1;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if overflow || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+fn signed(is_negative: bool, magnitude: u128) -> Option<i128> {
+    let magnitude = i128::try_from(magnitude).ok()?;
+    Some(if is_negative { -magnitude } else { magnitude })
+}
+
+/// `a * b / c` for signed integers, rounded half away from zero.
+pub(crate) fn mul_div(a: i128, b: i128, c: i128) -> Option<i128> {
+    let is_negative = ((a < 0) != (b < 0)) != (c < 0);
+    signed(
+        is_negative,
+        wide_mul_div(a.unsigned_abs(), b.unsigned_abs(), c.unsigned_abs())?,
+    )
+}
+
+/// The product of two fixed-point numbers.
+pub(crate) fn mul(a: i128, b: i128) -> Option<i128> {
+    mul_div(a, b, ONE)
+}
+
+/// The quotient of two fixed-point numbers.
+pub(crate) fn div(a: i128, b: i128) -> Option<i128> {
+    mul_div(a, ONE, b)
+}
+
+/// `magnitude * 10^shift`, rounded half up. None if it overflows.
+fn shift_magnitude(magnitude: u128, shift: isize) -> Option<u128> {
+    let factor = u32::try_from(shift.unsigned_abs())
+        .ok()
+        .and_then(|shift| 10_u128.checked_pow(shift));
+    if shift < 0 {
+        Some(RoundingMode::HalfUp.round_magnitude(magnitude, factor, false))
+    } else if magnitude == 0 {
+        Some(0)
+    } else {
+        magnitude.checked_mul(factor?)
+    }
+}
+
+/// The value as a fixed-point number. None if it is out of range.
+pub(crate) fn to_fixed<T: BaseInt>(value: IntFloat<T>) -> Option<i128> {
+    let magnitude = shift_magnitude(value.base.unsigned_abs(), WORK as isize - value.pow)?;
+    signed(value.base.is_negative(), magnitude)
+}
+
+/// `fixed * 10^exponent` as an IntFloat with `decimals` decimals. None if the base overflows.
+pub(crate) fn from_fixed<T: BaseInt>(
+    fixed: i128,
+    exponent: isize,
+    decimals: isize,
+) -> Option<IntFloat<T>> {
+    let shift = exponent.checked_add(decimals)?.checked_sub(WORK as isize)?;
+    let magnitude = shift_magnitude(fixed.unsigned_abs(), shift)?;
+    let base = signed(fixed < 0, magnitude).and_then(num_traits::cast)?;
+    Some(IntFloat::new(base, decimals))
+}
+
+/// `magnitude * 10^-pow` as a fixed-point mantissa in [1, 10] and a power of ten. None if
+/// `magnitude` is zero.
+fn normalize(magnitude: u128, pow: isize) -> Option<(i128, isize)> {
+    let digits = magnitude.checked_ilog10()? as isize;
+    let mantissa = shift_magnitude(magnitude, WORK as isize - digits)?;
+    Some((mantissa as i128, digits.checked_sub(pow)?))
+}
+
+/// The natural logarithm of `magnitude * 10^-pow`. None if `magnitude` is zero or the logarithm
+/// is out of range.
+pub(crate) fn ln(magnitude: u128, pow: isize) -> Option<i128> {
+    let (mantissa, exponent) = normalize(magnitude, pow)?;
+    let ln_power = signed(
+        exponent < 0,
+        wide_mul_div(exponent.unsigned_abs() as u128, LN10, EXTRA)?,
+    )?;
+    ln_mantissa(mantissa)?.checked_add(ln_power)
+}
+
+/// ln(x) for a fixed-point x in [1, 10].
+fn ln_mantissa(x: i128) -> Option<i128> {
+    // Bring x below two with ln(x) = ln(x / 2^k) + k ln(2), then use the series
+    // ln(x) = 2 (z + z^3 / 3 + z^5 / 5 + ...) with z = (x - 1) / (x + 1), which is at most 1/3.
+    let mut halvings = 0;
+    while x >= (2 * ONE) << halvings {
+        halvings += 1;
+    }
+    let x = mul_div(x, 1, 1 << halvings)?;
+    let z = div(x - ONE, x + ONE)?;
+    let z_squared = mul(z, z)?;
+    let mut power = z;
+    let mut sum = 0;
+    let mut n = 1;
+    while power != 0 {
+        sum += mul_div(power, 1, n)?;
+        power = mul(power, z_squared)?;
+        n += 2;
+    }
+    Some(2 * sum + halvings * mul_div(LN2 as i128, 1, EXTRA as i128)?)
+}
+
+/// e^x for a fixed-point x, as a fixed-point mantissa between 0.3 and 3.2 and a power of ten.
+/// None if the power of ten is out of range.
+pub(crate) fn exp(x: i128) -> Option<(i128, isize)> {
+    // Split x into k ln(10) + r with |r| at most ln(10) / 2, so that e^x = e^r * 10^k, and sum
+    // the Taylor series of e^r.
+    let k = signed(x < 0, wide_mul_div(x.unsigned_abs(), EXTRA, LN10)?)?;
+    let k_ln10 = signed(k < 0, wide_mul_div(k.unsigned_abs(), LN10, EXTRA)?)?;
+    let r = x - k_ln10;
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut n = 1;
+    while term != 0 {
+        term = mul_div(term, r, ONE * n)?;
+        sum += term;
+        n += 1;
+    }
+    Some((sum, isize::try_from(k).ok()?))
+}
+
+/// The sine and cosine of a fixed-point x.
+pub(crate) fn sin_cos(x: i128) -> Option<(i128, i128)> {
+    // Split x into k pi / 2 + r with |r| at most pi / 4, sum the Taylor series of the sine and
+    // cosine of r, and rotate them to the quadrant of k.
+    let k = signed(x < 0, wide_mul_div(x.unsigned_abs(), EXTRA, HALF_PI)?)?;
+    let r = x - signed(k < 0, wide_mul_div(k.unsigned_abs(), HALF_PI, EXTRA)?)?;
+    let r_squared = mul(r, r)?;
+    let (mut sin, mut cos) = (r, ONE);
+    let (mut sin_term, mut cos_term) = (r, ONE);
+    let mut n = 1;
+    while sin_term != 0 || cos_term != 0 {
+        sin_term = mul_div(-sin_term, r_squared, ONE * (2 * n) * (2 * n + 1))?;
+        cos_term = mul_div(-cos_term, r_squared, ONE * (2 * n - 1) * (2 * n))?;
+        sin += sin_term;
+        cos += cos_term;
+        n += 1;
+    }
+    Some(match k.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    })
+}
+
+/// atan(x) for a fixed-point x in [0, 1].
+fn atan_unit(x: i128) -> Option<i128> {
+    // Euler's series: the sum over n of (2n)!! / (2n + 1)!! * y^n * x / (1 + x^2), where
+    // y = x^2 / (1 + x^2) is at most 1/2.
+    let x_squared = mul(x, x)?;
+    let y = div(x_squared, ONE + x_squared)?;
+    let mut term = div(x, ONE + x_squared)?;
+    let mut sum = term;
+    let mut n = 1;
+    while term != 0 {
+        term = mul_div(term, 2 * n * y, (2 * n + 1) * ONE)?;
+        sum += term;
+        n += 1;
+    }
+    Some(sum)
+}
+
+/// The ratio of two normalized values, of which the first is at most the second.
+fn ratio((a, a_exponent): (i128, isize), (b, b_exponent): (i128, isize)) -> Option<i128> {
+    let quotient = div(a, b)? as u128;
+    Some(shift_magnitude(quotient, a_exponent.checked_sub(b_exponent)?)? as i128)
+}
+
+/// The angle of the point (x, y), in (-pi, pi], as a fixed-point number.
+pub(crate) fn atan2<T: BaseInt>(y: IntFloat<T>, x: IntFloat<T>) -> Option<i128> {
+    let half_pi = mul_div(HALF_PI as i128, 1, EXTRA as i128)?;
+    // The angle in the first quadrant, from the ratio of the smaller coordinate to the larger.
+    let angle = match (
+        normalize(y.base.unsigned_abs(), y.pow),
+        normalize(x.base.unsigned_abs(), x.pow),
+    ) {
+        (None, _) => 0,
+        (Some(_), None) => half_pi,
+        (Some(y), Some(x)) if (y.1, y.0) <= (x.1, x.0) => atan_unit(ratio(y, x)?)?,
+        (Some(y), Some(x)) => half_pi - atan_unit(ratio(x, y)?)?,
+    };
+    let angle = if x.base.is_negative() {
+        2 * half_pi - angle
+    } else {
+        angle
+    };
+    Some(if y.base.is_negative() { -angle } else { angle })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_mul_div() {
+        assert_eq!(
+            wide_mul_div(u128::MAX, u128::MAX, u128::MAX),
+            Some(u128::MAX)
+        );
+        assert_eq!(wide_mul_div(u128::MAX, 2, 4), Some(u128::MAX / 2 + 1));
+        assert_eq!(wide_mul_div(u128::MAX, 2, 1), None);
+        assert_eq!(wide_mul_div(7, 1, 2), Some(4));
+        assert_eq!(wide_mul_div(1 << 100, 1 << 100, 1 << 80), Some(1 << 120));
+        assert_eq!(wide_mul_div(1, 1, 0), None);
+        assert_eq!(mul_div(-7, 1, 2), Some(-4));
+        assert_eq!(mul_div(-7, -3, -2), Some(-11));
+    }
+
+    /// Results may be off by a few units in the last of the WORK decimals.
+    fn assert_close(actual: Option<i128>, expected: i128) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() <= 10,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(ln(1, 0), Some(0));
+        assert_eq!(ln(0, 0), None);
+        assert_close(ln(10, 0), 230258509299404568401799145468436);
+        assert_close(ln(2, 0), 69314718055994530941723212145818);
+        assert_close(ln(5, 1), -69314718055994530941723212145818);
+        assert_close(ln(3, 0), 109861228866810969139524523692253);
+        assert_close(ln(u128::MAX, -10), 11174869004161345644558562609349024);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(exp(0), Some((ONE, 0)));
+        let (mantissa, power) = exp(ONE).unwrap();
+        assert_eq!(power, 0);
+        assert_close(Some(mantissa), 271828182845904523536028747135266);
+        let (mantissa, power) = exp(-10 * ONE).unwrap();
+        assert_eq!(power, -4);
+        assert_close(Some(mantissa), 45399929762484851535591515560551);
+        let (mantissa, power) = exp(ln(2, 0).unwrap() * 1000).unwrap();
+        let expected = from_fixed::<i128>(mantissa, power, -290).unwrap();
+        assert_eq!(expected, IntFloat::new(107150860719, -290));
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        assert_eq!(sin_cos(0), Some((0, ONE)));
+        let (sin, cos) = sin_cos(ONE).unwrap();
+        assert_close(Some(sin), 84147098480789650665250232163030);
+        assert_close(Some(cos), 54030230586813971740093660744298);
+        let (sin, cos) = sin_cos(-1000 * ONE).unwrap();
+        assert_close(Some(sin), -82687954053200256025588742910922);
+        assert_close(Some(cos), 56237907629070299107824922660540);
+        let (sin, cos) = sin_cos(314159265358979323846264338327950).unwrap();
+        assert_close(Some(sin), 0);
+        assert_close(Some(cos), -ONE);
+    }
+
+    #[test]
+    fn test_atan2() {
+        let atan2 = |y: isize, x: isize| atan2(IntFloat::new(y, 0), IntFloat::new(x, 0));
+        assert_eq!(atan2(0, 0), Some(0));
+        assert_eq!(atan2(0, 1), Some(0));
+        assert_close(atan2(0, -1), 314159265358979323846264338327950);
+        assert_close(atan2(1, 0), 157079632679489661923132169163975);
+        assert_close(atan2(-1, 0), -157079632679489661923132169163975);
+        assert_close(atan2(1, 1), 78539816339744830961566084581988);
+        assert_close(atan2(-1, -1), -235619449019234492884698253745962);
+        assert_close(atan2(1, 2), 46364760900080611621425623146121);
+        assert_close(atan2(2, 1), 110714871779409050301706546017854);
+        assert_close(atan2(-7, 3), -116590454050981319591924876263031);
+    }
+}
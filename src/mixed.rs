@@ -0,0 +1,312 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloat128};
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+impl<T: BaseInt> IntFloat<T> {
+    /// An integer scalar as IntFloat with power 0. Panics if it doesn't fit in the base, just like
+    /// an overflowing operation would.
+    fn from_scalar_int<P: num_traits::ToPrimitive>(value: P) -> Self {
+        IntFloat::new(
+            <T as num_traits::NumCast>::from(value)
+                .expect("attempt to use a scalar that doesn't fit in the base"),
+            0,
+        )
+    }
+
+    /// A float scalar, converted exactly from its shortest decimal representation like
+    /// `IntFloat::try_from`. Panics if it is NaN or infinite, or doesn't fit in the base.
+    fn from_scalar_float<P>(value: P) -> Self
+    where
+        Self: TryFrom<P, Error = crate::TryFromFloatError>,
+    {
+        match Self::try_from(value) {
+            Ok(this) => this,
+            Err(err) => panic!("attempt to use an invalid float scalar: {}", err),
+        }
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    fn cmp_scalar_int(self, value: i128) -> Option<Ordering> {
+        let wide = self.cast().expect("every base fits in an i128");
+        Some(wide.cmp(&IntFloat::new(value, 0)))
+    }
+
+    /// Floats compare by their shortest decimal representation, like they convert in arithmetic:
+    /// 0.1 equals IntFloat::new(1, 1). NaN is unordered, and the infinities are beyond any value.
+    fn cmp_scalar_float<P>(self, value: P) -> Option<Ordering>
+    where
+        P: num_traits::Float,
+        IntFloat128: TryFrom<P>,
+    {
+        if value.is_nan() {
+            return None;
+        }
+        if value.is_infinite() {
+            return Some(if value > P::zero() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+        }
+        let value = IntFloat128::try_from(value)
+            .ok()
+            .expect("every finite float has at most 17 digits");
+        let wide: IntFloat128 = self.cast().expect("every base fits in an i128");
+        Some(wide.cmp(&value))
+    }
+}
+
+macro_rules! impl_mixed_cmp {
+    ($cmp:ident, $to_scalar:ty; $($prim:ty),*) => {
+        $(
+            impl<T: BaseInt> PartialEq<$prim> for IntFloat<T> {
+                fn eq(&self, other: &$prim) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl<T: BaseInt> PartialOrd<$prim> for IntFloat<T> {
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    self.$cmp(*other as $to_scalar)
+                }
+            }
+
+            impl<T: BaseInt> PartialEq<IntFloat<T>> for $prim {
+                fn eq(&self, other: &IntFloat<T>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<T: BaseInt> PartialOrd<IntFloat<T>> for $prim {
+                fn partial_cmp(&self, other: &IntFloat<T>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+// Comparisons with primitive scalars, so that guards like `price > 0` read naturally. They are
+// exact and never overflow, like `Ord`: the scalar is compared as an IntFloat with an i128 base.
+// u128 isn't included, since it doesn't fit in an i128.
+impl_mixed_cmp!(cmp_scalar_int, i128; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_mixed_cmp!(cmp_scalar_float, f64; f64);
+impl_mixed_cmp!(cmp_scalar_float, f32; f32);
+
+macro_rules! impl_mixed_op {
+    ($from:ident, $prim:ty, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl<T: BaseInt> $op<$prim> for IntFloat<T> {
+            type Output = IntFloat<T>;
+
+            fn $fn(self, rhs: $prim) -> IntFloat<T> {
+                $op::$fn(self, IntFloat::<T>::$from(rhs))
+            }
+        }
+
+        impl<T: BaseInt> $op<IntFloat<T>> for $prim {
+            type Output = IntFloat<T>;
+
+            fn $fn(self, rhs: IntFloat<T>) -> IntFloat<T> {
+                $op::$fn(IntFloat::<T>::$from(self), rhs)
+            }
+        }
+
+        impl<T: BaseInt> $op_assign<$prim> for IntFloat<T> {
+            fn $fn_assign(&mut self, rhs: $prim) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_mixed_ops {
+    ($from:ident; $($prim:ty),*) => {
+        $(
+            impl_mixed_op!($from, $prim, Add, add, AddAssign, add_assign);
+            impl_mixed_op!($from, $prim, Sub, sub, SubAssign, sub_assign);
+            impl_mixed_op!($from, $prim, Mul, mul, MulAssign, mul_assign);
+            impl_mixed_op!($from, $prim, Div, div, DivAssign, div_assign);
+            impl_mixed_op!($from, $prim, Rem, rem, RemAssign, rem_assign);
+        )*
+    };
+}
+
+// Arithmetic with primitive scalars, so that `price * 3` works without wrapping the literal. The
+// scalar is converted to an IntFloat first: integers with power 0, floats exactly from their
+// shortest decimal representation (so 2.5 becomes (25, 1)). Conversions that fail panic.
+impl_mixed_ops!(from_scalar_int; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_mixed_ops!(from_scalar_float; f32, f64);
+
+macro_rules! impl_ref_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl<T: BaseInt> $op<&IntFloat<T>> for IntFloat<T> {
+            type Output = IntFloat<T>;
+
+            fn $fn(self, rhs: &IntFloat<T>) -> IntFloat<T> {
+                $op::$fn(self, *rhs)
+            }
+        }
+
+        impl<T: BaseInt> $op<IntFloat<T>> for &IntFloat<T> {
+            type Output = IntFloat<T>;
+
+            fn $fn(self, rhs: IntFloat<T>) -> IntFloat<T> {
+                $op::$fn(*self, rhs)
+            }
+        }
+
+        impl<T: BaseInt> $op<&IntFloat<T>> for &IntFloat<T> {
+            type Output = IntFloat<T>;
+
+            fn $fn(self, rhs: &IntFloat<T>) -> IntFloat<T> {
+                $op::$fn(*self, *rhs)
+            }
+        }
+
+        impl<T: BaseInt> $op_assign<&IntFloat<T>> for IntFloat<T> {
+            fn $fn_assign(&mut self, rhs: &IntFloat<T>) {
+                *self = $op::$fn(*self, *rhs);
+            }
+        }
+    };
+}
+
+// Arithmetic with borrowed operands, which behaves exactly like the owned version. This makes
+// IntFloat satisfy the NumRef, RefNum and NumAssignRef bounds of num_traits.
+impl_ref_op!(Add, add, AddAssign, add_assign);
+impl_ref_op!(Sub, sub, SubAssign, sub_assign);
+impl_ref_op!(Mul, mul, MulAssign, mul_assign);
+impl_ref_op!(Div, div, DivAssign, div_assign);
+impl_ref_op!(Rem, rem, RemAssign, rem_assign);
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloat32};
+
+    #[test]
+    fn test_int_scalars() {
+        let price = IntFloat::new(1999, 2);
+        assert_eq!(price * 3, IntFloat::new(5997, 2));
+        assert_eq!(price + 1, IntFloat::new(2099, 2));
+        assert_eq!(price - 20_u8, IntFloat::new(-1, 2));
+        assert_eq!(IntFloat::new(500, 0) / 100_i64, IntFloat::new(5, 0));
+        assert_eq!(price % 2, IntFloat::new(199, 2));
+        assert_eq!(3 * price, price * 3);
+        assert_eq!(1 - price, IntFloat::new(-1899, 2));
+        assert_eq!(IntFloat32::new(5, 0) * 2_i128, IntFloat32::new(10, 0));
+
+        let mut total = price;
+        total *= 2;
+        total += 1_usize;
+        total -= 1_i16;
+        total /= 2;
+        total %= 10;
+        assert_eq!(total, IntFloat::new(999, 2));
+    }
+
+    #[test]
+    fn test_float_scalars() {
+        let this = IntFloat::new(15, 1);
+        assert_eq!(this + 2.5, IntFloat::new(4, 0));
+        assert_eq!(this * 0.1_f32, IntFloat::new(15, 2));
+        assert_eq!(1.5 - this, IntFloat::new(0, 0));
+        let mut this = this;
+        this *= 1e3;
+        assert_eq!(this, IntFloat::new(1500, 0));
+    }
+
+    #[test]
+    fn test_int_cmp() {
+        let price = IntFloat::new(1999, 2);
+        assert!(price > 0);
+        assert!(price < 20_u8);
+        assert!(price >= 19_i64);
+        assert!(20 > price);
+        assert!(price != 20);
+        assert_eq!(IntFloat::new(2000, 2), 20);
+        assert_eq!(20_usize, IntFloat::new(2, -1));
+        assert!(IntFloat::new(-1, 0) < 0_u64);
+        assert!(IntFloat::new(1, -39) > i128::MAX);
+        assert!(IntFloat::new(-1, -39) < i128::MIN);
+        assert!(IntFloat::new(1, 40) > 0);
+        assert!(IntFloat::new(1, 40) < 1);
+        assert!(IntFloat::new(-1, 40) > -1);
+        assert!(IntFloat::new(0, isize::MIN) == 0);
+        assert!(IntFloat::new(1, isize::MAX) > 0);
+        assert!(IntFloat32::new(i32::MAX, 0) < i64::MAX);
+    }
+
+    #[test]
+    fn test_float_cmp() {
+        let price = IntFloat::new(1999, 2);
+        assert!(price > 19.98);
+        assert!(price < 19.991_f32);
+        assert_eq!(IntFloat::new(1, 1), 0.1);
+        assert_eq!(0.1_f32, IntFloat::new(1, 1));
+        assert!(IntFloat::new(0, 3) == -0.0);
+        assert!(price < f64::INFINITY);
+        assert!(price > f64::NEG_INFINITY);
+        assert_eq!(price.partial_cmp(&f64::NAN), None);
+        assert_eq!(f32::NAN.partial_cmp(&price), None);
+        assert!(!price.eq(&f64::NAN));
+        assert!(IntFloat::new(isize::MAX, 0) < 1e300);
+        assert!(IntFloat::new(1, 0) > 5e-324);
+        assert!(IntFloat::new(-1, -400) < f64::MIN);
+    }
+
+    #[test]
+    fn test_ref_operands() {
+        let these = [IntFloat::new(1, 1), IntFloat::new(25, 2)];
+        let those = [IntFloat::new(2, 0), IntFloat::new(5, 1)];
+        let sums: Vec<IntFloat> = these.iter().zip(&those).map(|(x, y)| x + y).collect();
+        assert_eq!(sums, vec![IntFloat::new(21, 1), IntFloat::new(75, 2)]);
+
+        let this = IntFloat::new(75, 1);
+        let that = IntFloat::new(2, 0);
+        let (this_ref, that_ref) = (&this, &that);
+        assert_eq!(this_ref - that_ref, this - that);
+        assert_eq!(this_ref * that, this * that);
+        assert_eq!(this / that_ref, this / that);
+        assert_eq!(this_ref % that_ref, this % that);
+
+        let mut total = this;
+        total += &that;
+        total -= &that;
+        total *= &that;
+        total /= &that;
+        total %= &that;
+        assert_eq!(total, this % that);
+    }
+
+    #[test]
+    fn test_ref_num_traits() {
+        fn sum_of_squares<T>(items: &[T]) -> T
+        where
+            T: num_traits::NumRef + num_traits::NumAssignRef,
+            for<'a> &'a T: num_traits::RefNum<T>,
+        {
+            let mut total = T::zero();
+            for item in items {
+                total += &(item * item);
+            }
+            total
+        }
+
+        let these = [IntFloat::new(1, 1), IntFloat::new(2, 0)];
+        assert_eq!(sum_of_squares(&these), IntFloat::new(401, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to use an invalid float scalar")]
+    fn test_float_scalar_nan() {
+        let _ = IntFloat::new(15, 1) + f64::NAN;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to use a scalar that doesn't fit in the base")]
+    fn test_int_scalar_overflow() {
+        let _ = IntFloat32::new(15, 1) + u64::MAX;
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{CurrencyFormatted, IntFloat, Locale, MoneyError, RoundingMode};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An ISO 4217 currency: its three-letter code and the number of decimals of its minor unit,
+/// such as 2 for the cents of the euro and 0 for the yen.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::Currency;
+/// assert_eq!(Currency::EUR.minor_units(), 2);
+/// assert_eq!(Currency::from_code("KWD").unwrap().minor_units(), 3);
+/// assert_eq!(Currency::new("XBT", 8).code(), "XBT");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Currency {
+    code: [u8; 3],
+    minor_units: u8,
+}
+
+/// The currencies known to `Currency::from_code`, with their minor units.
+const CURRENCIES: [(&str, u8); 41] = [
+    ("AED", 2),
+    ("ARS", 2),
+    ("AUD", 2),
+    ("BHD", 3),
+    ("BRL", 2),
+    ("CAD", 2),
+    ("CHF", 2),
+    ("CLP", 0),
+    ("CNY", 2),
+    ("CZK", 2),
+    ("DKK", 2),
+    ("EUR", 2),
+    ("GBP", 2),
+    ("HKD", 2),
+    ("HUF", 2),
+    ("IDR", 2),
+    ("ILS", 2),
+    ("INR", 2),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("MXN", 2),
+    ("MYR", 2),
+    ("NOK", 2),
+    ("NZD", 2),
+    ("OMR", 3),
+    ("PHP", 2),
+    ("PLN", 2),
+    ("RON", 2),
+    ("SAR", 2),
+    ("SEK", 2),
+    ("SGD", 2),
+    ("THB", 2),
+    ("TND", 3),
+    ("TRY", 2),
+    ("TWD", 2),
+    ("USD", 2),
+    ("VND", 0),
+    ("ZAR", 2),
+];
+
+impl Currency {
+    pub const EUR: Currency = Currency::new("EUR", 2);
+    pub const USD: Currency = Currency::new("USD", 2);
+    pub const GBP: Currency = Currency::new("GBP", 2);
+    pub const CHF: Currency = Currency::new("CHF", 2);
+    pub const JPY: Currency = Currency::new("JPY", 0);
+
+    /// Create a currency from its code, which must be three uppercase ASCII letters, and the
+    /// decimals of its minor unit. Panics on an invalid code.
+    pub const fn new(code: &str, minor_units: u8) -> Self {
+        let bytes = code.as_bytes();
+        assert!(
+            bytes.len() == 3
+                && bytes[0].is_ascii_uppercase()
+                && bytes[1].is_ascii_uppercase()
+                && bytes[2].is_ascii_uppercase(),
+            "a currency code must be three uppercase ASCII letters"
+        );
+        Currency {
+            code: [bytes[0], bytes[1], bytes[2]],
+            minor_units,
+        }
+    }
+
+    /// Look up one of the common ISO 4217 currencies by its code, or None if it's unknown. Other
+    /// currencies can be created with `new`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        CURRENCIES
+            .iter()
+            .find(|(known, _)| *known == code)
+            .map(|&(code, minor_units)| Currency::new(code, minor_units))
+    }
+
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.code).expect("currency codes are ASCII")
+    }
+
+    pub fn minor_units(&self) -> u8 {
+        self.minor_units
+    }
+
+    /// The usual symbol of the currency, such as "€" for EUR, or its code if it has no symbol of
+    /// its own.
+    pub fn symbol(&self) -> &str {
+        match self.code() {
+            "EUR" => "€",
+            "GBP" => "£",
+            "ILS" => "₪",
+            "INR" => "₹",
+            "JPY" => "¥",
+            "KRW" => "₩",
+            "USD" => "$",
+            code => code,
+        }
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// An amount of money in a currency, always with exactly the decimals of the currency's minor
+/// unit. Amounts in different currencies can't be added or subtracted: the operators panic, and
+/// `try_add` and `try_sub` return an error instead. Comparing them gives None.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{Currency, IntFloat, Money, MoneyError, RoundingMode};
+/// let price = Money::new(IntFloat::new(1999, 2), Currency::EUR).unwrap();
+/// let shipping = Money::new(IntFloat::new(5, 0), Currency::EUR).unwrap();
+/// assert_eq!((price + shipping).to_string(), "24.99 EUR");
+/// assert_eq!((price * 3).to_string(), "59.97 EUR");
+///
+/// let vat = price.mul_rounded(IntFloat::new(21, 2), RoundingMode::HalfEven).unwrap();
+/// assert_eq!(vat.to_string(), "4.20 EUR");
+///
+/// let yen = Money::new(IntFloat::new(500, 0), Currency::JPY).unwrap();
+/// assert_eq!(price.try_add(yen), Err(MoneyError::CurrencyMismatch));
+/// assert_eq!(
+///     Money::new(IntFloat::new(12345, 3), Currency::EUR),
+///     Err(MoneyError::TooManyDecimals)
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Money {
+    amount: IntFloat,
+    currency: Currency,
+}
+
+impl Money {
+    /// An amount in `currency`, which may have fewer decimals than its minor unit but not more.
+    pub fn new(amount: IntFloat, currency: Currency) -> Result<Self, MoneyError> {
+        let minor_units = currency.minor_units as isize;
+        if amount.normalize().pow > minor_units {
+            return Err(MoneyError::TooManyDecimals);
+        }
+        Self::new_rounded(amount, currency, RoundingMode::HalfUp)
+    }
+
+    /// An amount in `currency`, rounded to its minor unit with `mode`.
+    pub fn new_rounded(
+        amount: IntFloat,
+        currency: Currency,
+        mode: RoundingMode,
+    ) -> Result<Self, MoneyError> {
+        let minor_units = currency.minor_units as isize;
+        let amount = amount
+            .round_dp_with(minor_units, mode)
+            .with_scale(minor_units)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { amount, currency })
+    }
+
+    /// The amount with the given number of minor units, such as cents.
+    pub fn from_minor(minor: isize, currency: Currency) -> Self {
+        Money {
+            amount: IntFloat::new(minor, currency.minor_units as isize),
+            currency,
+        }
+    }
+
+    pub fn amount(self) -> IntFloat {
+        self.amount
+    }
+
+    pub fn currency(self) -> Currency {
+        self.currency
+    }
+
+    /// The amount as a number of minor units, such as cents.
+    pub fn minor(self) -> isize {
+        self.amount.base
+    }
+
+    fn same_currency(self, rhs: Self) -> Result<(), MoneyError> {
+        if self.currency == rhs.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch)
+        }
+    }
+
+    pub fn try_add(self, rhs: Self) -> Result<Self, MoneyError> {
+        self.same_currency(rhs)?;
+        let minor = self.minor().checked_add(rhs.minor());
+        Ok(Money::from_minor(
+            minor.ok_or(MoneyError::Overflow)?,
+            self.currency,
+        ))
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, MoneyError> {
+        self.same_currency(rhs)?;
+        let minor = self.minor().checked_sub(rhs.minor());
+        Ok(Money::from_minor(
+            minor.ok_or(MoneyError::Overflow)?,
+            self.currency,
+        ))
+    }
+
+    /// Format the amount with the separators of `locale` and the symbol of its currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{Currency, Locale, Money};
+    /// let price = Money::from_minor(-123456, Currency::EUR);
+    /// assert_eq!(price.format_with(&Locale::EUROPEAN).to_string(), "-1.234,56 €");
+    /// let price = Money::from_minor(123456, Currency::CHF);
+    /// assert_eq!(price.format_with(&Locale::US).to_string(), "CHF 1,234.56");
+    /// ```
+    pub fn format_with<'a>(&'a self, locale: &'a Locale) -> CurrencyFormatted<'a, isize> {
+        self.amount.format_currency(self.currency.symbol(), locale)
+    }
+
+    /// Multiply by `factor`, such as an interest or tax rate, and round the result back to the
+    /// minor unit with `mode`.
+    pub fn mul_rounded(self, factor: IntFloat, mode: RoundingMode) -> Result<Self, MoneyError> {
+        let product = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Money::new_rounded(product, self.currency, mode)
+    }
+}
+
+/// Formatted as the amount with all decimals of the minor unit, followed by the currency code:
+/// "12.50 EUR".
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// Amounts in the same currency are ordered by value; amounts in different currencies can't be
+/// compared.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.same_currency(*other).ok()?;
+        Some(self.amount.cmp(&other.amount))
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Self) -> Money {
+        match self.try_add(rhs) {
+            Ok(sum) => sum,
+            Err(MoneyError::CurrencyMismatch) => {
+                panic!("attempt to add amounts in different currencies")
+            }
+            Err(_) => panic!("attempt to add with overflow"),
+        }
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Self) -> Money {
+        match self.try_sub(rhs) {
+            Ok(difference) => difference,
+            Err(MoneyError::CurrencyMismatch) => {
+                panic!("attempt to subtract amounts in different currencies")
+            }
+            Err(_) => panic!("attempt to subtract with overflow"),
+        }
+    }
+}
+
+/// Multiply by a whole number, which is always exact.
+impl Mul<isize> for Money {
+    type Output = Money;
+
+    fn mul(self, rhs: isize) -> Money {
+        let minor = self
+            .minor()
+            .checked_mul(rhs)
+            .expect("attempt to multiply with overflow");
+        Money::from_minor(minor, self.currency)
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money::from_minor(-self.minor(), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eur(amount: &str) -> Money {
+        Money::new(amount.parse().unwrap(), Currency::EUR).unwrap()
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(Currency::from_code("USD"), Some(Currency::USD));
+        assert_eq!(Currency::from_code("JPY"), Some(Currency::JPY));
+        assert_eq!(Currency::from_code("BHD"), Some(Currency::new("BHD", 3)));
+        assert_eq!(Currency::from_code("usd"), None);
+        assert_eq!(Currency::from_code("XXX"), None);
+        assert_eq!(Currency::GBP.to_string(), "GBP");
+        assert_eq!(Currency::GBP.symbol(), "£");
+        assert_eq!(Currency::CHF.symbol(), "CHF");
+    }
+
+    #[test]
+    #[should_panic(expected = "a currency code must be three uppercase ASCII letters")]
+    fn test_invalid_currency() {
+        Currency::new("EURO", 2);
+    }
+
+    #[test]
+    fn test_new() {
+        let this = eur("12.5");
+        assert_eq!(this.amount(), IntFloat::new(1250, 2));
+        assert_eq!(this.amount().to_string(), "12.50");
+        assert_eq!((this.minor(), this.currency()), (1250, Currency::EUR));
+        assert_eq!(eur("1.2300").amount().to_string(), "1.23");
+        assert_eq!(eur("2e3").to_string(), "2000.00 EUR");
+        assert_eq!(
+            Money::new(IntFloat::new(1, 1), Currency::JPY),
+            Err(MoneyError::TooManyDecimals)
+        );
+        assert_eq!(
+            Money::new(IntFloat::new(isize::MAX, 0), Currency::EUR),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(
+            Money::from_minor(-5, Currency::from_code("KWD").unwrap()).to_string(),
+            "-0.005 KWD"
+        );
+    }
+
+    #[test]
+    fn test_new_rounded() {
+        let amount = IntFloat::new(-12345, 3);
+        let rounded = |mode| Money::new_rounded(amount, Currency::EUR, mode).unwrap();
+        assert_eq!(rounded(RoundingMode::HalfUp), eur("-12.35"));
+        assert_eq!(rounded(RoundingMode::HalfEven), eur("-12.34"));
+        assert_eq!(rounded(RoundingMode::Ceiling), eur("-12.34"));
+        assert_eq!(
+            Money::new_rounded(IntFloat::new(5, 1), Currency::JPY, RoundingMode::HalfUp),
+            Ok(Money::from_minor(1, Currency::JPY))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (eur("10.25"), eur("0.80"));
+        assert_eq!(a + b, eur("11.05"));
+        assert_eq!(a - b, eur("9.45"));
+        assert_eq!(b - a, eur("-9.45"));
+        assert_eq!(-a, eur("-10.25"));
+        assert_eq!(a * -2, eur("-20.50"));
+        let usd = Money::from_minor(100, Currency::USD);
+        assert_eq!(a.try_add(usd), Err(MoneyError::CurrencyMismatch));
+        assert_eq!(a.try_sub(usd), Err(MoneyError::CurrencyMismatch));
+        let max = Money::from_minor(isize::MAX, Currency::EUR);
+        assert_eq!(max.try_add(b), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add amounts in different currencies")]
+    fn test_add_mismatch() {
+        let _ = eur("1") + Money::from_minor(100, Currency::USD);
+    }
+
+    #[test]
+    fn test_mul_rounded() {
+        let price = eur("19.99");
+        let rate = IntFloat::new(21, 2);
+        assert_eq!(
+            price.mul_rounded(rate, RoundingMode::HalfUp),
+            Ok(eur("4.20"))
+        );
+        assert_eq!(
+            price.mul_rounded(rate, RoundingMode::Floor),
+            Ok(eur("4.19"))
+        );
+        let yen = Money::from_minor(999, Currency::JPY);
+        assert_eq!(
+            yen.mul_rounded(IntFloat::new(5, 1), RoundingMode::HalfEven),
+            Ok(Money::from_minor(500, Currency::JPY))
+        );
+        assert_eq!(
+            price.mul_rounded(IntFloat::new(isize::MAX, 0), RoundingMode::HalfUp),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(eur("1.50") < eur("2"));
+        assert!(eur("-1") < eur("0"));
+        let usd = Money::from_minor(100, Currency::USD);
+        assert_eq!(eur("1").partial_cmp(&usd), None);
+    }
+
+    #[test]
+    fn test_format_with() {
+        let price = eur("-1234.5");
+        assert_eq!(
+            price.format_with(&Locale::EUROPEAN).to_string(),
+            "-1.234,50 €"
+        );
+        assert_eq!(price.format_with(&Locale::US).to_string(), "-€1,234.50");
+        let yen = Money::from_minor(1500000, Currency::JPY);
+        assert_eq!(yen.format_with(&Locale::US).to_string(), "¥1,500,000");
+        let francs = Money::from_minor(-150, Currency::CHF);
+        assert_eq!(francs.format_with(&Locale::US).to_string(), "-CHF 1.50");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use ::nalgebra::{Field, SimdValue};
+
+/// An IntFloat is a scalar with a single lane, like the primitive numbers. Together with `Field`
+/// this is all nalgebra needs beyond the num-traits implementations, so matrices of IntFloats
+/// can be added, subtracted, multiplied and transposed exactly.
+impl<T: BaseInt> SimdValue for IntFloat<T> {
+    const LANES: usize = 1;
+    type Element = IntFloat<T>;
+    type SimdBool = bool;
+
+    fn splat(val: Self::Element) -> Self {
+        val
+    }
+
+    fn extract(&self, _: usize) -> Self::Element {
+        *self
+    }
+
+    unsafe fn extract_unchecked(&self, _: usize) -> Self::Element {
+        *self
+    }
+
+    fn replace(&mut self, _: usize, val: Self::Element) {
+        *self = val
+    }
+
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self::Element) {
+        *self = val
+    }
+
+    fn select(self, cond: bool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// IntFloat is a field as far as nalgebra and simba are concerned, but `/` truncates like it does
+/// for IntFloat itself, so dividing a matrix by a scalar does too: map `div_with_precision` over
+/// it instead. `ComplexField` and `RealField`, which the decompositions, `determinant` and
+/// `try_inverse` need, aren't implemented, since their functions can't fail or take a number of
+/// decimals. Neither is converting from f64, which `mean` and `variance` need.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use nalgebra::{Matrix2, Matrix3x2};
+/// let x = |s: &str| s.parse::<IntFloat>().unwrap();
+/// // Three centered observations of two variables.
+/// let data = Matrix3x2::new(x("-1.5"), x("0.5"), x("0"), x("-1"), x("1.5"), x("0.5"));
+/// let covariance = (data.transpose() * data).map(|v| v.div_with_precision(x("2"), 2));
+/// assert_eq!(covariance, Matrix2::new(x("2.25"), x("0"), x("0"), x("0.75")));
+/// assert_eq!(covariance[(0, 0)].to_string(), "2.25");
+/// ```
+impl<T: BaseInt> Field for IntFloat<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+    use ::nalgebra::{Matrix2, Vector2, Vector3};
+
+    fn x(s: &str) -> IntFloat {
+        s.parse().unwrap()
+    }
+
+    fn square<F: Field + Copy>(value: F) -> F {
+        value * value
+    }
+
+    #[test]
+    fn test_matrix() {
+        let a = Matrix2::new(x("1.5"), x("2"), x("-0.25"), x("3"));
+        let b = Matrix2::new(x("0.1"), x("0"), x("4"), x("-1"));
+        assert_eq!(a + b, Matrix2::new(x("1.6"), x("2"), x("3.75"), x("2")));
+        assert_eq!(a - b, Matrix2::new(x("1.4"), x("2"), x("-4.25"), x("4")));
+        assert_eq!(
+            a * b,
+            Matrix2::new(x("8.15"), x("-2"), x("11.975"), x("-3"))
+        );
+        assert_eq!(a * Matrix2::identity(), a);
+        assert_eq!(-a, a * x("-1"));
+        assert_eq!(a.transpose()[(0, 1)], x("-0.25"));
+        assert_eq!(a.trace(), x("4.5"));
+
+        let v = Vector3::new(x("0.1"), x("0.2"), x("0.3"));
+        assert_eq!(v.dot(&v), x("0.14"));
+        assert_eq!(v.sum(), x("0.6"));
+        let c = Vector2::new(IntFloat32::new(5, 1), IntFloat32::new(-2, 0));
+        assert_eq!(
+            c.component_mul(&c),
+            Vector2::new(IntFloat32::new(25, 2), IntFloat32::new(4, 0))
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!(square(x("1.1")), x("1.21"));
+        let mut value = x("1.5");
+        assert_eq!(IntFloat::LANES, 1);
+        assert_eq!(value.extract(0), value);
+        value.replace(0, x("2.5"));
+        assert_eq!(value, IntFloat::splat(x("2.5")));
+        assert_eq!(value.select(false, x("1")), x("1"));
+    }
+}
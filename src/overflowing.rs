@@ -0,0 +1,181 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Multiply `base` by 10^`diff`, wrapping around at the boundary of the base. The flag tells
+    /// if that happened. Zero never overflows. A `diff` beyond u32::MAX is saturated, which
+    /// wraps to the same base, since 10^`diff` wraps to zero from the number of bits of the base.
+    fn overflowing_rescale(base: T, diff: usize) -> (T, bool) {
+        if base.is_zero() {
+            return (base, false);
+        }
+        let (factor, pow_overflow) =
+            T::TEN.overflowing_pow(u32::try_from(diff).unwrap_or(u32::MAX));
+        let (base, mul_overflow) = base.overflowing_mul(&factor);
+        (
+            base,
+            pow_overflow || mul_overflow || diff > u32::MAX as usize,
+        )
+    }
+
+    /// Bring both bases to the largest of both powers, wrapping on overflow.
+    fn overflowing_align(lhs: Self, rhs: Self) -> (T, T, isize, bool) {
+        let diff = lhs.pow.abs_diff(rhs.pow);
+        if rhs.pow > lhs.pow {
+            let (base, overflow) = Self::overflowing_rescale(lhs.base, diff);
+            (base, rhs.base, rhs.pow, overflow)
+        } else {
+            let (base, overflow) = Self::overflowing_rescale(rhs.base, diff);
+            (lhs.base, base, lhs.pow, overflow)
+        }
+    }
+}
+
+/// Arithmetic mirroring the overflowing_* methods of the primitive integers: the result is
+/// returned together with a flag that tells if the base (or the power) overflowed. If it did, the
+/// returned value holds the wrapped around base.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(isize::MAX, 2);
+/// assert_eq!(
+///     a.overflowing_add(IntFloat::new(1, 2)),
+///     (IntFloat::new(isize::MIN, 2), true)
+/// );
+/// assert_eq!(
+///     IntFloat::new(1, 0).overflowing_sub(IntFloat::new(1, 2)),
+///     (IntFloat::new(99, 2), false)
+/// );
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (lhs_base, rhs_base, pow, align_overflow) = Self::overflowing_align(self, rhs);
+        let (base, overflow) = lhs_base.overflowing_add(&rhs_base);
+        (IntFloat { base, pow }, align_overflow || overflow)
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (lhs_base, rhs_base, pow, align_overflow) = Self::overflowing_align(self, rhs);
+        let (base, overflow) = lhs_base.overflowing_sub(&rhs_base);
+        (IntFloat { base, pow }, align_overflow || overflow)
+    }
+
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (base, base_overflow) = self.base.overflowing_mul(&rhs.base);
+        let (pow, pow_overflow) = self.pow.overflowing_add(rhs.pow);
+        (IntFloat { base, pow }, base_overflow || pow_overflow)
+    }
+
+    /// Panics if `rhs` is zero, just like the primitive integers do.
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        let (base, base_overflow) = self.base.overflowing_div(rhs.base);
+        let (pow, pow_overflow) = self.pow.overflowing_sub(rhs.pow);
+        (IntFloat { base, pow }, base_overflow || pow_overflow)
+    }
+}
+
+impl<T: BaseInt> OverflowingAdd for IntFloat<T> {
+    fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+        IntFloat::overflowing_add(*self, *v)
+    }
+}
+
+impl<T: BaseInt> OverflowingSub for IntFloat<T> {
+    fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+        IntFloat::overflowing_sub(*self, *v)
+    }
+}
+
+impl<T: BaseInt> OverflowingMul for IntFloat<T> {
+    fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+        IntFloat::overflowing_mul(*self, *v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_overflowing_add() {
+        let this = IntFloat::new(534, 0);
+        let that = IntFloat::new(100, 2);
+        assert_eq!(this.overflowing_add(that), (this + that, false));
+        assert_eq!(that.overflowing_add(this), (this + that, false));
+
+        let (result, overflow) = IntFloat::new(isize::MAX, 0).overflowing_add(IntFloat::new(1, 0));
+        assert_eq!((result.base, result.pow, overflow), (isize::MIN, 0, true));
+
+        let (result, overflow) = IntFloat::new(isize::MAX, 0).overflowing_add(IntFloat::new(0, 1));
+        assert_eq!((result.base, result.pow, overflow), (-10, 1, true));
+
+        let (_, overflow) = IntFloat::new(1, 0).overflowing_add(IntFloat::new(1, 19));
+        assert!(overflow);
+
+        let (result, overflow) = IntFloat::new(0, 0).overflowing_add(IntFloat::new(5, 30));
+        assert_eq!((result.base, result.pow, overflow), (5, 30, false));
+        let (result, overflow) =
+            IntFloat::new(-5, isize::MIN).overflowing_add(IntFloat::new(0, isize::MAX));
+        assert_eq!((result.base, result.pow, overflow), (0, isize::MAX, true));
+        let (result, overflow) = IntFloat::new(1, -1).overflowing_add(IntFloat::new(1, isize::MAX));
+        assert_eq!((result.base, result.pow, overflow), (1, isize::MAX, true));
+    }
+
+    #[test]
+    fn test_overflowing_sub() {
+        let this = IntFloat::new(534, -2);
+        let that = IntFloat::new(1068, -2);
+        assert_eq!(that.overflowing_sub(this), (this, false));
+
+        let (result, overflow) = IntFloat::new(0, 0).overflowing_sub(IntFloat::new(isize::MIN, 0));
+        assert_eq!((result.base, result.pow, overflow), (isize::MIN, 0, true));
+        let (result, overflow) = IntFloat::new(-1, 0).overflowing_sub(IntFloat::new(isize::MIN, 0));
+        assert_eq!((result.base, result.pow, overflow), (isize::MAX, 0, false));
+    }
+
+    #[test]
+    fn test_overflowing_mul() {
+        let this = IntFloat::new(500, 2);
+        assert_eq!(
+            this.overflowing_mul(this),
+            (IntFloat::new(250000, 4), false)
+        );
+
+        let this = IntFloat::new(isize::MAX, 3);
+        let (result, overflow) = this.overflowing_mul(IntFloat::new(2, 1));
+        assert_eq!((result.base, result.pow, overflow), (-2, 4, true));
+
+        let (result, overflow) = IntFloat::new(1, isize::MAX).overflowing_mul(IntFloat::new(1, 1));
+        assert_eq!((result.base, result.pow, overflow), (1, isize::MIN, true));
+    }
+
+    #[test]
+    fn test_overflowing_div() {
+        let this = IntFloat::new(500, 2);
+        let that = IntFloat::new(250000, 4);
+        assert_eq!(that.overflowing_div(this), (this, false));
+
+        let (result, overflow) = IntFloat::new(isize::MIN, 0).overflowing_div(IntFloat::new(-1, 0));
+        assert_eq!((result.base, result.pow, overflow), (isize::MIN, 0, true));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflowing_div_by_zero() {
+        let _ = IntFloat::new(1, 0).overflowing_div(IntFloat::new(0, 0));
+    }
+
+    #[test]
+    fn test_overflowing_traits() {
+        let this = IntFloat::new(isize::MAX, 0);
+        let that = IntFloat::new(2, 0);
+        assert!(OverflowingAdd::overflowing_add(&this, &that).1);
+        assert!(OverflowingSub::overflowing_sub(&-this, &that).1);
+        assert!(OverflowingMul::overflowing_mul(&this, &that).1);
+        assert!(!OverflowingMul::overflowing_mul(&that, &that).1);
+    }
+}
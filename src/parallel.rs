@@ -0,0 +1,121 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use rayon::prelude::*;
+
+/// Parallel reductions over slices of IntFloats, using Rayon. The results are exactly the same
+/// as those of the sequential `Sum`, `Product`, `min` and `max`, including the pow. Only overflow
+/// can differ, because the partial results are combined in another order: the `checked_`
+/// versions return None if any partial result doesn't fit in the base, and the others panic.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, ParallelIntFloat};
+/// let prices: Vec<IntFloat> = (1..=10_000).map(|cents| IntFloat::new(cents, 2)).collect();
+/// assert_eq!(prices.par_sum().to_string(), "500050.00");
+/// assert_eq!(prices.par_max(), Some(IntFloat::new(100, 0)));
+/// assert_eq!(prices[..3].par_product().to_string(), "0.000006");
+/// ```
+pub trait ParallelIntFloat<T> {
+    /// The sum of all values, zero for an empty slice. Panics on overflow.
+    fn par_sum(&self) -> IntFloat<T>;
+
+    /// Like `par_sum`, but None on overflow.
+    fn checked_par_sum(&self) -> Option<IntFloat<T>>;
+
+    /// The product of all values, one for an empty slice. Panics on overflow.
+    fn par_product(&self) -> IntFloat<T>;
+
+    /// Like `par_product`, but None on overflow.
+    fn checked_par_product(&self) -> Option<IntFloat<T>>;
+
+    /// The smallest value, or None for an empty slice.
+    fn par_min(&self) -> Option<IntFloat<T>>;
+
+    /// The largest value, or None for an empty slice.
+    fn par_max(&self) -> Option<IntFloat<T>>;
+}
+
+impl<T: BaseInt + Send + Sync> ParallelIntFloat<T> for [IntFloat<T>] {
+    fn par_sum(&self) -> IntFloat<T> {
+        self.checked_par_sum()
+            .expect("attempt to add with overflow")
+    }
+
+    fn checked_par_sum(&self) -> Option<IntFloat<T>> {
+        self.par_iter()
+            .try_fold(IntFloat::zero, |total, value| total.checked_add(*value))
+            .try_reduce(IntFloat::zero, IntFloat::checked_add)
+    }
+
+    fn par_product(&self) -> IntFloat<T> {
+        self.checked_par_product()
+            .expect("attempt to multiply with overflow")
+    }
+
+    fn checked_par_product(&self) -> Option<IntFloat<T>> {
+        self.par_iter()
+            .try_fold(IntFloat::one, |total, value| total.checked_mul(*value))
+            .try_reduce(IntFloat::one, IntFloat::checked_mul)
+    }
+
+    fn par_min(&self) -> Option<IntFloat<T>> {
+        self.par_iter().copied().min()
+    }
+
+    fn par_max(&self) -> Option<IntFloat<T>> {
+        self.par_iter().copied().max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+
+    fn values() -> Vec<IntFloat> {
+        (-5000..5000)
+            .map(|base| IntFloat::new(base, base.rem_euclid(4)))
+            .collect()
+    }
+
+    #[test]
+    fn test_par_sum() {
+        let values = values();
+        assert_eq!(values.par_sum(), values.iter().sum::<IntFloat>());
+        assert_eq!(values.par_sum().pow, 3);
+        assert_eq!([IntFloat::new(15, -2)].par_sum(), IntFloat::new(1500, 0));
+        assert_eq!(<[IntFloat]>::par_sum(&[]), IntFloat::new(0, 0));
+        let large = vec![IntFloat32::new(i32::MAX / 100, 0); 1000];
+        assert_eq!(large.checked_par_sum(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_par_sum_overflow() {
+        [IntFloat32::new(i32::MAX, 0), IntFloat32::new(1, 0)].par_sum();
+    }
+
+    #[test]
+    fn test_par_product() {
+        let values: Vec<IntFloat> = (1..=20).map(|base| IntFloat::new(base, 1)).collect();
+        assert_eq!(values.par_product(), values.iter().product::<IntFloat>());
+        assert_eq!(values.par_product().pow, 20);
+        assert_eq!(<[IntFloat]>::par_product(&[]), IntFloat::new(1, 0));
+        assert_eq!(
+            values[..19].par_product().to_string(),
+            "0.0121645100408832000"
+        );
+        let large = [IntFloat32::new(10, 0); 10];
+        assert_eq!(large.checked_par_product(), None);
+    }
+
+    #[test]
+    fn test_par_min_max() {
+        let values = values();
+        assert_eq!(values.par_min(), Some(IntFloat::new(-5000, 0)));
+        assert_eq!(values.par_max(), Some(IntFloat::new(4996, 0)));
+        assert_eq!(values.par_max(), values.iter().copied().max());
+        assert_eq!(<[IntFloat]>::par_min(&[]), None);
+    }
+}
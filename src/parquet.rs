@@ -0,0 +1,161 @@
+//! Exact conversions between IntFloat and the DECIMAL logical type of Parquet, as used by the
+//! record API of the parquet crate (`Field::Decimal` and `Row::get_decimal`). A DECIMAL column
+//! has a fixed precision and scale. Following the Parquet format, `to_decimal` stores values with
+//! a precision up to 9 as an INT32, up to 18 as an INT64, and above that as a big-endian byte
+//! array with as few bytes as the precision allows.
+//!
+//! ```
+//! use intfloat::{parquet, IntFloat};
+//! use ::parquet::record::Field;
+//! let decimal = parquet::to_decimal(IntFloat::new(1999, 2), 9, 2).unwrap();
+//! assert_eq!(decimal.data(), 1999_i32.to_be_bytes());
+//! let field = Field::Decimal(decimal);
+//! if let Field::Decimal(decimal) = &field {
+//!     assert_eq!(parquet::from_decimal(decimal), Ok(IntFloat::new(1999, 2)));
+//! }
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use ::parquet::data_type::{ByteArray, Decimal};
+
+/// The largest precision of a DECIMAL that fits in an i128.
+const MAX_PRECISION: i32 = 38;
+
+/// The number of bytes a big-endian byte array DECIMAL needs for `precision` digits.
+fn byte_len(precision: i32) -> usize {
+    let limit = 10_u128.pow(precision as u32);
+    (1..16)
+        .find(|len| 1_u128 << (8 * len - 1) >= limit)
+        .unwrap_or(16)
+}
+
+/// The DECIMAL with the given precision and scale for `value`. Fails if the precision or scale
+/// isn't valid, or if `value` doesn't fit exactly: when it has non-zero digits beyond `scale`, or
+/// more than `precision` digits in total.
+pub fn to_decimal<T: BaseInt>(
+    value: IntFloat<T>,
+    precision: i32,
+    scale: i32,
+) -> Result<Decimal, OutOfRangeError> {
+    if !(1..=MAX_PRECISION).contains(&precision) || !(0..=precision).contains(&scale) {
+        return Err(OutOfRangeError);
+    }
+    let unscaled = value
+        .to_scaled_i128(scale as isize)
+        .ok_or(OutOfRangeError)?;
+    if unscaled.unsigned_abs() >= 10_u128.pow(precision as u32) {
+        return Err(OutOfRangeError);
+    }
+    Ok(match precision {
+        1..=9 => Decimal::from_i32(unscaled as i32, precision, scale),
+        10..=18 => Decimal::from_i64(unscaled as i64, precision, scale),
+        _ => {
+            let bytes = unscaled.to_be_bytes();
+            let value = ByteArray::from(bytes[16 - byte_len(precision)..].to_vec());
+            Decimal::from_bytes(value, precision, scale)
+        }
+    })
+}
+
+/// The IntFloat for a DECIMAL, with its scale as the number of decimals. Fails if the value
+/// doesn't fit in the base.
+pub fn from_decimal<T: BaseInt>(decimal: &Decimal) -> Result<IntFloat<T>, OutOfRangeError> {
+    let data = decimal.data();
+    let negative = data.first().is_some_and(|byte| byte & 0x80 != 0);
+    let fill = if negative { 0xff } else { 0 };
+    let (extra, data) = data.split_at(data.len().saturating_sub(16));
+    if extra.iter().any(|byte| *byte != fill) {
+        return Err(OutOfRangeError);
+    }
+    if !extra.is_empty() && (data[0] & 0x80 != 0) != negative {
+        return Err(OutOfRangeError);
+    }
+    let mut bytes = [fill; 16];
+    bytes[16 - data.len()..].copy_from_slice(data);
+    let base = num_traits::cast(i128::from_be_bytes(bytes)).ok_or(OutOfRangeError)?;
+    Ok(IntFloat::new(base, decimal.scale() as isize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    #[test]
+    fn test_physical_types() {
+        let value = IntFloat::new(-1250, 3);
+        assert_eq!(to_decimal(value, 9, 2), Ok(Decimal::from_i32(-125, 9, 2)));
+        assert_eq!(
+            to_decimal(value, 18, 4),
+            Ok(Decimal::from_i64(-12500, 18, 4))
+        );
+        let bytes = to_decimal(value, 20, 2).unwrap();
+        assert_eq!(
+            bytes.data(),
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x83]
+        );
+        assert_eq!(to_decimal(value, 38, 0), Err(OutOfRangeError));
+        let max = IntFloat128::new(10_i128.pow(38) - 1, 0);
+        assert_eq!(to_decimal(max, 38, 0).unwrap().data().len(), 16);
+    }
+
+    #[test]
+    fn test_byte_len() {
+        assert_eq!(byte_len(1), 1);
+        assert_eq!(byte_len(2), 1);
+        assert_eq!(byte_len(3), 2);
+        assert_eq!(byte_len(19), 9);
+        assert_eq!(byte_len(38), 16);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let value = IntFloat::new(100, 0);
+        assert_eq!(to_decimal(value, 2, 0), Err(OutOfRangeError));
+        assert!(to_decimal(value, 3, 0).is_ok());
+        assert_eq!(to_decimal(value, 3, 1), Err(OutOfRangeError));
+        assert_eq!(to_decimal(value, 39, 0), Err(OutOfRangeError));
+        assert_eq!(to_decimal(value, 0, 0), Err(OutOfRangeError));
+        assert_eq!(to_decimal(value, 5, -1), Err(OutOfRangeError));
+        assert_eq!(
+            to_decimal(IntFloat::new(125, 3), 5, 2),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        let decimal = |bytes: &[u8]| Decimal::from_bytes(ByteArray::from(bytes.to_vec()), 20, 2);
+        assert_eq!(
+            from_decimal(&Decimal::from_i32(-125, 9, 2)),
+            Ok(IntFloat::new(-125, 2))
+        );
+        assert_eq!(
+            from_decimal(&Decimal::from_i64(i64::MAX, 18, 0)),
+            Ok(IntFloat::new(i64::MAX as isize, 0))
+        );
+        assert_eq!(from_decimal(&decimal(&[0x83])), Ok(IntFloat::new(-125, 2)));
+        assert_eq!(
+            from_decimal(&decimal(&[0x00, 0x83])),
+            Ok(IntFloat::new(131, 2))
+        );
+        assert_eq!(from_decimal(&decimal(&[])), Ok(IntFloat::new(0, 2)));
+        assert_eq!(
+            from_decimal::<i32>(&decimal(&[0x01, 0, 0, 0, 0])),
+            Err(OutOfRangeError)
+        );
+        let mut long = vec![0xff; 4];
+        long.extend([0x80; 16]);
+        assert_eq!(
+            from_decimal(&decimal(&long)),
+            Ok(IntFloat128::new(i128::from_be_bytes([0x80; 16]), 2))
+        );
+        long[0] = 0;
+        assert_eq!(from_decimal::<i128>(&decimal(&long)), Err(OutOfRangeError));
+        let wide = IntFloat128::new(-(10_i128.pow(30)) + 1, 5);
+        assert_eq!(from_decimal(&to_decimal(wide, 38, 5).unwrap()), Ok(wide));
+        let small = IntFloat32::new(7, 1);
+        assert_eq!(from_decimal(&to_decimal(small, 4, 3).unwrap()), Ok(small));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloatError};
+
+/// Validated construction. `new` accepts any pow, but a value whose pow is further from zero
+/// than the number of decimal digits of the base can't be brought to the pow of an integer
+/// without overflowing, so adding, comparing or rescaling it will likely panic later on.
+/// `from_parts` rejects such pows right away.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloat32, IntFloatError};
+/// assert_eq!(IntFloat::from_parts(1250, 2), Ok(IntFloat::new(1250, 2)));
+/// assert_eq!(IntFloat32::MAX_SCALE, 9);
+/// assert_eq!(IntFloat32::from_parts(1, 10), Err(IntFloatError::ScaleTooLarge));
+/// assert_eq!(IntFloat32::from_parts_normalized(1000, 10), Ok(IntFloat32::new(1, 7)));
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// The largest pow, in absolute value, that `from_parts` accepts: the number of decimal
+    /// digits that always fit in the base, e.g. 18 for an i64.
+    pub const MAX_SCALE: isize = T::DIGITS as isize;
+
+    /// The value `base * 10^-pow`, like `new`, or `ScaleTooLarge` if `pow` is outside
+    /// `-MAX_SCALE..=MAX_SCALE`.
+    pub fn from_parts(base: T, pow: isize) -> Result<Self, IntFloatError> {
+        if pow.unsigned_abs() > Self::MAX_SCALE as usize {
+            return Err(IntFloatError::ScaleTooLarge);
+        }
+        Ok(IntFloat::new(base, pow))
+    }
+
+    /// Like `from_parts`, but normalized first, so that trailing zeros of `base` can bring a
+    /// large pow into range.
+    pub fn from_parts_normalized(base: T, pow: isize) -> Result<Self, IntFloatError> {
+        let normalized = IntFloat::new(base, pow).normalize();
+        Self::from_parts(normalized.base, normalized.pow)
+    }
+
+    /// A builder, starting from zero with a pow of 0.
+    pub fn builder() -> IntFloatBuilder<T> {
+        IntFloatBuilder::new()
+    }
+}
+
+/// Builder of a validated IntFloat, for when the mantissa and scale come from different places,
+/// e.g. while decoding a custom format. `build` checks the scale like `IntFloat::from_parts`.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloatError};
+/// let price = IntFloat::builder().mantissa(1999).scale(2).build();
+/// assert_eq!(price.unwrap().to_string(), "19.99");
+/// let tiny = IntFloat::builder().mantissa(5).scale(40);
+/// assert_eq!(tiny.build(), Err(IntFloatError::ScaleTooLarge));
+/// let round = IntFloat::builder().mantissa(1500).scale(2).normalized().build();
+/// assert_eq!(round.unwrap().to_string(), "15");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IntFloatBuilder<T> {
+    base: T,
+    pow: isize,
+    normalize: bool,
+}
+
+impl<T: BaseInt> IntFloatBuilder<T> {
+    pub fn new() -> Self {
+        IntFloatBuilder {
+            base: T::ZERO,
+            pow: 0,
+            normalize: false,
+        }
+    }
+
+    /// Set the base.
+    pub fn mantissa(self, base: T) -> Self {
+        IntFloatBuilder { base, ..self }
+    }
+
+    /// Set the pow, i.e. the number of decimals.
+    pub fn scale(self, pow: isize) -> Self {
+        IntFloatBuilder { pow, ..self }
+    }
+
+    /// Strip trailing zeros from the base before checking the scale.
+    pub fn normalized(self) -> Self {
+        IntFloatBuilder {
+            normalize: true,
+            ..self
+        }
+    }
+
+    /// The IntFloat, or `ScaleTooLarge` if its pow is out of range.
+    pub fn build(self) -> Result<IntFloat<T>, IntFloatError> {
+        if self.normalize {
+            IntFloat::from_parts_normalized(self.base, self.pow)
+        } else {
+            IntFloat::from_parts(self.base, self.pow)
+        }
+    }
+}
+
+impl<T: BaseInt> Default for IntFloatBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generic, IntFloat, IntFloat128, IntFloat64};
+
+    #[test]
+    fn test_from_parts() {
+        assert_eq!(IntFloat64::MAX_SCALE, 18);
+        assert_eq!(IntFloat128::MAX_SCALE, 38);
+        assert_eq!(generic::IntFloat::<i8>::MAX_SCALE, 2);
+        assert_eq!(IntFloat64::from_parts(5, -18), Ok(IntFloat64::new(5, -18)));
+        assert_eq!(IntFloat64::from_parts(5, 18), Ok(IntFloat64::new(5, 18)));
+        assert_eq!(
+            IntFloat64::from_parts(5, 19),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+        assert_eq!(
+            IntFloat64::from_parts(5, -19),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+        assert_eq!(
+            IntFloat64::from_parts(0, isize::MIN),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+
+        let this = IntFloat64::from_parts_normalized(12300, 20).unwrap();
+        assert_eq!((this.base, this.pow), (123, 18));
+        assert_eq!(
+            IntFloat64::from_parts_normalized(1230, 20),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+        assert_eq!(
+            IntFloat64::from_parts_normalized(0, isize::MAX),
+            Ok(IntFloat64::new(0, 0))
+        );
+        assert_eq!(
+            IntFloat64::from_parts_normalized(100, -18),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let this = IntFloat::builder().build().unwrap();
+        assert_eq!((this.base, this.pow), (0, 0));
+        let this = IntFloatBuilder::default()
+            .scale(3)
+            .mantissa(-42)
+            .build()
+            .unwrap();
+        assert_eq!((this.base, this.pow), (-42, 3));
+        let this = generic::IntFloat::<i8>::builder()
+            .mantissa(100)
+            .scale(4)
+            .normalized()
+            .build()
+            .unwrap();
+        assert_eq!((this.base, this.pow), (1, 2));
+        assert_eq!(
+            generic::IntFloat::<i8>::builder()
+                .mantissa(100)
+                .scale(4)
+                .build(),
+            Err(IntFloatError::ScaleTooLarge)
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+
+/// The sign of a positive NUMERIC.
+const POSITIVE: u16 = 0x0000;
+/// The sign of a negative NUMERIC.
+const NEGATIVE: u16 = 0x4000;
+/// The largest number of decimals of a NUMERIC.
+const MAX_SCALE: isize = 0x3fff;
+
+/// A Postgres NUMERIC in its binary form: base-10000 digits, where the first digit is multiplied
+/// by 10000^weight, and the scale is the number of decimals shown. Zero has no digits, and there
+/// are no leading or trailing zero digits.
+///
+/// The binary form is the number of digits, the weight, the sign and the scale, followed by the
+/// digits, all as big-endian 16-bit integers.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct PgNumeric {
+    pub(crate) weight: i16,
+    pub(crate) negative: bool,
+    pub(crate) scale: u16,
+    pub(crate) digits: Vec<i16>,
+}
+
+impl PgNumeric {
+    /// The NUMERIC for `value`, with its pow as the scale. A negative pow is rescaled to zero
+    /// decimals. Fails if that overflows the base, or if the pow is above the largest scale of a
+    /// NUMERIC.
+    pub(crate) fn from_intfloat<T: BaseInt>(value: IntFloat<T>) -> Result<Self, OutOfRangeError> {
+        let value = if value.pow < 0 {
+            value.with_scale(0).ok_or(OutOfRangeError)?
+        } else {
+            value
+        };
+        if value.pow > MAX_SCALE {
+            return Err(OutOfRangeError);
+        }
+        let scale = value.pow as usize;
+        let mut decimals = value
+            .base
+            .to_i128()
+            .ok_or(OutOfRangeError)?
+            .unsigned_abs()
+            .to_string();
+        // Pad with zeros to whole digits on both sides of the decimal point.
+        let int_len = decimals.len().saturating_sub(scale);
+        let int_pad = (4 - int_len % 4) % 4;
+        let frac_pad = (4 - scale % 4) % 4;
+        let leading = int_pad + scale.saturating_sub(decimals.len());
+        decimals.insert_str(0, &"0".repeat(leading));
+        decimals.push_str(&"0".repeat(frac_pad));
+
+        let mut weight = ((int_len + int_pad) / 4) as isize - 1;
+        let mut digits: Vec<i16> = decimals
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |digit, byte| digit * 10 + (byte - b'0') as i16)
+            })
+            .collect();
+        let zeros = digits.iter().take_while(|digit| **digit == 0).count();
+        digits.drain(..zeros);
+        weight -= zeros as isize;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            weight = 0;
+        }
+        Ok(PgNumeric {
+            weight: i16::try_from(weight).map_err(|_| OutOfRangeError)?,
+            negative: value.base < T::ZERO,
+            scale: scale as u16,
+            digits,
+        })
+    }
+
+    /// The IntFloat for this NUMERIC, with its scale as the number of decimals. Fails if the
+    /// value doesn't fit in the base, or if it has non-zero digits beyond the scale.
+    pub(crate) fn to_intfloat<T: BaseInt>(&self) -> Result<IntFloat<T>, OutOfRangeError> {
+        let mut base: i128 = 0;
+        for (index, digit) in self.digits.iter().enumerate() {
+            if *digit == 0 {
+                continue;
+            }
+            // The last digit can extend up to 3 decimals beyond the scale, as zeros.
+            let exponent = 4 * (self.weight as isize - index as isize) + self.scale as isize;
+            let part = if exponent < 0 {
+                let divisor = u32::try_from(-exponent)
+                    .ok()
+                    .and_then(|exponent| 10_i16.checked_pow(exponent))
+                    .filter(|divisor| digit % divisor == 0)
+                    .ok_or(OutOfRangeError)?;
+                (digit / divisor) as i128
+            } else {
+                u32::try_from(exponent)
+                    .ok()
+                    .and_then(|exponent| 10_i128.checked_pow(exponent))
+                    .and_then(|factor| (*digit as i128).checked_mul(factor))
+                    .ok_or(OutOfRangeError)?
+            };
+            base = base.checked_add(part).ok_or(OutOfRangeError)?;
+        }
+        if self.negative {
+            base = -base;
+        }
+        let base = num_traits::cast(base).ok_or(OutOfRangeError)?;
+        Ok(IntFloat::new(base, self.scale as isize))
+    }
+
+    /// The binary form of this NUMERIC.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let sign = if self.negative { NEGATIVE } else { POSITIVE };
+        let mut bytes = Vec::with_capacity(8 + 2 * self.digits.len());
+        bytes.extend((self.digits.len() as i16).to_be_bytes());
+        bytes.extend(self.weight.to_be_bytes());
+        bytes.extend(sign.to_be_bytes());
+        bytes.extend(self.scale.to_be_bytes());
+        for digit in &self.digits {
+            bytes.extend(digit.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// The NUMERIC for its binary form. Fails for NaN and infinity, and for malformed bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, OutOfRangeError> {
+        let word = |index: usize| {
+            bytes
+                .get(2 * index..2 * index + 2)
+                .map(|word| [word[0], word[1]])
+                .ok_or(OutOfRangeError)
+        };
+        let len = usize::try_from(i16::from_be_bytes(word(0)?)).map_err(|_| OutOfRangeError)?;
+        if bytes.len() != 8 + 2 * len {
+            return Err(OutOfRangeError);
+        }
+        let negative = match u16::from_be_bytes(word(2)?) {
+            POSITIVE => false,
+            NEGATIVE => true,
+            _ => return Err(OutOfRangeError),
+        };
+        let digits = (4..4 + len)
+            .map(|index| {
+                let digit = i16::from_be_bytes(word(index)?);
+                if (0..10000).contains(&digit) {
+                    Ok(digit)
+                } else {
+                    Err(OutOfRangeError)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(PgNumeric {
+            weight: i16::from_be_bytes(word(1)?),
+            negative,
+            scale: u16::from_be_bytes(word(3)?),
+            digits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    fn numeric(weight: i16, negative: bool, scale: u16, digits: &[i16]) -> PgNumeric {
+        PgNumeric {
+            weight,
+            negative,
+            scale,
+            digits: digits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_from_intfloat() {
+        let from = |value: IntFloat| PgNumeric::from_intfloat(value);
+        assert_eq!(from(IntFloat::new(0, 0)), Ok(numeric(0, false, 0, &[])));
+        assert_eq!(from(IntFloat::new(0, 3)), Ok(numeric(0, false, 3, &[])));
+        assert_eq!(
+            from(IntFloat::new(123456789, 4)),
+            Ok(numeric(1, false, 4, &[1, 2345, 6789]))
+        );
+        assert_eq!(
+            from(IntFloat::new(-1250, 2)),
+            Ok(numeric(0, true, 2, &[12, 5000]))
+        );
+        assert_eq!(
+            from(IntFloat::new(1, 5)),
+            Ok(numeric(-2, false, 5, &[1000]))
+        );
+        assert_eq!(
+            from(IntFloat::new(10000, 0)),
+            Ok(numeric(1, false, 0, &[1]))
+        );
+        assert_eq!(from(IntFloat::new(5, -8)), Ok(numeric(2, false, 0, &[5])));
+        assert_eq!(from(IntFloat::new(1, MAX_SCALE + 1)), Err(OutOfRangeError));
+        assert_eq!(from(IntFloat::new(1, isize::MIN)), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_to_intfloat() {
+        assert_eq!(
+            numeric(1, false, 4, &[1, 2345, 6789]).to_intfloat(),
+            Ok(IntFloat::new(123456789, 4))
+        );
+        assert_eq!(
+            numeric(0, true, 2, &[12, 5000]).to_intfloat(),
+            Ok(IntFloat::new(-1250, 2))
+        );
+        assert_eq!(
+            numeric(2, false, 0, &[5]).to_intfloat(),
+            Ok(IntFloat::new(500000000, 0))
+        );
+        assert_eq!(
+            numeric(0, false, 2, &[1, 2300]).to_intfloat(),
+            Ok(IntFloat::new(123, 2))
+        );
+        assert_eq!(
+            numeric(0, false, 2, &[1, 2345]).to_intfloat::<isize>(),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            numeric(0, false, 20, &[]).to_intfloat(),
+            Ok(IntFloat::new(0, 20))
+        );
+        assert_eq!(
+            numeric(9, false, 0, &[1]).to_intfloat::<i32>(),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            numeric(10, false, 0, &[1]).to_intfloat::<i128>(),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let value = numeric(0, true, 2, &[12, 5000]);
+        let bytes = value.to_bytes();
+        assert_eq!(bytes, [0, 2, 0, 0, 0x40, 0, 0, 2, 0, 12, 0x13, 0x88]);
+        assert_eq!(PgNumeric::from_bytes(&bytes), Ok(value));
+        // NaN
+        assert!(PgNumeric::from_bytes(&[0, 0, 0, 0, 0xc0, 0, 0, 0]).is_err());
+        assert!(PgNumeric::from_bytes(&bytes[..10]).is_err());
+        assert!(PgNumeric::from_bytes(&[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10]).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            IntFloat128::new(i128::MAX, 0),
+            IntFloat128::new(i128::MIN + 1, 38),
+            IntFloat128::new(-7, 100),
+        ] {
+            let numeric = PgNumeric::from_intfloat(value).unwrap();
+            let bytes = numeric.to_bytes();
+            assert_eq!(
+                PgNumeric::from_bytes(&bytes).unwrap().to_intfloat(),
+                Ok(value)
+            );
+        }
+        let small = IntFloat32::new(-3, 1);
+        let numeric = PgNumeric::from_intfloat(small).unwrap();
+        assert_eq!(numeric.to_intfloat(), Ok(small));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use bytemuck::{Pod, Zeroable};
+
+// All zeros is a valid IntFloat for every base: zero at scale 0.
+unsafe impl<T: BaseInt> Zeroable for IntFloat<T> {}
+
+// IntFloat is `#[repr(C)]`, so it is Pod when the base and the pow leave no padding between or
+// after them. That holds for an isize base on every platform, and for an i64 base where isize is
+// 64 bits wide. The smaller bases, and i128 with its 16-byte alignment, do leave padding.
+const _: () = assert!(size_of::<IntFloat<isize>>() == 2 * size_of::<isize>());
+unsafe impl Pod for IntFloat<isize> {}
+
+#[cfg(target_pointer_width = "64")]
+const _: () = assert!(size_of::<IntFloat<i64>>() == 16);
+#[cfg(target_pointer_width = "64")]
+unsafe impl Pod for IntFloat<i64> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat, IntFloat128, IntFloat32, IntFloat64};
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_cast_slice() {
+        let values = vec![IntFloat::new(1999, 2), IntFloat::new(-5, 1)];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes.len(), 2 * size_of::<IntFloat>());
+        assert_eq!(
+            bytes[..size_of::<IntFloat>()],
+            [1999_isize.to_ne_bytes(), 2_isize.to_ne_bytes()].concat()
+        );
+        let back: &[IntFloat] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &values[..]);
+        let wide: &[IntFloat64] = bytemuck::cast_slice(bytes);
+        assert_eq!(wide[1], IntFloat64::new(-5, 1));
+    }
+
+    #[test]
+    fn test_zeroed() {
+        assert_eq!(IntFloat32::zeroed(), IntFloat32::new(0, 0));
+        assert_eq!(IntFloat128::zeroed().pow, 0);
+        let value: IntFloat = bytemuck::cast([-15_isize, 1]);
+        assert_eq!(value, IntFloat::new(-15, 1));
+    }
+}
@@ -0,0 +1,138 @@
+//! Exact conversions between columns of IntFloats and Polars Series with the Decimal dtype, which
+//! stores every value as an i128 with a fixed precision and scale for the whole column. Nulls
+//! map to None.
+//!
+//! ```
+//! use intfloat::{polars, IntFloat};
+//! let prices = [Some(IntFloat::new(1999, 2)), None, Some(IntFloat::new(5, 0))];
+//! let series = polars::to_series("price", prices, 10, 2).unwrap();
+//! assert_eq!(series.dtype().to_string(), "decimal[10,2]");
+//! assert_eq!(series.null_count(), 1);
+//! let back: Vec<Option<IntFloat>> = polars::from_series(&series).unwrap();
+//! assert_eq!(back[2].unwrap().to_string(), "5.00");
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, OutOfRangeError};
+use ::polars::prelude::{AnyValue, Int128Chunked, IntoSeries, NewChunkedArray, Series};
+
+/// A Series named `name` with the Decimal dtype of the given precision and scale. Fails if the
+/// precision and scale aren't valid, or if a value doesn't fit exactly: when it has non-zero
+/// digits beyond `scale`, or more than `precision` digits in total.
+pub fn to_series<T, I>(
+    name: &str,
+    values: I,
+    precision: usize,
+    scale: usize,
+) -> Result<Series, OutOfRangeError>
+where
+    T: BaseInt,
+    I: IntoIterator<Item = Option<IntFloat<T>>>,
+{
+    let scale_pow = isize::try_from(scale).map_err(|_| OutOfRangeError)?;
+    let values = values
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| value.to_scaled_i128(scale_pow).ok_or(OutOfRangeError))
+                .transpose()
+        })
+        .collect::<Result<Vec<Option<i128>>, OutOfRangeError>>()?;
+    Int128Chunked::from_iter_options(name.into(), values.into_iter())
+        .into_decimal(precision, scale)
+        .map(IntoSeries::into_series)
+        .map_err(|_| OutOfRangeError)
+}
+
+/// The values of a Series with the Decimal dtype, keeping its scale as the number of decimals.
+/// Fails if the Series has another dtype, or if a value doesn't fit in the base.
+pub fn from_series<T: BaseInt>(
+    series: &Series,
+) -> Result<Vec<Option<IntFloat<T>>>, OutOfRangeError> {
+    let decimals = series.decimal().map_err(|_| OutOfRangeError)?;
+    let scale = isize::try_from(decimals.scale()).map_err(|_| OutOfRangeError)?;
+    decimals
+        .physical()
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    let base = num_traits::cast(value).ok_or(OutOfRangeError)?;
+                    Ok(IntFloat::new(base, scale))
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// The value of a Decimal AnyValue, or None for a null. Fails for other AnyValues, or if the
+/// value doesn't fit in the base.
+pub fn from_any_value<T: BaseInt>(
+    value: &AnyValue<'_>,
+) -> Result<Option<IntFloat<T>>, OutOfRangeError> {
+    match *value {
+        AnyValue::Null => Ok(None),
+        AnyValue::Decimal(value, _, scale) => {
+            let base = num_traits::cast(value).ok_or(OutOfRangeError)?;
+            let scale = isize::try_from(scale).map_err(|_| OutOfRangeError)?;
+            Ok(Some(IntFloat::new(base, scale)))
+        }
+        _ => Err(OutOfRangeError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    #[test]
+    fn test_round_trip() {
+        let values = vec![
+            Some(IntFloat32::new(125, 2)),
+            None,
+            Some(IntFloat32::new(-3, 0)),
+            Some(IntFloat32::new(1, 3)),
+        ];
+        let series = to_series("amount", values.clone(), 5, 3).unwrap();
+        assert_eq!(series.name().as_str(), "amount");
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.get(0).unwrap(), AnyValue::Decimal(1250, 5, 3));
+        let back: Vec<Option<IntFloat32>> = from_series(&series).unwrap();
+        assert_eq!(back, values);
+        assert_eq!(back[2].unwrap().to_string(), "-3.000");
+        let wide = vec![Some(IntFloat128::new(10_i128.pow(37), 0))];
+        let series = to_series("wide", wide.clone(), 38, 0).unwrap();
+        assert_eq!(from_series(&series), Ok(wide));
+        assert_eq!(from_series::<isize>(&series), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let values = [Some(IntFloat::new(1234, 3))];
+        assert!(to_series("x", values, 10, 2).is_err());
+        let values = [Some(IntFloat::new(100, 0))];
+        assert!(to_series("x", values, 2, 0).is_err());
+        assert!(to_series("x", values, 3, 0).is_ok());
+        assert!(to_series("x", values, 39, 0).is_err());
+        let ints = Int128Chunked::from_slice("x".into(), &[1]).into_series();
+        assert_eq!(from_series::<isize>(&ints), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_from_any_value() {
+        assert_eq!(
+            from_any_value(&AnyValue::Decimal(-125, 10, 2)),
+            Ok(Some(IntFloat::new(-125, 2)))
+        );
+        assert_eq!(from_any_value::<isize>(&AnyValue::Null), Ok(None));
+        assert_eq!(
+            from_any_value::<isize>(&AnyValue::Float64(1.5)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            from_any_value::<i32>(&AnyValue::Decimal(1 << 40, 20, 0)),
+            Err(OutOfRangeError)
+        );
+    }
+}
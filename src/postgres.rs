@@ -0,0 +1,68 @@
+use crate::intfloat::IntFloat;
+use crate::pg_numeric::PgNumeric;
+use crate::BaseInt;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+/// Binds as the binary NUMERIC with the pow as the scale, so the number of decimals is kept. A
+/// negative pow is rescaled to zero decimals. Fails if that overflows the base, or if the pow is
+/// above 16383, the largest scale of a NUMERIC.
+impl<T: BaseInt> ToSql for IntFloat<T> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&PgNumeric::from_intfloat(*self)?.to_bytes());
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+
+    to_sql_checked!();
+}
+
+/// Reads a NUMERIC with its scale as the number of decimals. Fails for NaN and infinity, and if
+/// the value doesn't fit in the base.
+impl<'a, T: BaseInt> FromSql<'a> for IntFloat<T> {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(PgNumeric::from_bytes(raw)?.to_intfloat()?)
+    }
+
+    accepts!(NUMERIC);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32, OutOfRangeError};
+
+    fn to_sql(value: IntFloat) -> Result<BytesMut, Box<dyn Error + Sync + Send>> {
+        let mut out = BytesMut::new();
+        value.to_sql_checked(&Type::NUMERIC, &mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn test_to_sql() {
+        let bytes = to_sql(IntFloat::new(-1250, 2)).unwrap();
+        assert_eq!(&bytes[..], [0, 2, 0, 0, 0x40, 0, 0, 2, 0, 12, 0x13, 0x88]);
+        let error = to_sql(IntFloat::new(1, 20000)).unwrap_err();
+        assert_eq!(error.downcast_ref(), Some(&OutOfRangeError));
+        let mut out = BytesMut::new();
+        assert!(IntFloat::new(1, 0)
+            .to_sql_checked(&Type::FLOAT8, &mut out)
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_sql() {
+        let bytes = to_sql(IntFloat::new(123456789, 4)).unwrap();
+        let value: IntFloat = FromSql::from_sql(&Type::NUMERIC, &bytes).unwrap();
+        assert_eq!(value.to_string(), "12345.6789");
+        let wide = IntFloat128::new(i128::MAX, 10);
+        let mut out = BytesMut::new();
+        wide.to_sql(&Type::NUMERIC, &mut out).unwrap();
+        assert_eq!(IntFloat128::from_sql(&Type::NUMERIC, &out).unwrap(), wide);
+        assert!(IntFloat32::from_sql(&Type::NUMERIC, &out).is_err());
+        assert!(<IntFloat as FromSql>::accepts(&Type::NUMERIC));
+        assert!(!<IntFloat as FromSql>::accepts(&Type::FLOAT8));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::BaseInt;
+use num_traits::{Pow, ToPrimitive, Zero};
+
+/// Integer powers. Non-negative exponents are exact: the base is raised to the exponent, and the
+/// power multiplied by it. Negative exponents take the reciprocal of that result, rounded half
+/// away from zero to as many significant digits as the base can always hold (18 for IntFloat).
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use num_traits::Pow;
+/// let rate = IntFloat::new(105, 2);
+/// assert_eq!(rate.powi(3).to_string(), "1.157625");
+/// assert_eq!(rate.pow(2_u32), IntFloat::new(11025, 4));
+/// assert_eq!(IntFloat::new(8, 0).powi(-1).to_string(), "0.125000000000000000");
+/// assert_eq!(IntFloat::new(isize::MAX, 0).checked_pow(2), None);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        Some(IntFloat {
+            base: num_traits::checked_pow(self.base, exp as usize)?,
+            pow: self.pow.checked_mul(isize::try_from(exp).ok()?)?,
+        })
+    }
+
+    /// Like `powi`, but None on overflow, or for a negative power of zero.
+    pub fn checked_powi(self, exp: i32) -> Option<Self> {
+        let power = self.checked_pow(exp.unsigned_abs())?;
+        if exp >= 0 {
+            Some(power)
+        } else {
+            power.checked_recip()
+        }
+    }
+
+    /// Panics on overflow, or for a negative power of zero.
+    pub fn powi(self, exp: i32) -> Self {
+        if exp < 0 && self.base.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        self.checked_powi(exp)
+            .expect("attempt to raise to a power with overflow")
+    }
+
+    /// Raise to a fractional power, with the result rounded to `decimals` decimals. Integer
+    /// exponents are computed exactly like `powi` where possible. Other exponents go through
+    /// e^(exponent * ln(self)) in fixed point with 32 decimals, so about the first 30 significant
+    /// digits of the result are correct. Zero to the power zero is one.
+    ///
+    /// Panics if the result doesn't fit in the base, for a negative power of zero, or for a
+    /// fractional power of a negative value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// let two = IntFloat::new(2, 0);
+    /// assert_eq!(two.powf(IntFloat::new(5, 1), 10).to_string(), "1.4142135624");
+    /// assert_eq!(two.powf(IntFloat::new(-3, 0), 4).to_string(), "0.1250");
+    /// let growth = IntFloat::new(121, 2).powf(IntFloat::new(1, 1), 6);
+    /// assert_eq!(growth.to_string(), "1.019245");
+    /// ```
+    pub fn powf(self, exponent: Self, decimals: isize) -> Self {
+        if self.base.is_zero() && exponent.base.is_negative() {
+            panic!("attempt to divide by zero");
+        }
+        if self.base.is_negative() && !exponent.fract().is_zero() {
+            panic!("attempt to raise a negative number to a fractional power");
+        }
+        self.checked_powf(exponent, decimals)
+            .expect("attempt to raise to a power with overflow")
+    }
+
+    /// Like `powf`, but None if the result doesn't fit in the base, for a negative power of zero,
+    /// or for a fractional power of a negative value.
+    pub fn checked_powf(self, exponent: Self, decimals: isize) -> Option<Self> {
+        if exponent.base.is_zero() {
+            return IntFloat::one().with_scale(decimals);
+        }
+        if self.base.is_zero() {
+            return if exponent.base.is_negative() {
+                None
+            } else {
+                Some(IntFloat::new(self.base, decimals))
+            };
+        }
+        let is_integer = exponent.fract().is_zero();
+        if self.base.is_negative() && !is_integer {
+            return None;
+        }
+        if is_integer {
+            if let Some(exact) = self.exact_powi(exponent, decimals) {
+                return Some(exact);
+            }
+        }
+        let ln = math::ln(self.base.unsigned_abs(), self.pow)?;
+        let (mantissa, power) = math::exp(math::mul(math::to_fixed(exponent)?, ln)?)?;
+        // Only integer exponents get here for negative values, and odd ones keep the sign.
+        let integer = exponent.trunc();
+        let is_odd = integer.pow == 0 && integer.base % (T::one() + T::one()) != T::zero();
+        let mantissa = if self.base.is_negative() && is_odd {
+            -mantissa
+        } else {
+            mantissa
+        };
+        math::from_fixed(mantissa, power, decimals)
+    }
+
+    /// An integer power computed exactly and then rounded, or None if an intermediate result
+    /// doesn't fit.
+    fn exact_powi(self, exponent: Self, decimals: isize) -> Option<Self> {
+        let exponent = exponent.to_i32()?;
+        let power = self.checked_pow(exponent.unsigned_abs())?;
+        if exponent >= 0 {
+            power.with_scale(decimals)
+        } else {
+            IntFloat::one().checked_div_with_precision(power, decimals)
+        }
+    }
+
+    /// One divided by the value, rounded to as many significant digits as the base always holds.
+    fn checked_recip(self) -> Option<Self> {
+        let safe_digits = T::MAX.unsigned_abs().ilog10() as isize;
+        let digits = self.base.unsigned_abs().checked_ilog10()? as isize + 1;
+        // 1 / (base * 10^-pow) has its leading digit 10^(pow - digits) or 10^(pow - digits + 1).
+        let decimals = safe_digits
+            .checked_sub(self.pow)?
+            .checked_add(digits)?
+            .checked_sub(1)?;
+        IntFloat::one().checked_div_with_precision(self, decimals)
+    }
+}
+
+impl<T: BaseInt> Pow<u32> for IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn pow(self, rhs: u32) -> IntFloat<T> {
+        self.checked_pow(rhs)
+            .expect("attempt to raise to a power with overflow")
+    }
+}
+
+impl<T: BaseInt> Pow<i32> for IntFloat<T> {
+    type Output = IntFloat<T>;
+
+    fn pow(self, rhs: i32) -> IntFloat<T> {
+        self.powi(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat32, IntFloat64};
+
+    #[test]
+    fn test_checked_pow() {
+        let this = IntFloat64::new(-15, 1);
+        assert_eq!(this.checked_pow(0), Some(IntFloat64::new(1, 0)));
+        assert_eq!(this.checked_pow(1), Some(this));
+        assert_eq!(this.checked_pow(3), Some(IntFloat64::new(-3375, 3)));
+        assert_eq!(
+            IntFloat64::new(5, -2).checked_pow(2),
+            Some(IntFloat64::new(25, -4))
+        );
+        assert_eq!(IntFloat64::new(10, 0).checked_pow(19), None);
+        assert_eq!(IntFloat64::new(1, isize::MAX).checked_pow(2), None);
+        // The pow of the result only fits where an isize holds u32::MAX.
+        assert_eq!(
+            IntFloat64::new(1, 1)
+                .checked_pow(u32::MAX)
+                .map(|power| power.pow),
+            isize::try_from(u32::MAX).ok()
+        );
+    }
+
+    #[test]
+    fn test_powi() {
+        let this = IntFloat64::new(2, 0);
+        assert_eq!(this.powi(10), IntFloat64::new(1024, 0));
+        assert_eq!(this.powi(-1), IntFloat64::new(5, 1));
+        assert_eq!(this.powi(-2).to_string(), "0.250000000000000000");
+        assert_eq!(
+            IntFloat64::new(3, 0).powi(-1),
+            IntFloat64::new(333333333333333333, 18)
+        );
+        assert_eq!(
+            IntFloat64::new(-3, -2).powi(-1),
+            IntFloat64::new(-333333333333333333, 20)
+        );
+        assert_eq!(
+            IntFloat64::new(999, 3).powi(-1).to_string(),
+            "1.00100100100100100"
+        );
+        assert_eq!(
+            IntFloat32::new(7, 0).powi(-1),
+            IntFloat32::new(142857143, 9)
+        );
+        assert_eq!(IntFloat64::new(0, 0).checked_powi(-1), None);
+        assert_eq!(IntFloat64::new(0, 0).powi(0), IntFloat64::new(1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_powi_zero() {
+        IntFloat64::new(0, 2).powi(-2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to raise to a power with overflow")]
+    fn test_pow_overflow() {
+        let _ = IntFloat64::new(10, 0).pow(19_u32);
+    }
+
+    #[test]
+    fn test_powf() {
+        let two = IntFloat64::new(2, 0);
+        let half = IntFloat64::new(5, 1);
+        assert_eq!(two.powf(half, 16), IntFloat64::new(14142135623730950, 16));
+        assert_eq!(two.powf(-half, 16), IntFloat64::new(7071067811865475, 16));
+        assert_eq!(IntFloat64::new(9, 0).powf(half, 2), IntFloat64::new(300, 2));
+        assert_eq!(
+            IntFloat64::new(1, 3).powf(IntFloat64::new(25, 1), 3),
+            IntFloat64::new(0, 3)
+        );
+        assert_eq!(
+            two.powf(IntFloat64::new(10, 0), 1),
+            IntFloat64::new(10240, 1)
+        );
+        assert_eq!(two.powf(IntFloat64::new(-2, 0), 2), IntFloat64::new(25, 2));
+        assert_eq!(two.powf(IntFloat64::new(1, 0), -1), IntFloat64::new(0, -1));
+        assert_eq!(
+            IntFloat64::new(-2, 0).powf(IntFloat64::new(3, 0), 0),
+            IntFloat64::new(-8, 0)
+        );
+        assert_eq!(
+            IntFloat64::new(-3, 0).powf(IntFloat64::new(50, 0), -20),
+            IntFloat64::new(7179, -20),
+        );
+        assert_eq!(
+            IntFloat64::new(-3, 0).powf(IntFloat64::new(51, 0), -20),
+            IntFloat64::new(-21537, -20),
+        );
+        assert_eq!(
+            IntFloat64::new(12345, 2).powf(IntFloat64::new(1234, 3), 10),
+            IntFloat64::new(3809780989053, 10)
+        );
+        let wide = crate::IntFloat128::new(3, 0).powf(crate::IntFloat128::new(1, 1), 30);
+        assert_eq!(wide.to_string(), "1.116123174033904434442614138377");
+    }
+
+    #[test]
+    fn test_powf_special() {
+        let zero = IntFloat64::new(0, 0);
+        assert_eq!(zero.powf(zero, 2), IntFloat64::new(100, 2));
+        assert_eq!(IntFloat64::new(-5, 1).powf(zero, 0), IntFloat64::new(1, 0));
+        assert_eq!(zero.powf(IntFloat64::new(5, 1), 2), IntFloat64::new(0, 2));
+        assert_eq!(zero.checked_powf(IntFloat64::new(-5, 1), 2), None);
+        assert_eq!(
+            IntFloat64::new(-2, 0).checked_powf(IntFloat64::new(5, 1), 2),
+            None
+        );
+        assert_eq!(
+            IntFloat64::new(10, 0).checked_powf(IntFloat64::new(19, 0), 0),
+            None
+        );
+        assert_eq!(
+            IntFloat64::new(10, 0).checked_powf(IntFloat64::new(1000000, 0), 0),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to raise a negative number to a fractional power")]
+    fn test_powf_negative() {
+        IntFloat64::new(-2, 0).powf(IntFloat64::new(5, 1), 2);
+    }
+
+    #[test]
+    fn test_pow_trait() {
+        let this = IntFloat64::new(11, 1);
+        assert_eq!(this.pow(2_u32), IntFloat64::new(121, 2));
+        assert_eq!(this.pow(2_i32), IntFloat64::new(121, 2));
+        assert_eq!(IntFloat64::new(4, 0).pow(-1_i32), IntFloat64::new(25, 2));
+    }
+}
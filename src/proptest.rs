@@ -0,0 +1,226 @@
+//! Strategies for property-testing code that uses IntFloat with proptest. `any::<IntFloat>()`
+//! generates any base with a pow from -4 to as many decimals as the base allows, e.g. 18 for an
+//! i64. `IntFloatStrategy` constrains the pow and the value to ranges, which usually matches the
+//! domain better, e.g. prices with 2 decimals between 0 and 10000:
+//!
+//! ```
+//! use intfloat::proptest::IntFloatStrategy;
+//! use intfloat::IntFloat;
+//! use proptest::prelude::*;
+//!
+//! fn prices() -> IntFloatStrategy<isize> {
+//!     IntFloatStrategy::new()
+//!         .scale(2..=2)
+//!         .range(IntFloat::new(0, 0), IntFloat::new(10_000, 0))
+//! }
+//!
+//! proptest! {
+//!     fn rounding_doesnt_change_prices(price in prices()) {
+//!         prop_assert_eq!(price.round_dp(2), price);
+//!     }
+//! }
+//! # rounding_doesnt_change_prices();
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, RoundingMode};
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::sample::select;
+use ::proptest::strategy::{BoxedStrategy, NewTree, Strategy, ValueTree};
+use ::proptest::test_runner::TestRunner;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// A strategy for IntFloats with a pow in `scale` and a value between `min` and `max`, both
+/// inclusive. Every pow in the scale is equally likely, and the base is uniform among the values
+/// at that pow. Shrinking goes toward fewer decimals, preferring a pow of 0, and toward bases closer to zero.
+#[derive(Clone, Debug)]
+pub struct IntFloatStrategy<T> {
+    scale: RangeInclusive<isize>,
+    range: Option<(IntFloat<T>, IntFloat<T>)>,
+}
+
+impl<T: BaseInt> IntFloatStrategy<T> {
+    /// Any base, with a pow from -4 to as many decimals as the base allows.
+    pub fn new() -> Self {
+        IntFloatStrategy {
+            scale: -4..=T::DIGITS as isize,
+            range: None,
+        }
+    }
+
+    /// Only generate values with a pow in `scale`.
+    pub fn scale(self, scale: RangeInclusive<isize>) -> Self {
+        IntFloatStrategy { scale, ..self }
+    }
+
+    /// Only generate values from `min` to `max`, both inclusive.
+    pub fn range(self, min: IntFloat<T>, max: IntFloat<T>) -> Self {
+        IntFloatStrategy {
+            range: Some((min, max)),
+            ..self
+        }
+    }
+
+    /// The base of `value` at `pow`, rounded with `mode` and clamped to the base type.
+    fn bound(value: IntFloat<T>, pow: isize, mode: RoundingMode) -> i128 {
+        let wide = IntFloat::new(
+            value.base.to_i128().expect("every base fits in an i128"),
+            value.pow,
+        );
+        let base = if pow <= wide.pow {
+            wide.round_dp_with(pow, mode).base
+        } else {
+            pow.checked_sub(wide.pow)
+                .and_then(|diff| IntFloat::checked_rescale(wide.base, diff))
+                .unwrap_or(if wide.base < 0 { i128::MIN } else { i128::MAX })
+        };
+        base.clamp(Self::wide(T::MIN), Self::wide(T::MAX))
+    }
+
+    fn wide(base: T) -> i128 {
+        base.to_i128().expect("every base fits in an i128")
+    }
+
+    /// The bases at `pow` within the range.
+    fn bases(&self, pow: isize) -> RangeInclusive<i128> {
+        match self.range {
+            Some((min, max)) => {
+                Self::bound(min, pow, RoundingMode::Ceiling)
+                    ..=Self::bound(max, pow, RoundingMode::Floor)
+            }
+            None => Self::wide(T::MIN)..=Self::wide(T::MAX),
+        }
+    }
+
+    fn build(&self) -> BoxedStrategy<IntFloat<T>>
+    where
+        T: Debug + 'static,
+    {
+        let mut pows: Vec<(isize, RangeInclusive<i128>)> = self
+            .scale
+            .clone()
+            .map(|pow| (pow, self.bases(pow)))
+            .filter(|(_, range)| !range.is_empty())
+            .collect();
+        // select shrinks toward the first pow, so put the simplest first: 0, 1, 2, ..., -1, -2.
+        pows.sort_by_key(|&(pow, _)| (pow < 0, pow.unsigned_abs()));
+        assert!(
+            !pows.is_empty(),
+            "no IntFloats in {:?} with a pow in {:?}",
+            self.range,
+            self.scale
+        );
+        select(pows)
+            .prop_flat_map(|(pow, range)| {
+                range.prop_map(move |base| {
+                    IntFloat::new(
+                        num_traits::cast(base).expect("the bounds fit in the base"),
+                        pow,
+                    )
+                })
+            })
+            .boxed()
+    }
+}
+
+impl<T: BaseInt> Default for IntFloatStrategy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: BaseInt + 'static> Strategy for IntFloatStrategy<T> {
+    type Tree = Box<dyn ValueTree<Value = IntFloat<T>>>;
+    type Value = IntFloat<T>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.build().new_tree(runner)
+    }
+}
+
+impl<T: BaseInt + 'static> Arbitrary for IntFloat<T> {
+    type Parameters = ();
+    type Strategy = IntFloatStrategy<T>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        IntFloatStrategy::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generic, IntFloat, IntFloat32};
+    use ::proptest::prelude::*;
+    use ::proptest::test_runner::TestCaseError;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary(value in any::<IntFloat32>()) {
+            prop_assert!((-4..=9).contains(&value.pow));
+        }
+
+        #[test]
+        fn test_range(
+            value in IntFloatStrategy::new()
+                .scale(0..=3)
+                .range(IntFloat::new(-15, 1), IntFloat::new(2, 0))
+        ) {
+            prop_assert!((0..=3).contains(&value.pow));
+            prop_assert!(IntFloat::new(-15, 1) <= value && value <= IntFloat::new(2, 0));
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        // Only 0.5 and 0.6 lie in the range with 1 decimal, and nothing without decimals.
+        let strategy = IntFloatStrategy::new()
+            .scale(0..=1)
+            .range(IntFloat::new(45, 2), IntFloat::new(61, 2));
+        let mut runner = TestRunner::deterministic();
+        let mut seen = Vec::new();
+        for _ in 0..50 {
+            let value = strategy.new_tree(&mut runner).unwrap().current();
+            assert_eq!(value.pow, 1);
+            seen.push(value.base);
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen, [5, 6]);
+
+        let strategy = IntFloatStrategy::<i8>::new().scale(-1..=0);
+        for _ in 0..50 {
+            let value: generic::IntFloat<i8> = strategy.new_tree(&mut runner).unwrap().current();
+            assert!((-1..=0).contains(&value.pow));
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let strategy = IntFloatStrategy::new().range(IntFloat::new(1, 0), IntFloat::new(1000, 0));
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&strategy, |value| {
+            if value >= IntFloat::new(3, 0) {
+                Err(TestCaseError::fail("too large"))
+            } else {
+                Ok(())
+            }
+        });
+        match result {
+            Err(::proptest::test_runner::TestError::Fail(_, value)) => {
+                assert_eq!((value.base, value.pow), (3, 0))
+            }
+            result => panic!("expected a failure, got {:?}", result),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no IntFloats")]
+    fn test_empty() {
+        let strategy = IntFloatStrategy::new()
+            .scale(0..=1)
+            .range(IntFloat::new(1, 2), IntFloat::new(2, 2));
+        let _ = strategy.new_tree(&mut TestRunner::deterministic());
+    }
+}
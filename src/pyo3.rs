@@ -0,0 +1,208 @@
+//! Conversions between IntFloat and Python numbers, for exposing Rust code to Python with PyO3.
+//! IntFloat converts to and from `decimal.Decimal` exactly, using the decimal string, and an
+//! `int` converts exactly to an IntFloat without decimals. A `float` isn't exact, so by default
+//! extracting an IntFloat from one fails: `extract_with` takes a FloatPolicy that allows it.
+//!
+//! ```
+//! use intfloat::pyo3::{extract_with, FloatPolicy};
+//! use intfloat::IntFloat;
+//! use pyo3::prelude::*;
+//!
+//! Python::initialize();
+//! Python::attach(|py| {
+//!     let price = IntFloat::new(1250, 2).into_pyobject(py).unwrap();
+//!     assert_eq!(price.repr().unwrap().to_string(), "Decimal('12.50')");
+//!     assert_eq!(price.extract::<IntFloat>().unwrap(), IntFloat::new(1250, 2));
+//!
+//!     let float = 0.1_f64.into_pyobject(py).unwrap();
+//!     assert!(float.extract::<IntFloat>().is_err());
+//!     let value: IntFloat = extract_with(&float, FloatPolicy::Shortest).unwrap();
+//!     assert_eq!(value, IntFloat::new(1, 1));
+//! });
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, TryFromFloatError};
+use ::pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use ::pyo3::sync::PyOnceLock;
+use ::pyo3::types::{PyAnyMethods, PyFloat, PyInt, PyStringMethods, PyType};
+use ::pyo3::{Borrowed, Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, Python};
+
+/// How `extract_with` converts a Python `float`, which usually isn't exactly the decimal number
+/// it was written as.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FloatPolicy {
+    /// Fail with a TypeError, like extracting with `FromPyObject`.
+    Reject,
+    /// Use the shortest decimal string that round-trips to the float, as Python's `repr` does, so
+    /// 0.1 becomes IntFloat::new(1, 1).
+    Shortest,
+    /// Round to this number of decimals, like `IntFloat::try_from_f64`.
+    Decimals(isize),
+}
+
+static DECIMAL: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+fn decimal_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    DECIMAL.import(py, "decimal", "Decimal")
+}
+
+fn float_error(error: TryFromFloatError) -> PyErr {
+    match error {
+        TryFromFloatError::Overflow => PyOverflowError::new_err(error.to_string()),
+        _ => PyValueError::new_err(error.to_string()),
+    }
+}
+
+/// Extract an IntFloat from a `decimal.Decimal` or an `int` exactly, or from a `float` as
+/// `policy` says. Fails with a ValueError for a NaN or infinite Decimal, with an OverflowError if
+/// the value doesn't fit in the base, and with a TypeError for other objects.
+pub fn extract_with<T: BaseInt>(
+    object: &Bound<'_, PyAny>,
+    policy: FloatPolicy,
+) -> PyResult<IntFloat<T>> {
+    if object.is_instance_of::<PyFloat>() {
+        let float: f64 = object.extract()?;
+        return match policy {
+            FloatPolicy::Reject => Err(PyTypeError::new_err(
+                "a float is not an exact decimal number, use decimal.Decimal instead",
+            )),
+            FloatPolicy::Shortest => IntFloat::try_from(float).map_err(float_error),
+            FloatPolicy::Decimals(decimals) => {
+                IntFloat::try_from_f64(float, decimals).map_err(float_error)
+            }
+        };
+    }
+    if object.is_instance_of::<PyInt>() {
+        let int: i128 = object.extract()?;
+        let base = num_traits::cast(int).ok_or_else(|| {
+            PyOverflowError::new_err("int is too large for the base of the IntFloat")
+        })?;
+        return Ok(IntFloat::new(base, 0));
+    }
+    if object.is_instance(decimal_type(object.py())?)? {
+        let str = object.str()?;
+        let str = str.to_cow()?;
+        if !str.starts_with(|char: char| char.is_ascii_digit() || char == '-') {
+            return Err(PyValueError::new_err(format!(
+                "cannot convert {} to IntFloat",
+                str
+            )));
+        }
+        return str.parse().map_err(|_| {
+            PyOverflowError::new_err("Decimal is too large for the base of the IntFloat")
+        });
+    }
+    Err(PyTypeError::new_err(
+        "expected a decimal.Decimal or an int for IntFloat",
+    ))
+}
+
+/// Extracts a `decimal.Decimal` or an `int` exactly. A `float` is rejected: use `extract_with`
+/// to allow it.
+impl<T: BaseInt> FromPyObject<'_, '_> for IntFloat<T> {
+    type Error = PyErr;
+
+    fn extract(object: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        extract_with(&object, FloatPolicy::Reject)
+    }
+}
+
+/// Converts to a `decimal.Decimal` with the same digits, e.g. `Decimal('12.50')`.
+impl<'py, T: BaseInt> IntoPyObject<'py> for IntFloat<T> {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        decimal_type(py)?.call1((self.to_string(),))
+    }
+}
+
+impl<'py, T: BaseInt> IntoPyObject<'py> for &IntFloat<T> {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (*self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+
+    fn decimal<'py>(py: Python<'py>, str: &str) -> Bound<'py, PyAny> {
+        decimal_type(py).unwrap().call1((str,)).unwrap()
+    }
+
+    #[test]
+    fn test_decimal() {
+        Python::initialize();
+        Python::attach(|py| {
+            for str in ["12.50", "-0.007", "1.05E+3", "0E-8", "123456789012345678"] {
+                let value: IntFloat = decimal(py, str).extract().unwrap();
+                let back = value.into_pyobject(py).unwrap();
+                assert!(back.eq(decimal(py, str)).unwrap());
+            }
+            let value: IntFloat = decimal(py, "12.50").extract().unwrap();
+            assert_eq!(value.to_string(), "12.50");
+            let wide = IntFloat128::new(i128::MIN, 20);
+            assert_eq!(
+                wide.into_pyobject(py)
+                    .unwrap()
+                    .extract::<IntFloat128>()
+                    .unwrap(),
+                wide
+            );
+            assert!(decimal(py, "NaN")
+                .extract::<IntFloat>()
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            assert!(decimal(py, "-Infinity").extract::<IntFloat>().is_err());
+            let error = decimal(py, "12345678901.5")
+                .extract::<IntFloat32>()
+                .unwrap_err();
+            assert!(error.is_instance_of::<PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn test_int_and_float() {
+        Python::initialize();
+        Python::attach(|py| {
+            let int = 42_i64.into_pyobject(py).unwrap();
+            assert_eq!(int.extract::<IntFloat>().unwrap(), IntFloat::new(42, 0));
+            let int = (1_i64 << 40).into_pyobject(py).unwrap();
+            assert!(int
+                .extract::<IntFloat32>()
+                .unwrap_err()
+                .is_instance_of::<PyOverflowError>(py));
+
+            let float = 2.675_f64.into_pyobject(py).unwrap();
+            let error = float.extract::<IntFloat>().unwrap_err();
+            assert!(error.is_instance_of::<PyTypeError>(py));
+            assert!(extract_with::<isize>(&float, FloatPolicy::Reject).is_err());
+            assert_eq!(
+                extract_with(&float, FloatPolicy::Shortest)
+                    .map(|value: IntFloat| value.to_string())
+                    .unwrap(),
+                "2.675"
+            );
+            let rounded: IntFloat = extract_with(&float, FloatPolicy::Decimals(1)).unwrap();
+            assert_eq!(rounded.to_string(), "2.7");
+            let nan = f64::NAN.into_pyobject(py).unwrap();
+            assert!(extract_with::<isize>(&nan, FloatPolicy::Shortest)
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+
+            let text = "12.5".into_pyobject(py).unwrap();
+            assert!(text
+                .extract::<IntFloat>()
+                .unwrap_err()
+                .is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
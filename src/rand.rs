@@ -0,0 +1,212 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use ::rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformInt, UniformSampler};
+use ::rand::distr::{Distribution, StandardUniform};
+use ::rand::Rng;
+use std::marker::PhantomData;
+
+/// The uniform distribution over the IntFloats between two bounds, used by `random_range` and
+/// `Uniform::new`. The samples have the larger pow of both bounds, so every value at that scale
+/// between the bounds is equally likely: `1.0..2.50` gives 1.00, 1.01, ..., 2.49. Creating it
+/// fails with `Error::NonFinite` if the bounds can't be brought to that pow in an i128.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// use rand::rngs::StdRng;
+/// use rand::{RngExt, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let price = rng.random_range(IntFloat::from(1.0, 2)..IntFloat::from(2.0, 2));
+/// assert!(IntFloat::new(1, 0) <= price && price < IntFloat::new(2, 0));
+/// assert_eq!(price.with_scale(2), Some(price));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformIntFloat<T> {
+    base: UniformInt<i128>,
+    pow: isize,
+    marker: PhantomData<T>,
+}
+
+impl<T: BaseInt> UniformIntFloat<T> {
+    /// Both bases at the larger pow of both bounds, as i128 so the difference always fits.
+    /// Fails with `NonFinite` if that doesn't fit either.
+    fn align(low: IntFloat<T>, high: IntFloat<T>) -> Result<(i128, i128, isize), Error> {
+        let pow = low.pow.max(high.pow);
+        let scaled = |value: IntFloat<T>| {
+            IntFloat::new(value.base.to_i128()?, value.pow)
+                .with_scale(pow)
+                .map(|value| value.base)
+        };
+        match (scaled(low), scaled(high)) {
+            (Some(low), Some(high)) => Ok((low, high, pow)),
+            _ => Err(Error::NonFinite),
+        }
+    }
+}
+
+impl<T: BaseInt> UniformSampler for UniformIntFloat<T> {
+    type X = IntFloat<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high, pow) = Self::align(*low.borrow(), *high.borrow())?;
+        Ok(UniformIntFloat {
+            base: UniformInt::new(low, high)?,
+            pow,
+            marker: PhantomData,
+        })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high, pow) = Self::align(*low.borrow(), *high.borrow())?;
+        Ok(UniformIntFloat {
+            base: UniformInt::new_inclusive(low, high)?,
+            pow,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let base = num_traits::cast(self.base.sample(rng)).expect("the bounds fit in the base");
+        IntFloat::new(base, self.pow)
+    }
+}
+
+impl<T: BaseInt> SampleUniform for IntFloat<T> {
+    type Sampler = UniformIntFloat<T>;
+}
+
+/// The uniform distribution over [0, 1) with a fixed number of decimals: with 2 decimals, 0.00,
+/// 0.01, ..., 0.99 are equally likely. The default has as many decimals as the base allows, e.g.
+/// 18 for an i64, which is also what `StandardUniform` (and so `rng.random()`) uses.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntFloat64, UnitIntFloat};
+/// use rand::rngs::StdRng;
+/// use rand::{RngExt, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let fraction: IntFloat = rng.sample(UnitIntFloat::new(2).unwrap());
+/// assert!(IntFloat::new(0, 0) <= fraction && fraction < IntFloat::new(1, 0));
+/// assert_eq!(fraction.with_scale(2), Some(fraction));
+/// let fraction: IntFloat64 = rng.random();
+/// assert_eq!(fraction.with_scale(18), Some(fraction));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitIntFloat<T> {
+    uniform: UniformIntFloat<T>,
+}
+
+impl<T: BaseInt> UnitIntFloat<T> {
+    /// The distribution with `decimals` decimals, or `Error::NonFinite` if 10^`decimals` doesn't
+    /// fit in the base.
+    pub fn new(decimals: isize) -> Result<Self, Error> {
+        let one = IntFloat::new(T::ONE, 0);
+        one.with_scale(decimals).ok_or(Error::NonFinite)?;
+        Ok(UnitIntFloat {
+            uniform: UniformIntFloat::new(IntFloat::new(T::ZERO, decimals), one)?,
+        })
+    }
+}
+
+impl<T: BaseInt> Default for UnitIntFloat<T> {
+    fn default() -> Self {
+        UnitIntFloat::new(T::DIGITS as isize).expect("10^DIGITS fits in the base")
+    }
+}
+
+impl<T: BaseInt> Distribution<IntFloat<T>> for UnitIntFloat<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IntFloat<T> {
+        self.uniform.sample(rng)
+    }
+}
+
+/// Uniform over [0, 1) with as many decimals as the base allows, like `UnitIntFloat::default()`.
+impl<T: BaseInt> Distribution<IntFloat<T>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IntFloat<T> {
+        UnitIntFloat::default().sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitIntFloat;
+    use crate::{generic, IntFloat, IntFloat128, IntFloat32};
+    use ::rand::distr::uniform::Error;
+    use ::rand::distr::{Distribution, Uniform};
+    use ::rand::rngs::StdRng;
+    use ::rand::{RngExt, SeedableRng};
+
+    #[test]
+    fn test_uniform() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let uniform = Uniform::new(IntFloat::new(1, 0), IntFloat::new(125, 2)).unwrap();
+        let mut counts = [0; 25];
+        for _ in 0..25_000 {
+            let value = uniform.sample(&mut rng);
+            assert_eq!(value.pow, 2);
+            counts[(value.base - 100) as usize] += 1;
+        }
+        // Each of the 25 values is expected 1000 times.
+        assert!(counts.iter().all(|&count| (850..1150).contains(&count)));
+
+        let uniform = Uniform::new_inclusive(IntFloat::new(-1, 1), IntFloat::new(1, 1)).unwrap();
+        let mut seen = [false; 3];
+        for _ in 0..100 {
+            seen[(uniform.sample(&mut rng).base + 1) as usize] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn test_random_range() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..100 {
+            let value =
+                rng.random_range(IntFloat32::new(i32::MIN, 0)..IntFloat32::new(i32::MAX, 0));
+            assert_eq!(value.pow, 0);
+        }
+        let value = rng.random_range(IntFloat::new(5, 1)..=IntFloat::new(50, 2));
+        assert_eq!(value, IntFloat::new(50, 2));
+        assert_eq!(
+            Uniform::new(IntFloat::new(1, 0), IntFloat::new(100, 2)).unwrap_err(),
+            Error::EmptyRange
+        );
+        assert_eq!(
+            Uniform::new(IntFloat::new(1, -100), IntFloat::new(1, 100)).unwrap_err(),
+            Error::NonFinite
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let unit = UnitIntFloat::new(1).unwrap();
+        let mut counts = [0; 10];
+        for _ in 0..10_000 {
+            let value: IntFloat = unit.sample(&mut rng);
+            assert_eq!(value.pow, 1);
+            counts[value.base as usize] += 1;
+        }
+        assert!(counts.iter().all(|&count| (850..1150).contains(&count)));
+        assert_eq!(UnitIntFloat::<i8>::new(3).unwrap_err(), Error::NonFinite);
+
+        let value: generic::IntFloat<i8> = rng.random();
+        assert_eq!(value.pow, 2);
+        assert!((0..100).contains(&value.base));
+        let value: IntFloat32 = rng.random();
+        assert_eq!(value.pow, 9);
+        let value: IntFloat128 = rng.random();
+        assert_eq!(value.pow, 38);
+        assert!((0..10_i128.pow(38)).contains(&value.base));
+    }
+}
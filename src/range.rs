@@ -0,0 +1,167 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use std::iter::FusedIterator;
+
+/// Ranges with an exact decimal step, e.g. to build price grids without the drift of adding
+/// floats. All values have the largest pow of `start`, `end` and `step`. A negative step counts
+/// down. Both panic if `step` is zero, or if the pows can't be aligned without overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let step = IntFloat::new(5, 2);
+/// let grid: Vec<String> = IntFloat::range(IntFloat::zero(), IntFloat::new(2, 1), step)
+///     .map(|price| price.to_string())
+///     .collect();
+/// assert_eq!(grid, ["0.00", "0.05", "0.10", "0.15"]);
+/// let grid = IntFloat::range_inclusive(IntFloat::zero(), IntFloat::one(), step);
+/// assert_eq!(grid.size_hint(), (21, Some(21)));
+/// assert_eq!(grid.last(), Some(IntFloat::new(100, 2)));
+/// let down = IntFloat::range(IntFloat::new(1, 0), IntFloat::new(0, 0), IntFloat::new(-25, 2));
+/// assert_eq!(down.collect::<Vec<_>>().len(), 4);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// From `start` up to, but not including, `end`.
+    pub fn range(start: Self, end: Self, step: Self) -> IntFloatRange<T> {
+        IntFloatRange::new(start, end, step, false)
+    }
+
+    /// From `start` up to and including `end`.
+    pub fn range_inclusive(start: Self, end: Self, step: Self) -> IntFloatRange<T> {
+        IntFloatRange::new(start, end, step, true)
+    }
+}
+
+/// Iterator of `IntFloat::range` and `IntFloat::range_inclusive`.
+#[derive(Clone, Debug)]
+pub struct IntFloatRange<T> {
+    next: Option<T>,
+    end: T,
+    step: T,
+    pow: isize,
+    inclusive: bool,
+}
+
+impl<T: BaseInt> IntFloatRange<T> {
+    fn new(start: IntFloat<T>, end: IntFloat<T>, step: IntFloat<T>, inclusive: bool) -> Self {
+        assert!(!step.base.is_zero(), "step is zero");
+        let pow = start.pow.max(end.pow).max(step.pow);
+        let align = |value: IntFloat<T>| {
+            IntFloat::checked_rescale(value.base, pow - value.pow)
+                .expect("attempt to align with overflow")
+        };
+        IntFloatRange {
+            next: Some(align(start)),
+            end: align(end),
+            step: align(step),
+            pow,
+            inclusive,
+        }
+    }
+
+    fn contains(&self, base: T) -> bool {
+        match (self.step.is_positive(), self.inclusive) {
+            (true, false) => base < self.end,
+            (true, true) => base <= self.end,
+            (false, false) => base > self.end,
+            (false, true) => base >= self.end,
+        }
+    }
+
+    /// The number of values left, or None if it doesn't fit in a usize.
+    fn remaining(&self) -> Option<usize> {
+        let base = match self.next {
+            Some(base) if self.contains(base) => base.to_i128()?,
+            _ => return Some(0),
+        };
+        let distance = self.end.to_i128()?.checked_sub(base)?.unsigned_abs();
+        let step = self.step.to_i128()?.unsigned_abs();
+        let partial = !self.inclusive && distance % step != 0;
+        let count = distance / step + (self.inclusive || partial) as u128;
+        usize::try_from(count).ok()
+    }
+}
+
+impl<T: BaseInt> Iterator for IntFloatRange<T> {
+    type Item = IntFloat<T>;
+
+    fn next(&mut self) -> Option<IntFloat<T>> {
+        let base = self.next.filter(|&base| self.contains(base));
+        self.next = base.and_then(|base| base.checked_add(&self.step));
+        base.map(|base| IntFloat::new(base, self.pow))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(count) => (count, Some(count)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: BaseInt> FusedIterator for IntFloatRange<T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generic, IntFloat, IntFloat128};
+
+    #[test]
+    fn test_range() {
+        let values: Vec<IntFloat> = IntFloat::range(
+            IntFloat::new(1, 0),
+            IntFloat::new(2, 0),
+            IntFloat::new(3, 1),
+        )
+        .collect();
+        assert_eq!(
+            values,
+            [
+                IntFloat::new(10, 1),
+                IntFloat::new(13, 1),
+                IntFloat::new(16, 1),
+                IntFloat::new(19, 1)
+            ]
+        );
+        assert!(values.iter().all(|value| value.pow == 1));
+        let mut empty = IntFloat::range(IntFloat::one(), IntFloat::one(), IntFloat::one());
+        assert_eq!((empty.size_hint(), empty.next()), ((0, Some(0)), None));
+        let backwards = IntFloat::range(IntFloat::one(), IntFloat::zero(), IntFloat::one());
+        assert_eq!(backwards.count(), 0);
+        let down = IntFloat::range_inclusive(
+            IntFloat::new(1, 0),
+            IntFloat::new(-1, 0),
+            IntFloat::new(-5, 1),
+        );
+        assert_eq!(down.size_hint(), (5, Some(5)));
+        assert_eq!(down.last(), Some(IntFloat::new(-10, 1)));
+    }
+
+    #[test]
+    fn test_range_limits() {
+        let max = generic::IntFloat::<i8>::new(i8::MAX, 0);
+        let step = generic::IntFloat::<i8>::new(100, 0);
+        let mut range = generic::IntFloat::range_inclusive(generic::IntFloat::zero(), max, step);
+        assert_eq!(range.size_hint(), (2, Some(2)));
+        assert_eq!(range.next(), Some(generic::IntFloat::new(0, 0)));
+        assert_eq!(range.next(), Some(generic::IntFloat::new(100, 0)));
+        assert_eq!(range.size_hint(), (0, Some(0)));
+        assert_eq!(range.next(), None);
+        let all = generic::IntFloat::range_inclusive(-max, max, generic::IntFloat::one());
+        assert_eq!(all.count(), 255);
+        let wide = IntFloat128::range(IntFloat128::MIN, IntFloat128::MAX, IntFloat128::new(1, 0));
+        assert_eq!(wide.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "step is zero")]
+    fn test_range_zero_step() {
+        IntFloat::range(IntFloat::zero(), IntFloat::one(), IntFloat::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to align with overflow")]
+    fn test_range_overflow() {
+        IntFloat::range(IntFloat::new(1, -10), IntFloat::one(), IntFloat::new(1, 10));
+    }
+}
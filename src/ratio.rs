@@ -0,0 +1,477 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::{BaseInt, OutOfRangeError};
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// Exact rational companion of IntFloat, with a numerator and denominator of the same integer
+/// type as its base. Values are always kept in lowest terms with a positive denominator, so that
+/// divisions like 1 / 3 can be carried through a calculation exactly and only rounded at the end
+/// with `to_intfloat`.
+///
+/// Just like the primitive integers, the operators panic when the reduced result doesn't fit, and
+/// there are `checked_` versions that return None instead.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntRatio};
+/// let third = IntRatio::new(1, 3);
+/// let total = third + third + third;
+/// assert_eq!(total, IntRatio::new(1, 1));
+///
+/// let price = IntRatio::try_from(IntFloat::new(1000, 2)).unwrap();
+/// let share = price / IntRatio::new(3, 1);
+/// assert_eq!(share.to_string(), "10/3");
+/// assert_eq!(share.to_intfloat(2), IntFloat::new(333, 2));
+/// assert_eq!((share * IntRatio::new(3, 1)).to_intfloat(2).to_string(), "10.00");
+/// ```
+pub struct IntRatio<T> {
+    numer: T,
+    denom: T,
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<T: BaseInt> IntRatio<T> {
+    /// The ratio `numer` / `denom`, in lowest terms. Panics if `denom` is zero, or if the result
+    /// doesn't fit, which only happens for a numerator or denominator of T::MIN.
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "attempt to divide by zero");
+        Self::checked_new(numer, denom).expect("attempt to negate with overflow")
+    }
+
+    /// Like `new`, but None if `denom` is zero or the result doesn't fit.
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        Self::reduce(numer.to_i128()?, denom.to_i128()?)
+    }
+
+    /// Bring `numer` / `denom` in lowest terms with a positive denominator, if that fits in T.
+    fn reduce(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        // The divisor is at most 2^127, which wraps to i128::MIN but still divides correctly.
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+        let (mut numer, mut denom) = (numer / divisor, denom / divisor);
+        if denom < 0 {
+            (numer, denom) = (numer.checked_neg()?, denom.checked_neg()?);
+        }
+        Some(IntRatio {
+            numer: num_traits::cast(numer)?,
+            denom: num_traits::cast(denom)?,
+        })
+    }
+
+    fn parts(self) -> (i128, i128) {
+        let as_i128 = |value: T| value.to_i128().expect("every base fits in an i128");
+        (as_i128(self.numer), as_i128(self.denom))
+    }
+
+    /// The numerator, which carries the sign.
+    pub fn numer(self) -> T {
+        self.numer
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denom(self) -> T {
+        self.denom
+    }
+
+    /// The reciprocal. Panics if the value is zero or the result doesn't fit.
+    pub fn recip(self) -> Self {
+        IntRatio::new(self.denom, self.numer)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let ((numer, denom), (rhs_numer, rhs_denom)) = (self.parts(), rhs.parts());
+        let divisor = gcd(denom as u128, rhs_denom as u128) as i128;
+        let numer = numer
+            .checked_mul(rhs_denom / divisor)?
+            .checked_add(rhs_numer.checked_mul(denom / divisor)?)?;
+        Self::reduce(numer, denom.checked_mul(rhs_denom / divisor)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (rhs_numer, rhs_denom) = rhs.parts();
+        self.checked_add(Self::reduce(rhs_numer.checked_neg()?, rhs_denom)?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let ((numer, denom), (rhs_numer, rhs_denom)) = (self.parts(), rhs.parts());
+        // Cancel crosswise first, so that the products stay as small as possible.
+        let left = gcd(numer.unsigned_abs(), rhs_denom as u128) as i128;
+        let right = gcd(rhs_numer.unsigned_abs(), denom as u128) as i128;
+        Self::reduce(
+            (numer / left).checked_mul(rhs_numer / right)?,
+            (denom / right).checked_mul(rhs_denom / left)?,
+        )
+    }
+
+    /// None if `rhs` is zero or the result doesn't fit.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (rhs_numer, rhs_denom) = rhs.parts();
+        self.checked_mul(Self::reduce(rhs_denom, rhs_numer)?)
+    }
+
+    /// Round to `decimals` decimals, half away from zero. Panics if the result doesn't fit.
+    pub fn to_intfloat(self, decimals: isize) -> IntFloat<T> {
+        self.checked_to_intfloat(decimals)
+            .expect("attempt to convert with overflow")
+    }
+
+    /// Like `to_intfloat`, but None if the result doesn't fit.
+    pub fn checked_to_intfloat(self, decimals: isize) -> Option<IntFloat<T>> {
+        IntFloat::new(self.numer, 0)
+            .checked_div_with_precision(IntFloat::new(self.denom, 0), decimals)
+    }
+}
+
+/// Exact conversion from IntFloat. Fails if the power of ten doesn't fit in T, even after
+/// dropping trailing zeros.
+impl<T: BaseInt> TryFrom<IntFloat<T>> for IntRatio<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: IntFloat<T>) -> Result<Self, Self::Error> {
+        let value = value.normalize();
+        let base = value.base.to_i128().ok_or(OutOfRangeError)?;
+        let factor = u32::try_from(value.pow.unsigned_abs())
+            .ok()
+            .and_then(|pow| 10_i128.checked_pow(pow))
+            .ok_or(OutOfRangeError)?;
+        let (numer, denom) = if value.pow < 0 {
+            (base.checked_mul(factor).ok_or(OutOfRangeError)?, 1)
+        } else {
+            (base, factor)
+        };
+        Self::reduce(numer, denom).ok_or(OutOfRangeError)
+    }
+}
+
+/// Exact conversion to IntFloat, with as few decimals as possible. This only succeeds if the
+/// denominator has no prime factors other than 2 and 5, and the result fits.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, IntRatio};
+/// assert_eq!(IntFloat::try_from(IntRatio::new(3, 8)), Ok(IntFloat::new(375, 3)));
+/// assert!(IntFloat::try_from(IntRatio::new(1, 3)).is_err());
+/// ```
+impl<T: BaseInt> TryFrom<IntRatio<T>> for IntFloat<T> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: IntRatio<T>) -> Result<Self, Self::Error> {
+        let (numer, denom) = value.parts();
+        let (mut rest, mut twos, mut fives) = (denom, 0_u32, 0_u32);
+        while rest % 2 == 0 {
+            (rest, twos) = (rest / 2, twos + 1);
+        }
+        while rest % 5 == 0 {
+            (rest, fives) = (rest / 5, fives + 1);
+        }
+        if rest != 1 {
+            return Err(OutOfRangeError);
+        }
+        let decimals = twos.max(fives);
+        let base = 2_i128
+            .checked_pow(decimals - twos)
+            .zip(5_i128.checked_pow(decimals - fives))
+            .and_then(|(twos, fives)| numer.checked_mul(twos)?.checked_mul(fives))
+            .and_then(num_traits::cast)
+            .ok_or(OutOfRangeError)?;
+        Ok(IntFloat::new(base, decimals as isize))
+    }
+}
+
+impl<T: BaseInt> From<T> for IntRatio<T> {
+    fn from(value: T) -> Self {
+        IntRatio {
+            numer: value,
+            denom: T::one(),
+        }
+    }
+}
+
+/// Formatted as "numerator/denominator", or just the numerator if the denominator is one.
+impl<T: BaseInt> Display for IntRatio<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T: BaseInt> PartialOrd for IntRatio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: BaseInt> Ord for IntRatio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ((numer, denom), (other_numer, other_denom)) = (self.parts(), other.parts());
+        match numer.signum().cmp(&other_numer.signum()) {
+            Ordering::Equal => {
+                // Compare the cross products, which can take up to 255 bits.
+                let lhs = math::wide_mul(numer.unsigned_abs(), other_denom as u128);
+                let rhs = math::wide_mul(other_numer.unsigned_abs(), denom as u128);
+                if numer < 0 {
+                    rhs.cmp(&lhs)
+                } else {
+                    lhs.cmp(&rhs)
+                }
+            }
+            ordering => ordering,
+        }
+    }
+}
+
+impl<T: BaseInt> Add for IntRatio<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl<T: BaseInt> Sub for IntRatio<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl<T: BaseInt> Mul for IntRatio<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl<T: BaseInt> Div for IntRatio<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.numer.is_zero(), "attempt to divide by zero");
+        self.checked_div(rhs)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+impl<T: BaseInt> Neg for IntRatio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        IntRatio {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: BaseInt> Zero for IntRatio<T> {
+    fn zero() -> Self {
+        IntRatio::from(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: BaseInt> One for IntRatio<T> {
+    fn one() -> Self {
+        IntRatio::from(T::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generic, IntFloat, IntFloat128, IntFloat32, IntFloat64, IntRatio};
+
+    #[test]
+    fn test_new() {
+        let this = IntRatio::new(6, -4);
+        assert_eq!((this.numer(), this.denom()), (-3, 2));
+        assert_eq!(IntRatio::new(0, -5), IntRatio::zero());
+        assert_eq!(IntRatio::new(isize::MIN, isize::MIN), IntRatio::one());
+        assert_eq!(IntRatio::checked_new(1, 0), None);
+        assert_eq!(IntRatio::checked_new(isize::MIN, -1), None);
+        assert_eq!(
+            IntRatio::checked_new(isize::MIN, 2),
+            Some(IntRatio::new(isize::MIN / 2, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_new_zero_denominator() {
+        IntRatio::new(1, 0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (third, half) = (IntRatio::new(1, 3), IntRatio::new(1, 2));
+        assert_eq!(third + half, IntRatio::new(5, 6));
+        assert_eq!(third - half, IntRatio::new(-1, 6));
+        assert_eq!(third * half, IntRatio::new(1, 6));
+        assert_eq!(third / half, IntRatio::new(2, 3));
+        assert_eq!(-third, IntRatio::new(-1, 3));
+        assert_eq!(third.recip(), IntRatio::from(3));
+        assert_eq!(
+            IntRatio::new(4, 6) + IntRatio::new(1, 6),
+            IntRatio::new(5, 6)
+        );
+
+        // Cancelling crosswise keeps the products within range.
+        let big = IntRatio::new(isize::MAX, 3);
+        assert_eq!(big * IntRatio::new(3, isize::MAX), IntRatio::one());
+        assert_eq!(big.checked_add(big), None);
+        assert_eq!(
+            IntRatio::from(isize::MAX).checked_add(IntRatio::one()),
+            None
+        );
+        assert_eq!(third.checked_div(IntRatio::zero()), None);
+
+        let small = generic::IntRatio::new(1, i128::MAX);
+        assert_eq!(small.checked_mul(small), None);
+        assert!((small - small).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_overflow() {
+        let _ = IntRatio::from(isize::MAX) + IntRatio::one();
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut values = vec![
+            IntRatio::new(1, 3),
+            IntRatio::new(-1, 2),
+            IntRatio::zero(),
+            IntRatio::new(-1, 3),
+            IntRatio::new(1, 2),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                IntRatio::new(-1, 2),
+                IntRatio::new(-1, 3),
+                IntRatio::zero(),
+                IntRatio::new(1, 3),
+                IntRatio::new(1, 2),
+            ]
+        );
+        let near = generic::IntRatio::new(i128::MAX - 1, i128::MAX);
+        let nearer = generic::IntRatio::new(i128::MAX - 2, i128::MAX - 1);
+        assert!(near < nearer.recip());
+        assert!(nearer < near);
+    }
+
+    #[test]
+    fn test_from_intfloat() {
+        assert_eq!(
+            IntRatio::try_from(IntFloat::new(125, 2)),
+            Ok(IntRatio::new(5, 4))
+        );
+        assert_eq!(
+            IntRatio::try_from(IntFloat::new(-3, -2)),
+            Ok(IntRatio::from(-300))
+        );
+        assert_eq!(
+            IntRatio::try_from(IntFloat::new(0, 50)),
+            Ok(IntRatio::zero())
+        );
+        assert_eq!(
+            generic::IntRatio::try_from(IntFloat64::new(100, 20)),
+            Ok(generic::IntRatio::new(1, 10_i64.pow(18)))
+        );
+        assert_eq!(
+            IntRatio::try_from(IntFloat::new(1, 19)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            IntRatio::try_from(IntFloat::new(1, -19)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            generic::IntRatio::try_from(IntFloat32::new(1, 9)),
+            Ok(generic::IntRatio::new(1, 1_000_000_000))
+        );
+    }
+
+    #[test]
+    fn test_to_intfloat() {
+        let third = IntRatio::new(-1, 3);
+        assert_eq!(third.to_intfloat(4), IntFloat::new(-3333, 4));
+        assert_eq!(IntRatio::new(2, 3).to_intfloat(0), IntFloat::new(1, 0));
+        assert_eq!(IntRatio::new(1, 8).to_intfloat(2), IntFloat::new(13, 2));
+        assert_eq!(third.checked_to_intfloat(20), None);
+        assert_eq!(
+            generic::IntRatio::new(1_i128, 7).to_intfloat(30),
+            IntFloat128::new(142857142857142857142857142857, 30)
+        );
+
+        assert_eq!(
+            IntFloat::try_from(IntRatio::new(1, 8)),
+            Ok(IntFloat::new(125, 3))
+        );
+        assert_eq!(
+            IntFloat::try_from(IntRatio::new(-7, 20)),
+            Ok(IntFloat::new(-35, 2))
+        );
+        assert_eq!(
+            IntFloat::try_from(IntRatio::from(12)),
+            Ok(IntFloat::new(12, 0))
+        );
+        assert_eq!(
+            IntFloat::try_from(IntRatio::new(1, 6)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            IntFloat128::try_from(generic::IntRatio::new(1, 1 << 40)),
+            Ok(IntFloat128::new(5_i128.pow(40), 40))
+        );
+        assert_eq!(
+            IntFloat::try_from(IntRatio::new(isize::MAX, 2)),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            IntFloat::new(1234, 2),
+            IntFloat::new(-5, 7),
+            IntFloat::new(0, 0),
+        ] {
+            let ratio = IntRatio::try_from(value).unwrap();
+            assert_eq!(IntFloat::try_from(ratio), Ok(value.normalize()));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(IntRatio::new(-10, 4).to_string(), "-5/2");
+        assert_eq!(IntRatio::new(6, 3).to_string(), "2");
+    }
+}
@@ -0,0 +1,300 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::BaseInt;
+
+/// The integer square root of `n`, by Newton's method.
+pub(crate) fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method decreases monotonically to the root from any starting point above it.
+    let mut x = 1 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// The integer n-th root of `radicand`, by Newton's method.
+fn iroot(radicand: u128, n: u32) -> u128 {
+    if radicand < 2 || n == 1 {
+        return radicand;
+    }
+    let n = n as u128;
+    let mut x: u128 = 1 << (radicand.ilog2() as u128 / n + 1);
+    loop {
+        let quotient = u32::try_from(n - 1)
+            .ok()
+            .and_then(|exp| x.checked_pow(exp))
+            .map_or(0, |power| radicand / power);
+        // Saturating only matters when the root is one, and still ends the iteration there.
+        let next = ((n - 1) * x).saturating_add(quotient) / n;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// The magnitude of the value times 2^n, scaled to n times `decimals` decimals and truncated:
+    /// the integer of which the truncated n-th root is twice the root with `decimals` decimals.
+    /// None if it doesn't fit in a u128.
+    fn scaled_radicand(self, n: u32, decimals: isize) -> Option<u128> {
+        let magnitude = self
+            .base
+            .unsigned_abs()
+            .checked_mul(1_u128.checked_shl(n)?)?;
+        let shift = decimals.checked_mul(n as isize)?.checked_sub(self.pow)?;
+        let factor = u32::try_from(shift.unsigned_abs())
+            .ok()
+            .and_then(|shift| 10_u128.checked_pow(shift));
+        if shift >= 0 {
+            magnitude.checked_mul(factor?)
+        } else {
+            Some(factor.map_or(0, |factor| magnitude / factor))
+        }
+    }
+
+    /// Half of a doubled, truncated root, rounded half up, with the sign and `decimals` decimals.
+    fn from_doubled_root(doubled: u128, is_negative: bool, decimals: isize) -> Option<Self> {
+        let magnitude = i128::try_from(doubled.div_ceil(2)).ok()?;
+        let base = num_traits::cast(if is_negative { -magnitude } else { magnitude })?;
+        Some(IntFloat::new(base, decimals))
+    }
+
+    /// The square root, correctly rounded half away from zero to `decimals` decimals. It is
+    /// computed with integer arithmetic in 128 bits, which limits the result to bases of about
+    /// 9.2e18 (19 digits). Panics if the value is negative, or the result is out of that range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(2, 0).sqrt(6).to_string(), "1.414214");
+    /// assert_eq!(IntFloat::new(225, 2).sqrt(1).to_string(), "1.5");
+    /// assert_eq!(IntFloat::new(-4, 0).checked_sqrt(2), None);
+    /// ```
+    pub fn sqrt(self, decimals: isize) -> Self {
+        if self.base.is_negative() {
+            panic!("attempt to take the square root of a negative number");
+        }
+        self.checked_sqrt(decimals)
+            .expect("attempt to take the square root with overflow")
+    }
+
+    /// Like `sqrt`, but None if the value is negative or the result is out of range.
+    pub fn checked_sqrt(self, decimals: isize) -> Option<Self> {
+        if self.base.is_negative() {
+            return None;
+        }
+        if self.base.is_zero() {
+            return Some(IntFloat::new(self.base, decimals));
+        }
+        let doubled = isqrt(self.scaled_radicand(2, decimals)?);
+        Self::from_doubled_root(doubled, false, decimals)
+    }
+}
+
+/// Cube and n-th roots, rounded half away from zero to `decimals` decimals. Odd roots of negative
+/// values are negative. The result is computed with integer arithmetic in 128 bits and correctly
+/// rounded if `2^n * |root|^n` fits in a u128, which for cube roots means bases up to about 3.4e12.
+/// Beyond that, the root is computed as e^(ln(self) / n) in fixed point with 32 decimals, so about
+/// the first 30 significant digits are correct.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// assert_eq!(IntFloat::new(-27, 0).cbrt(2).to_string(), "-3.00");
+/// assert_eq!(IntFloat::new(2, 0).cbrt(15).to_string(), "1.259921049894873");
+/// let product = IntFloat::new(12, 1) * IntFloat::new(15, 1) * IntFloat::new(21, 1);
+/// assert_eq!(product.nth_root(3, 8).to_string(), "1.55774823");
+/// assert_eq!(IntFloat::new(-16, 0).checked_nth_root(4, 2), None);
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    /// Panics if the result doesn't fit in the base.
+    pub fn cbrt(self, decimals: isize) -> Self {
+        self.nth_root(3, decimals)
+    }
+
+    /// Panics if `n` is zero, if `n` is even and the value negative, or if the result doesn't
+    /// fit in the base.
+    pub fn nth_root(self, n: u32, decimals: isize) -> Self {
+        if n == 0 {
+            panic!("attempt to take the zeroth root");
+        }
+        if n.is_multiple_of(2) && self.base.is_negative() {
+            panic!("attempt to take an even root of a negative number");
+        }
+        self.checked_nth_root(n, decimals)
+            .expect("attempt to take a root with overflow")
+    }
+
+    /// Like `cbrt`, but None if the result doesn't fit in the base.
+    pub fn checked_cbrt(self, decimals: isize) -> Option<Self> {
+        self.checked_nth_root(3, decimals)
+    }
+
+    /// Like `nth_root`, but None if `n` is zero, if `n` is even and the value negative, or if the
+    /// result doesn't fit in the base.
+    pub fn checked_nth_root(self, n: u32, decimals: isize) -> Option<Self> {
+        let is_negative = self.base.is_negative();
+        if n == 0 || (n.is_multiple_of(2) && is_negative) {
+            return None;
+        }
+        if self.base.is_zero() {
+            return Some(IntFloat::new(self.base, decimals));
+        }
+        if let Some(radicand) = self.scaled_radicand(n, decimals) {
+            return Self::from_doubled_root(iroot(radicand, n), is_negative, decimals);
+        }
+        let ln = math::ln(self.base.unsigned_abs(), self.pow)?;
+        let (mantissa, power) = math::exp(math::mul_div(ln, 1, n as i128)?)?;
+        math::from_fixed(
+            if is_negative { -mantissa } else { mantissa },
+            power,
+            decimals,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat128, IntFloat32, IntFloat64};
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0..1000_u128 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n);
+        }
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(1 << 126), 1 << 63);
+        assert_eq!(isqrt((1 << 126) - 1), (1 << 63) - 1);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(IntFloat64::new(4, 0).sqrt(0), IntFloat64::new(2, 0));
+        assert_eq!(IntFloat64::new(4, 0).sqrt(3), IntFloat64::new(2000, 3));
+        assert_eq!(
+            IntFloat64::new(2, 0).sqrt(17),
+            IntFloat64::new(141421356237309505, 17)
+        );
+        assert_eq!(IntFloat64::new(2, 0).sqrt(-1), IntFloat64::new(0, -1));
+        assert_eq!(IntFloat64::new(1, 4).sqrt(2), IntFloat64::new(1, 2));
+        assert_eq!(IntFloat64::new(1, 5).sqrt(4), IntFloat64::new(32, 4));
+        assert_eq!(IntFloat64::new(225, 0).sqrt(0), IntFloat64::new(15, 0));
+        assert_eq!(IntFloat64::new(1, -4).sqrt(-1), IntFloat64::new(10, -1));
+        assert_eq!(IntFloat64::new(0, 3).sqrt(5), IntFloat64::new(0, 5));
+        assert_eq!(IntFloat32::new(3, 0).sqrt(8), IntFloat32::new(173205081, 8));
+        // Ties round up: sqrt(2.25) = 1.5.
+        assert_eq!(IntFloat64::new(225, 2).sqrt(0), IntFloat64::new(2, 0));
+        assert_eq!(IntFloat64::new(624, 2).sqrt(0), IntFloat64::new(2, 0));
+        assert_eq!(IntFloat64::new(625, 2).sqrt(0), IntFloat64::new(3, 0));
+        assert_eq!(
+            IntFloat128::new((u128::MAX / 4) as i128, 0).sqrt(0),
+            IntFloat128::new(9223372036854775808, 0)
+        );
+    }
+
+    #[test]
+    fn test_checked_sqrt() {
+        assert_eq!(IntFloat64::new(-1, 0).checked_sqrt(0), None);
+        assert_eq!(IntFloat64::new(2, 0).checked_sqrt(19), None);
+        assert_eq!(IntFloat32::new(2, 0).checked_sqrt(10), None);
+        assert_eq!(IntFloat64::new(2, 0).checked_sqrt(isize::MAX), None);
+        assert_eq!(IntFloat128::new(i128::MAX, 0).checked_sqrt(0), None);
+    }
+
+    #[test]
+    fn test_iroot() {
+        for n in 1..6 {
+            for radicand in 0..2000_u128 {
+                let root = iroot(radicand, n);
+                assert!(root.pow(n) <= radicand && (root + 1).pow(n) > radicand);
+            }
+        }
+        assert_eq!(iroot(u128::MAX, 2), u64::MAX as u128);
+        assert_eq!(iroot(u128::MAX, 3), 6981463658331);
+        assert_eq!(iroot(u128::MAX, 127), 2);
+        assert_eq!(iroot(u128::MAX, 128), 1);
+        assert_eq!(iroot(1 << 120, 40), 8);
+    }
+
+    #[test]
+    fn test_nth_root() {
+        assert_eq!(IntFloat64::new(27, 0).cbrt(0), IntFloat64::new(3, 0));
+        assert_eq!(IntFloat64::new(-27, 0).cbrt(1), IntFloat64::new(-30, 1));
+        assert_eq!(IntFloat64::new(1, 3).cbrt(3), IntFloat64::new(100, 3));
+        assert_eq!(
+            IntFloat64::new(2, 0).cbrt(10),
+            IntFloat64::new(12599210499, 10)
+        );
+        assert_eq!(
+            IntFloat64::new(10, 0).nth_root(5, 6),
+            IntFloat64::new(1584893, 6)
+        );
+        assert_eq!(
+            IntFloat64::new(1 << 62, 0).nth_root(62, 2),
+            IntFloat64::new(200, 2)
+        );
+        assert_eq!(IntFloat64::new(7, 1).nth_root(1, 2), IntFloat64::new(70, 2));
+        assert_eq!(IntFloat64::new(0, 1).cbrt(2), IntFloat64::new(0, 2));
+        assert_eq!(
+            IntFloat64::new(-32, 0).nth_root(5, 0),
+            IntFloat64::new(-2, 0)
+        );
+        // Ties round away from zero: the cube root of 3.375 is 1.5.
+        assert_eq!(IntFloat64::new(3375, 3).cbrt(0), IntFloat64::new(2, 0));
+        assert_eq!(IntFloat64::new(-3375, 3).cbrt(0), IntFloat64::new(-2, 0));
+    }
+
+    #[test]
+    fn test_nth_root_fallback() {
+        assert_eq!(
+            IntFloat64::new(2, 0).cbrt(15),
+            IntFloat64::new(1259921049894873, 15)
+        );
+        assert_eq!(
+            IntFloat64::new(-2, 0).cbrt(15),
+            IntFloat64::new(-1259921049894873, 15)
+        );
+        assert_eq!(
+            IntFloat64::new(3_i64.pow(39), 0).cbrt(7),
+            IntFloat64::new(15943230000000, 7)
+        );
+        assert_eq!(IntFloat64::new(2, 0).checked_cbrt(19), None);
+    }
+
+    #[test]
+    fn test_checked_nth_root() {
+        assert_eq!(IntFloat64::new(16, 0).checked_nth_root(0, 0), None);
+        assert_eq!(IntFloat64::new(-16, 0).checked_nth_root(4, 0), None);
+        assert_eq!(
+            IntFloat64::new(16, 0).checked_nth_root(4, 0),
+            Some(IntFloat64::new(2, 0))
+        );
+        assert_eq!(
+            IntFloat64::new(16, 0).checked_nth_root(200, 0),
+            Some(IntFloat64::new(1, 0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take an even root of a negative number")]
+    fn test_nth_root_negative() {
+        IntFloat64::new(-16, 0).nth_root(4, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take the square root of a negative number")]
+    fn test_sqrt_negative() {
+        IntFloat64::new(-4, 0).sqrt(2);
+    }
+}
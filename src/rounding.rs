@@ -0,0 +1,529 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use std::cmp::Ordering;
+
+/// Strategy to round a value that lies between two representable values.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{IntFloat, RoundingMode};
+/// let a = IntFloat::new(-125, 2);
+/// assert_eq!(a.round_dp_with(1, RoundingMode::HalfUp), IntFloat::new(-13, 1));
+/// assert_eq!(a.round_dp_with(1, RoundingMode::HalfEven), IntFloat::new(-12, 1));
+/// assert_eq!(a.round_dp_with(1, RoundingMode::Ceiling), IntFloat::new(-12, 1));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum RoundingMode {
+    /// Round to the nearest value, and halfway values away from zero: 1.25 becomes 1.3 and -1.25
+    /// becomes -1.3. This is the rounding used elsewhere in this crate.
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, and halfway values toward zero: 1.25 becomes 1.2.
+    HalfDown,
+    /// Round to the nearest value, and halfway values to an even last digit: 1.25 becomes 1.2 and
+    /// 1.35 becomes 1.4. Also known as banker's rounding.
+    HalfEven,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward zero, i.e. truncate.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+}
+
+impl RoundingMode {
+    /// Divide the absolute value `digits` by `divisor` and round the quotient according to this
+    /// mode. A `divisor` of None stands for a power of ten that is too large for a u128.
+    pub(crate) fn round_magnitude(
+        self,
+        digits: u128,
+        divisor: Option<u128>,
+        is_negative: bool,
+    ) -> u128 {
+        let (quotient, remainder, half) = match divisor {
+            Some(divisor) => (
+                digits / divisor,
+                digits % divisor,
+                (digits % divisor).cmp(&(divisor - digits % divisor)),
+            ),
+            None => (0, digits, Ordering::Less),
+        };
+        let away = self.rounds_away(quotient % 2 == 1, remainder == 0, half, is_negative);
+        quotient + away as u128
+    }
+
+    /// Whether a truncated quotient moves one step away from zero. `half` compares the remainder
+    /// with the distance to the next quotient.
+    pub(crate) fn rounds_away(
+        self,
+        quotient_is_odd: bool,
+        is_exact: bool,
+        half: Ordering,
+        is_negative: bool,
+    ) -> bool {
+        match self {
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && quotient_is_odd)
+            }
+            RoundingMode::Floor => is_negative && !is_exact,
+            RoundingMode::Ceiling => !is_negative && !is_exact,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => !is_exact,
+        }
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Round to `dp` decimals with the given rounding mode. Values that already have at most `dp`
+    /// decimals are returned unchanged.
+    pub fn round_dp_with(self, dp: isize, mode: RoundingMode) -> Self {
+        if self.pow <= dp {
+            return self;
+        }
+        let divisor = u32::try_from(self.pow - dp)
+            .ok()
+            .and_then(|diff| 10_u128.checked_pow(diff));
+        let is_negative = self.base.is_negative();
+        let magnitude = mode.round_magnitude(self.base.unsigned_abs(), divisor, is_negative);
+        // The rounded magnitude is at most a tenth of the original plus one, so it always fits.
+        let magnitude: T = num_traits::cast(magnitude).expect("rounding shrinks the base");
+        IntFloat {
+            base: if is_negative { -magnitude } else { magnitude },
+            pow: dp,
+        }
+    }
+
+    /// Round to `dp` decimals, half away from zero. Values that already have at most `dp`
+    /// decimals are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(12345, 3).round_dp(2).to_string(), "12.35");
+    /// assert_eq!(IntFloat::new(5, 0).round_dp(2).to_string(), "5");
+    /// ```
+    pub fn round_dp(self, dp: isize) -> Self {
+        self.round_dp_with(dp, RoundingMode::HalfUp)
+    }
+
+    /// Change the number of decimals to exactly `scale`, adding trailing zeros or rounding half
+    /// away from zero as needed. Returns None if the base overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// assert_eq!(IntFloat::new(5, 0).with_scale(2).unwrap().to_string(), "5.00");
+    /// assert_eq!(IntFloat::new(12345, 3).with_scale(1).unwrap().to_string(), "12.3");
+    /// assert_eq!(IntFloat::new(isize::MAX, 0).with_scale(1), None);
+    /// ```
+    pub fn with_scale(self, scale: isize) -> Option<Self> {
+        if scale <= self.pow {
+            return Some(self.round_dp(scale));
+        }
+        if self.base.is_zero() {
+            return Some(IntFloat::new(self.base, scale));
+        }
+        Some(IntFloat {
+            base: Self::checked_rescale(self.base, scale.checked_sub(self.pow)?)?,
+            pow: scale,
+        })
+    }
+
+    /// The base at exactly `scale` decimals as an i128, or None if that would drop a non-zero
+    /// digit or doesn't fit.
+    #[cfg(any(feature = "arrow", feature = "parquet", feature = "polars"))]
+    pub(crate) fn to_scaled_i128(self, scale: isize) -> Option<i128> {
+        let wide = IntFloat::new(self.base.to_i128()?, self.pow);
+        let scaled = wide.with_scale(scale)?;
+        (scaled == wide).then_some(scaled.base)
+    }
+
+    /// Change the number of decimals to exactly `scale`, like `with_scale`. Panics if the base
+    /// overflows.
+    pub fn rescale(self, scale: isize) -> Self {
+        self.with_scale(scale)
+            .expect("attempt to rescale with overflow")
+    }
+
+    /// Divide by `rhs` and round the quotient half away from zero to `decimals` decimals, so that
+    /// more digits are kept than with `/`, which truncates the quotient of the bases. Panics if
+    /// `rhs` is zero or if the quotient doesn't fit in the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// let one = IntFloat::new(1, 0);
+    /// let three = IntFloat::new(3, 0);
+    /// assert_eq!(one / three, IntFloat::new(0, 0));
+    /// assert_eq!(one.div_with_precision(three, 4).to_string(), "0.3333");
+    /// assert_eq!(three.div_with_precision(IntFloat::new(-45, 1), 2).to_string(), "-0.67");
+    /// ```
+    pub fn div_with_precision(self, rhs: Self, decimals: isize) -> Self {
+        assert!(!rhs.base.is_zero(), "attempt to divide by zero");
+        self.checked_div_with_precision(rhs, decimals)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Like `div_with_precision`, but None if `rhs` is zero or the quotient doesn't fit.
+    pub fn checked_div_with_precision(self, rhs: Self, decimals: isize) -> Option<Self> {
+        self.checked_div_with_mode(rhs, decimals, RoundingMode::HalfUp)
+    }
+
+    /// Like `checked_div_with_precision`, but rounded with `mode`.
+    pub(crate) fn checked_div_with_mode(
+        self,
+        rhs: Self,
+        decimals: isize,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        if rhs.base.is_zero() {
+            return None;
+        }
+        if self.base.is_zero() {
+            return Some(IntFloat::new(self.base, decimals));
+        }
+        // The quotient of the bases has to be scaled by 10^shift to end up with `decimals`.
+        let shift = decimals.checked_sub(self.pow)?.checked_add(rhs.pow)?;
+        let factor = u32::try_from(shift.unsigned_abs())
+            .ok()
+            .and_then(|shift| 10_u128.checked_pow(shift));
+        let (numerator, denominator) = if shift >= 0 {
+            (
+                self.base.unsigned_abs().checked_mul(factor?)?,
+                Some(rhs.base.unsigned_abs()),
+            )
+        } else {
+            let denominator = factor.and_then(|factor| rhs.base.unsigned_abs().checked_mul(factor));
+            (self.base.unsigned_abs(), denominator)
+        };
+        let is_negative = self.base.is_negative() != rhs.base.is_negative();
+        let magnitude = mode.round_magnitude(numerator, denominator, is_negative);
+        let base = i128::try_from(magnitude)
+            .ok()
+            .map(|magnitude| if is_negative { -magnitude } else { magnitude })
+            .and_then(num_traits::cast)?;
+        Some(IntFloat {
+            base,
+            pow: decimals,
+        })
+    }
+
+    /// The largest integer less than or equal to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::IntFloat;
+    /// let a = IntFloat::new(-534, 2);
+    /// assert_eq!(a.floor(), IntFloat::new(-6, 0));
+    /// assert_eq!(a.ceil(), IntFloat::new(-5, 0));
+    /// assert_eq!(a.trunc(), IntFloat::new(-5, 0));
+    /// assert_eq!(a.fract(), IntFloat::new(-34, 2));
+    /// ```
+    pub fn floor(self) -> Self {
+        self.round_dp_with(0, RoundingMode::Floor)
+    }
+
+    /// The smallest integer greater than or equal to the value.
+    pub fn ceil(self) -> Self {
+        self.round_dp_with(0, RoundingMode::Ceiling)
+    }
+
+    /// The integer part of the value, by dropping all decimals.
+    pub fn trunc(self) -> Self {
+        self.round_dp_with(0, RoundingMode::TowardZero)
+    }
+
+    /// The fractional part of the value, with the same sign and scale: `self - self.trunc()`.
+    pub fn fract(self) -> Self {
+        if self.pow <= 0 {
+            return IntFloat::new(T::zero(), self.pow);
+        }
+        let base = match usize::try_from(self.pow)
+            .ok()
+            .and_then(|pow| num_traits::checked_pow(T::TEN, pow))
+        {
+            Some(divisor) => self.base % divisor,
+            None => self.base,
+        };
+        IntFloat::new(base, self.pow)
+    }
+
+    /// Round to a multiple of `tick` with `mode`, e.g. to the tick size of an exchange. The
+    /// number of ticks is rounded exactly, so `Floor` and `Ceiling` round the value itself down
+    /// and up, whatever the sign of `tick`. The result has the scale of `tick`. Panics if `tick`
+    /// is zero or if the result doesn't fit in the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intfloat::{IntFloat, RoundingMode};
+    /// let tick = IntFloat::new(25, 2);
+    /// let price = IntFloat::new(100127, 3);
+    /// assert_eq!(price.round_to_multiple_of(tick, RoundingMode::HalfEven).to_string(), "100.25");
+    /// assert_eq!(price.round_to_multiple_of(tick, RoundingMode::Floor).to_string(), "100.00");
+    /// assert_eq!(price.round_to_multiple_of(IntFloat::new(5, -1), RoundingMode::HalfUp).to_string(), "100");
+    /// ```
+    pub fn round_to_multiple_of(self, tick: Self, mode: RoundingMode) -> Self {
+        assert!(!tick.base.is_zero(), "attempt to divide by zero");
+        self.checked_round_to_multiple_of(tick, mode)
+            .expect("attempt to round with overflow")
+    }
+
+    /// Like `round_to_multiple_of`, but None if `tick` is zero or the result doesn't fit.
+    pub fn checked_round_to_multiple_of(self, tick: Self, mode: RoundingMode) -> Option<Self> {
+        let tick = if tick.base.is_negative() {
+            IntFloat::new(tick.base.checked_neg()?, tick.pow)
+        } else {
+            tick
+        };
+        self.checked_div_with_mode(tick, 0, mode)?.checked_mul(tick)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat64};
+
+    fn round_all(base: isize, mode: RoundingMode) -> Vec<isize> {
+        [base, -base]
+            .into_iter()
+            .map(|base| IntFloat::new(base, 1).round_dp_with(0, mode).base)
+            .collect()
+    }
+
+    #[test]
+    fn test_round_dp_with() {
+        assert_eq!(round_all(25, RoundingMode::HalfUp), vec![3, -3]);
+        assert_eq!(round_all(25, RoundingMode::HalfDown), vec![2, -2]);
+        assert_eq!(round_all(25, RoundingMode::HalfEven), vec![2, -2]);
+        assert_eq!(round_all(35, RoundingMode::HalfEven), vec![4, -4]);
+        assert_eq!(round_all(26, RoundingMode::HalfDown), vec![3, -3]);
+        assert_eq!(round_all(24, RoundingMode::HalfUp), vec![2, -2]);
+        assert_eq!(round_all(21, RoundingMode::Floor), vec![2, -3]);
+        assert_eq!(round_all(21, RoundingMode::Ceiling), vec![3, -2]);
+        assert_eq!(round_all(29, RoundingMode::TowardZero), vec![2, -2]);
+        assert_eq!(round_all(21, RoundingMode::AwayFromZero), vec![3, -3]);
+        assert_eq!(round_all(20, RoundingMode::AwayFromZero), vec![2, -2]);
+        assert_eq!(round_all(20, RoundingMode::Ceiling), vec![2, -2]);
+    }
+
+    #[test]
+    fn test_round_dp_with_scale() {
+        let this = IntFloat::new(12345, 3);
+        assert_eq!(
+            this.round_dp_with(5, RoundingMode::Floor),
+            IntFloat::new(12345, 3)
+        );
+        assert_eq!(
+            this.round_dp_with(-1, RoundingMode::HalfUp),
+            IntFloat::new(1, -1)
+        );
+        assert_eq!(
+            this.round_dp_with(-2, RoundingMode::Ceiling),
+            IntFloat::new(1, -2)
+        );
+        assert_eq!(
+            this.round_dp_with(-2, RoundingMode::HalfUp),
+            IntFloat::new(0, -2)
+        );
+    }
+
+    #[test]
+    fn test_round_dp() {
+        assert_eq!(
+            IntFloat::new(-12345, 3).round_dp(2),
+            IntFloat::new(-1235, 2)
+        );
+        assert_eq!(IntFloat::new(12344, 3).round_dp(2), IntFloat::new(1234, 2));
+        assert_eq!(IntFloat::new(12344, 3).round_dp(4), IntFloat::new(12344, 3));
+    }
+
+    #[test]
+    fn test_rescale() {
+        let this = IntFloat::new(534, 2);
+        assert_eq!(this.rescale(4), IntFloat::new(53400, 4));
+        assert_eq!(this.rescale(4).pow, 4);
+        assert_eq!(this.rescale(1), IntFloat::new(53, 1));
+        assert_eq!(this.rescale(2), this);
+        assert_eq!(this.rescale(-1), IntFloat::new(1, -1));
+        assert_eq!(this.with_scale(30), None);
+        assert_eq!(
+            IntFloat::new(0, 0).with_scale(30),
+            Some(IntFloat::new(0, 30))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to rescale with overflow")]
+    fn test_rescale_overflow() {
+        IntFloat::new(isize::MAX, 0).rescale(1);
+    }
+
+    #[test]
+    fn test_floor_ceil_trunc_fract() {
+        let this = IntFloat::new(534, 2);
+        assert_eq!(this.floor(), IntFloat::new(5, 0));
+        assert_eq!(this.ceil(), IntFloat::new(6, 0));
+        assert_eq!(this.trunc(), IntFloat::new(5, 0));
+        assert_eq!(this.fract(), IntFloat::new(34, 2));
+        assert_eq!(this.trunc() + this.fract(), this);
+        assert_eq!((-this).floor(), IntFloat::new(-6, 0));
+        assert_eq!((-this).trunc() + (-this).fract(), -this);
+
+        let this = IntFloat::new(500, 2);
+        assert_eq!(this.floor(), this);
+        assert_eq!(this.ceil(), this);
+        assert_eq!(this.fract(), IntFloat::new(0, 2));
+
+        let this = IntFloat::new(5, -2);
+        assert_eq!(this.floor(), this);
+        assert_eq!(this.ceil(), this);
+        assert_eq!(this.fract(), IntFloat::new(0, 0));
+
+        let this = IntFloat::new(isize::MAX, 30);
+        assert_eq!(this.floor(), IntFloat::new(0, 0));
+        assert_eq!(this.ceil(), IntFloat::new(1, 0));
+        assert_eq!(this.fract(), this);
+    }
+
+    #[test]
+    fn test_div_with_precision() {
+        let one = IntFloat::new(1, 0);
+        let three = IntFloat::new(3, 0);
+        assert_eq!(one.div_with_precision(three, 3), IntFloat::new(333, 3));
+        assert_eq!(
+            IntFloat::new(2, 0).div_with_precision(three, 3),
+            IntFloat::new(667, 3)
+        );
+        assert_eq!(
+            IntFloat::new(-2, 0).div_with_precision(three, 3),
+            IntFloat::new(-667, 3)
+        );
+        assert_eq!(
+            IntFloat::new(-2, 0).div_with_precision(-three, 0),
+            IntFloat::new(1, 0)
+        );
+        assert_eq!(
+            IntFloat::new(534, 2).div_with_precision(IntFloat::new(2, -2), 4),
+            IntFloat::new(267, 4)
+        );
+        assert_eq!(
+            IntFloat::new(5, -2).div_with_precision(IntFloat::new(4, 1), -2),
+            IntFloat::new(13, -2)
+        );
+        assert_eq!(
+            one.div_with_precision(IntFloat::new(isize::MAX, 0), 5),
+            IntFloat::new(0, 5)
+        );
+        assert_eq!(
+            IntFloat::new(0, 0).div_with_precision(three, isize::MAX),
+            IntFloat::new(0, isize::MAX)
+        );
+        assert_eq!(
+            one.div_with_precision(three, -isize::MAX),
+            IntFloat::new(0, -isize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_checked_div_with_precision() {
+        let one = IntFloat::new(1, 0);
+        let three = IntFloat::new(3, 0);
+        assert_eq!(
+            one.checked_div_with_precision(three, 2),
+            Some(IntFloat::new(33, 2))
+        );
+        assert_eq!(one.checked_div_with_precision(three, 30), None);
+        assert_eq!(one.checked_div_with_precision(IntFloat::new(0, 1), 2), None);
+        assert_eq!(
+            IntFloat::new(1, 1).checked_div_with_precision(three, isize::MIN),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_with_precision_overflow() {
+        IntFloat::new(1, 0).div_with_precision(IntFloat::new(3, 0), 30);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_with_precision_by_zero() {
+        IntFloat::new(1, 0).div_with_precision(IntFloat::new(0, 0), 3);
+    }
+
+    #[test]
+    fn test_round_to_multiple_of() {
+        let tick = IntFloat::new(25, 2);
+        let this = IntFloat::new(-1125, 3).round_to_multiple_of(tick, RoundingMode::HalfEven);
+        assert_eq!((this.base, this.pow), (-100, 2));
+        let this = IntFloat::new(-1125, 3).round_to_multiple_of(tick, RoundingMode::HalfUp);
+        assert_eq!((this.base, this.pow), (-125, 2));
+        let this = IntFloat::new(-11, 1).round_to_multiple_of(-tick, RoundingMode::Floor);
+        assert_eq!((this.base, this.pow), (-125, 2));
+        let this = IntFloat::new(-11, 1).round_to_multiple_of(tick, RoundingMode::Ceiling);
+        assert_eq!((this.base, this.pow), (-100, 2));
+        let this =
+            IntFloat::new(7, 0).round_to_multiple_of(IntFloat::new(3, 0), RoundingMode::TowardZero);
+        assert_eq!((this.base, this.pow), (6, 0));
+        assert_eq!(
+            IntFloat::new(125, 2).round_to_multiple_of(IntFloat::new(5, 1), RoundingMode::Floor),
+            IntFloat::new(1, 0)
+        );
+        assert_eq!(
+            IntFloat::new(1, 0)
+                .checked_round_to_multiple_of(IntFloat::new(0, 2), RoundingMode::HalfUp),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(isize::MAX, 0)
+                .checked_round_to_multiple_of(IntFloat::new(10, 0), RoundingMode::Ceiling),
+            None
+        );
+        assert_eq!(
+            IntFloat::new(1, 0)
+                .checked_round_to_multiple_of(IntFloat::new(isize::MIN, 0), RoundingMode::HalfUp),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_round_to_multiple_of_zero() {
+        IntFloat::new(1, 0).round_to_multiple_of(IntFloat::new(0, 0), RoundingMode::HalfUp);
+    }
+
+    #[test]
+    fn test_round_dp_with_extremes() {
+        let this = IntFloat64::new(i64::MAX, 19);
+        assert_eq!(
+            this.round_dp_with(0, RoundingMode::HalfUp),
+            IntFloat64::new(1, 0)
+        );
+        let this = IntFloat64::new(i64::MIN, 0);
+        assert_eq!(
+            this.round_dp_with(-18, RoundingMode::Floor),
+            IntFloat64::new(-10, -18)
+        );
+        let this = IntFloat::new(1, isize::MAX);
+        assert_eq!(
+            this.round_dp_with(0, RoundingMode::Ceiling),
+            IntFloat::new(1, 0)
+        );
+        assert_eq!(
+            this.round_dp_with(0, RoundingMode::HalfUp),
+            IntFloat::new(0, 0)
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
+
+/// Arithmetic that clamps the base to its minimum or maximum value at the scale of the result
+/// (the largest power of the operands for addition and subtraction, their sum for
+/// multiplication) instead of overflowing. If the sum of the powers doesn't fit in an isize, a
+/// product too large to represent is clamped at pow `isize::MIN`, and one too small is truncated
+/// toward zero at pow `isize::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(isize::MAX - 1, 1);
+/// assert_eq!(
+///     a.saturating_add(IntFloat::new(5, 0)),
+///     IntFloat::new(isize::MAX, 1)
+/// );
+/// assert_eq!(
+///     IntFloat::new(1, 0).saturating_sub(IntFloat::new(1, 2)),
+///     IntFloat::new(99, 2)
+/// );
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        if self.base.is_zero() {
+            return rhs;
+        }
+        if rhs.base.is_zero() {
+            return self;
+        }
+        if let Some(sum) = self.checked_add(rhs) {
+            return sum;
+        }
+        // The operand with the smaller power is rescaled. Either that overflows, in which case it
+        // dominates the sum, or the addition itself overflows, so both operands have the same sign.
+        // Either way, the sign of the rescaled operand is the sign of the true sum.
+        let (rescaled, pow) = if rhs.pow > self.pow {
+            (self, rhs.pow)
+        } else {
+            (rhs, self.pow)
+        };
+        IntFloat {
+            base: if rescaled.base.is_positive() {
+                T::max_value()
+            } else {
+                T::min_value()
+            },
+            pow,
+        }
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match rhs.checked_neg() {
+            Some(neg) => self.saturating_add(neg),
+            // -T::MIN is T::MAX + 1
+            None => self
+                .saturating_add(IntFloat::new(T::max_value(), rhs.pow))
+                .saturating_add(IntFloat::new(T::one(), rhs.pow)),
+        }
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let base = self.base.saturating_mul(&rhs.base);
+        if let Some(pow) = self.pow.checked_add(rhs.pow) {
+            return IntFloat { base, pow };
+        }
+        if self.pow > 0 {
+            // Both powers are positive, and the sum is `excess` above isize::MAX.
+            let excess = self.pow - (isize::MAX - rhs.pow);
+            let factor = usize::try_from(excess)
+                .ok()
+                .and_then(|excess| num_traits::checked_pow(T::TEN, excess));
+            IntFloat {
+                base: factor.map_or(T::zero(), |factor| base / factor),
+                pow: isize::MAX,
+            }
+        } else {
+            IntFloat {
+                base: if base.is_zero() {
+                    base
+                } else if base.is_positive() {
+                    T::max_value()
+                } else {
+                    T::min_value()
+                },
+                pow: isize::MIN,
+            }
+        }
+    }
+}
+
+impl<T: BaseInt> SaturatingAdd for IntFloat<T> {
+    fn saturating_add(&self, v: &Self) -> Self {
+        IntFloat::saturating_add(*self, *v)
+    }
+}
+
+impl<T: BaseInt> SaturatingSub for IntFloat<T> {
+    fn saturating_sub(&self, v: &Self) -> Self {
+        IntFloat::saturating_sub(*self, *v)
+    }
+}
+
+impl<T: BaseInt> SaturatingMul for IntFloat<T> {
+    fn saturating_mul(&self, v: &Self) -> Self {
+        IntFloat::saturating_mul(*self, *v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_saturating_add() {
+        let this = IntFloat::new(534, 0);
+        let that = IntFloat::new(100, 2);
+        assert_eq!(this.saturating_add(that), this + that);
+
+        let this = IntFloat::new(isize::MAX, 2);
+        let that = IntFloat::new(1, 2);
+        let result = this.saturating_add(that);
+        assert_eq!((result.base, result.pow), (isize::MAX, 2));
+        let result = (-this).saturating_add(-that).saturating_add(-that);
+        assert_eq!((result.base, result.pow), (isize::MIN, 2));
+
+        // Rescaling a large base to the other power overflows
+        let this = IntFloat::new(-isize::MAX / 10, 0);
+        let that = IntFloat::new(5, 3);
+        let result = this.saturating_add(that);
+        assert_eq!((result.base, result.pow), (isize::MIN, 3));
+        let result = that.saturating_add(-this);
+        assert_eq!((result.base, result.pow), (isize::MAX, 3));
+        assert!(IntFloat::checked_rescale(this.base, 3).is_none());
+
+        // Zero never dominates, however far apart the powers are
+        let result = IntFloat::new(0, 0).saturating_add(IntFloat::new(5, 30));
+        assert_eq!((result.base, result.pow), (5, 30));
+        let result = IntFloat::new(-5, 30).saturating_add(IntFloat::new(0, isize::MIN));
+        assert_eq!((result.base, result.pow), (-5, 30));
+        let result = IntFloat::new(1, -1).saturating_add(IntFloat::new(-1, isize::MAX));
+        assert_eq!((result.base, result.pow), (isize::MAX, isize::MAX));
+    }
+
+    #[test]
+    fn test_saturating_sub() {
+        let this = IntFloat::new(534, -2);
+        let that = IntFloat::new(1068, -2);
+        assert_eq!(that.saturating_sub(this), this);
+
+        let this = IntFloat::new(isize::MIN, 1);
+        let result = this.saturating_sub(IntFloat::new(1, 1));
+        assert_eq!((result.base, result.pow), (isize::MIN, 1));
+        let result = IntFloat::new(0, 1).saturating_sub(this);
+        assert_eq!((result.base, result.pow), (isize::MAX, 1));
+        let result = IntFloat::new(-1, 1).saturating_sub(this);
+        assert_eq!((result.base, result.pow), (isize::MAX, 1));
+        let result = IntFloat::new(-2, 1).saturating_sub(this);
+        assert_eq!((result.base, result.pow), (isize::MAX - 1, 1));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_saturating_mul() {
+        let this = IntFloat::new(500, 2);
+        assert_eq!(this.saturating_mul(this), IntFloat::new(250000, 4));
+
+        let this = IntFloat::new(3_000_000_000_000, 9);
+        let result = this.saturating_mul(this);
+        assert_eq!((result.base, result.pow), (isize::MAX, 18));
+        let result = this.saturating_mul(-this);
+        assert_eq!((result.base, result.pow), (isize::MIN, 18));
+    }
+
+    #[test]
+    fn test_saturating_mul_pow_overflow() {
+        let tiny = IntFloat::new(50, isize::MAX);
+        let result = tiny.saturating_mul(IntFloat::new(1, 1));
+        assert_eq!((result.base, result.pow), (5, isize::MAX));
+        let result = tiny.saturating_mul(tiny);
+        assert_eq!((result.base, result.pow), (0, isize::MAX));
+
+        let huge = IntFloat::new(-3, isize::MIN);
+        let result = huge.saturating_mul(IntFloat::new(2, -1));
+        assert_eq!((result.base, result.pow), (isize::MIN, isize::MIN));
+        let result = huge.saturating_mul(huge);
+        assert_eq!((result.base, result.pow), (isize::MAX, isize::MIN));
+        let result = IntFloat::new(0, isize::MIN).saturating_mul(huge);
+        assert_eq!((result.base, result.pow), (0, isize::MIN));
+    }
+
+    #[test]
+    fn test_saturating_traits() {
+        let this = IntFloat::new(isize::MAX, 0);
+        let that = IntFloat::new(2, 0);
+        assert_eq!(SaturatingAdd::saturating_add(&this, &that), this);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&-this, &that),
+            IntFloat::new(isize::MIN, 0)
+        );
+        assert_eq!(SaturatingMul::saturating_mul(&this, &that), this);
+    }
+}
@@ -0,0 +1,374 @@
+//! Serde support for IntFloat. By default, IntFloat is (de)serialized as exact decimal string.
+//! Other representations can be chosen per field with the modules in here, for example:
+//!
+//! ```
+//! use intfloat::IntFloat;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Quote {
+//!     price: IntFloat,
+//!     #[serde(with = "intfloat::serde::tuple")]
+//!     volume: IntFloat,
+//!     #[serde(with = "intfloat::serde::float")]
+//!     change: IntFloat,
+//! }
+//!
+//! let quote = Quote {
+//!     price: IntFloat::new(1234, 2),
+//!     volume: IntFloat::new(15, 1),
+//!     change: IntFloat::new(-25, 2),
+//! };
+//! let json = serde_json::to_string(&quote).unwrap();
+//! assert_eq!(json, r#"{"price":"12.34","volume":[15,1],"change":-0.25}"#);
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// IntFloat is serialized as its exact decimal string, e.g. "12.34", so no precision is lost in
+/// formats that would otherwise store a float.
+impl<T: BaseInt> Serialize for IntFloat<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// IntFloat is deserialized from a decimal string (as produced by Serialize), from an integer, or
+/// from a float. A float is read via its shortest round-trip representation, so 0.1 becomes
+/// IntFloat::new(1, 1).
+impl<'de, T: BaseInt> Deserialize<'de> for IntFloat<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(IntFloatVisitor(PhantomData))
+    }
+}
+
+struct IntFloatVisitor<T>(PhantomData<T>);
+
+impl<T: BaseInt> IntFloatVisitor<T> {
+    fn visit_int<E: de::Error, I: Copy + num_traits::ToPrimitive>(
+        self,
+        v: I,
+        unexpected: de::Unexpected,
+    ) -> Result<IntFloat<T>, E> {
+        T::from(v)
+            .map(|base| IntFloat::new(base, 0))
+            .ok_or_else(|| E::invalid_value(unexpected, &self))
+    }
+}
+
+impl<T: BaseInt> Visitor<'_> for IntFloatVisitor<T> {
+    type Value = IntFloat<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal number or a string containing one")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<IntFloat<T>, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<IntFloat<T>, E> {
+        self.visit_int(v, de::Unexpected::Signed(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<IntFloat<T>, E> {
+        self.visit_int(v, de::Unexpected::Unsigned(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<IntFloat<T>, E> {
+        self.visit_int(v, de::Unexpected::Other("128-bit integer"))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<IntFloat<T>, E> {
+        self.visit_int(v, de::Unexpected::Other("128-bit integer"))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<IntFloat<T>, E> {
+        format!("{:e}", v)
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Float(v), &self))
+    }
+}
+
+/// (De)serialize IntFloat strictly as its exact decimal string, e.g. "12.34". Unlike the default
+/// implementation, numbers are rejected when deserializing.
+pub mod str {
+    use crate::intfloat::IntFloat;
+    use crate::BaseInt;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    pub fn serialize<T: BaseInt, S: Serializer>(
+        value: &IntFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T: BaseInt, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IntFloat<T>, D::Error> {
+        deserializer.deserialize_str(StrVisitor(PhantomData))
+    }
+
+    struct StrVisitor<T>(PhantomData<T>);
+
+    impl<T: BaseInt> Visitor<'_> for StrVisitor<T> {
+        type Value = IntFloat<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string containing a decimal number")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<IntFloat<T>, E> {
+            v.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// (De)serialize IntFloat as a compact `[base, pow]` tuple, e.g. `[1234, 2]` for 12.34.
+pub mod tuple {
+    use crate::intfloat::IntFloat;
+    use crate::BaseInt;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: BaseInt + Serialize, S: Serializer>(
+        value: &IntFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (value.base, value.pow).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: BaseInt + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IntFloat<T>, D::Error> {
+        let (base, pow) = <(T, isize)>::deserialize(deserializer)?;
+        Ok(IntFloat::new(base, pow))
+    }
+}
+
+/// (De)serialize IntFloat as a struct with named fields, e.g. `{"base": 1234, "pow": 2}`.
+pub mod parts {
+    use crate::intfloat::IntFloat;
+    use crate::BaseInt;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "IntFloat")]
+    struct Parts<T> {
+        base: T,
+        pow: isize,
+    }
+
+    pub fn serialize<T: BaseInt + Serialize, S: Serializer>(
+        value: &IntFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Parts {
+            base: value.base,
+            pow: value.pow,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: BaseInt + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IntFloat<T>, D::Error> {
+        let parts = Parts::deserialize(deserializer)?;
+        Ok(IntFloat::new(parts.base, parts.pow))
+    }
+}
+
+/// (De)serialize IntFloat as a float. Note that this is lossy for values that a float can't
+/// represent exactly; deserialization reads the shortest round-trip representation of the float,
+/// just like the default implementation.
+pub mod float {
+    use crate::intfloat::IntFloat;
+    use crate::BaseInt;
+    use num_traits::ToPrimitive;
+    use serde::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    pub fn serialize<T: BaseInt, S: Serializer>(
+        value: &IntFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(value.to_f64().unwrap())
+    }
+
+    pub fn deserialize<'de, T: BaseInt, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IntFloat<T>, D::Error> {
+        deserializer.deserialize_f64(super::IntFloatVisitor(PhantomData))
+    }
+}
+
+/// (De)serialize IntFloat as a BSON Decimal128, which keeps the number of decimals, so documents
+/// with decimal fields can be read directly into IntFloat. Fails for values that a Decimal128
+/// can't represent exactly, and for NaN and infinity.
+#[cfg(feature = "bson")]
+pub mod decimal128 {
+    use crate::intfloat::IntFloat;
+    use crate::BaseInt;
+    use bson::Decimal128;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: BaseInt, S: Serializer>(
+        value: &IntFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Decimal128::try_from(*value)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: BaseInt, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IntFloat<T>, D::Error> {
+        IntFloat::try_from(Decimal128::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat128};
+    use serde::Deserialize;
+
+    #[test]
+    fn test_serialize() {
+        let this = IntFloat::new(1234, 2);
+        assert_eq!(serde_json::to_string(&this).unwrap(), "\"12.34\"");
+
+        let this = IntFloat::new(-5, -2);
+        assert_eq!(serde_json::to_string(&this).unwrap(), "\"-500\"");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let this: IntFloat = serde_json::from_str("\"12.34\"").unwrap();
+        assert_eq!(this, IntFloat::new(1234, 2));
+        assert_eq!(this.to_string(), "12.34");
+
+        let this: IntFloat = serde_json::from_str("\"1.5e-3\"").unwrap();
+        assert_eq!(this, IntFloat::new(15, 4));
+
+        let this: IntFloat = serde_json::from_str("-42").unwrap();
+        assert_eq!(this, IntFloat::new(-42, 0));
+
+        let this: IntFloat = serde_json::from_str("0.1").unwrap();
+        assert_eq!(this.to_string(), "0.1");
+
+        let this: IntFloat = serde_json::from_str("12.5e3").unwrap();
+        assert_eq!(this, IntFloat::new(12500, 0));
+
+        assert!(serde_json::from_str::<IntFloat>("\"abc\"").is_err());
+        assert!(serde_json::from_str::<IntFloat>("18446744073709551615").is_err());
+        assert!(serde_json::from_str::<IntFloat>("true").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let these = vec![
+            IntFloat::new(1234, 2),
+            IntFloat::new(500, 2),
+            IntFloat::new(-1, 9),
+            IntFloat::new(7, -3),
+        ];
+        let json = serde_json::to_string(&these).unwrap();
+        let those: Vec<IntFloat> = serde_json::from_str(&json).unwrap();
+        assert_eq!(these, those);
+        assert_eq!(those[1].to_string(), "5.00");
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Representations {
+        #[serde(with = "super::str")]
+        string: IntFloat,
+        #[serde(with = "super::tuple")]
+        tuple: IntFloat,
+        #[serde(with = "super::parts")]
+        parts: IntFloat,
+        #[serde(with = "super::float")]
+        float: IntFloat,
+    }
+
+    #[test]
+    fn test_representations() {
+        let this = Representations {
+            string: IntFloat::new(1234, 2),
+            tuple: IntFloat::new(1234, 2),
+            parts: IntFloat::new(-5, -2),
+            float: IntFloat::new(125, 3),
+        };
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(
+            json,
+            r#"{"string":"12.34","tuple":[1234,2],"parts":{"base":-5,"pow":-2},"float":0.125}"#
+        );
+        let that: Representations = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, that);
+        assert_eq!((that.parts.base, that.parts.pow), (-5, -2));
+    }
+
+    #[test]
+    fn test_representations_strict() {
+        let json = r#"{"string":12.34,"tuple":[1234,2],"parts":{"base":1,"pow":0},"float":1}"#;
+        assert!(serde_json::from_str::<Representations>(json).is_err());
+
+        let json = r#"{"string":"1","tuple":"12.34","parts":{"base":1,"pow":0},"float":1}"#;
+        assert!(serde_json::from_str::<Representations>(json).is_err());
+
+        let json = r#"{"string":"1","tuple":[1,0],"parts":{"base":1},"float":1}"#;
+        assert!(serde_json::from_str::<Representations>(json).is_err());
+
+        let json = r#"{"string":"1","tuple":[1,0],"parts":{"base":1,"pow":0},"float":1}"#;
+        let that: Representations = serde_json::from_str(json).unwrap();
+        assert_eq!(that.float, IntFloat::new(1, 0));
+    }
+
+    #[test]
+    fn test_intfloat128() {
+        let this = IntFloat128::new(i128::MAX, 10);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, "\"17014118346046923173168730371.5884105727\"");
+        let that: IntFloat128 = serde_json::from_str(&json).unwrap();
+        assert_eq!((that.base, that.pow), (i128::MAX, 10));
+
+        let that: IntFloat128 = serde_json::from_str("18446744073709551615").unwrap();
+        assert_eq!(that, IntFloat128::new(u64::MAX as i128, 0));
+        let that: IntFloat128 = serde_json::from_str("0.25").unwrap();
+        assert_eq!(that, IntFloat128::new(25, 2));
+    }
+
+    #[cfg(feature = "bson")]
+    #[test]
+    fn test_decimal128() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Invoice {
+            #[serde(with = "super::decimal128")]
+            total: IntFloat,
+        }
+        let this = Invoice {
+            total: IntFloat::new(1250, 2),
+        };
+        let document = bson::serialize_to_document(&this).unwrap();
+        assert_eq!(
+            document.get_decimal128("total").unwrap().to_string(),
+            "12.50"
+        );
+        let that: Invoice = bson::deserialize_from_document(document).unwrap();
+        assert_eq!(that.total.to_string(), "12.50");
+        let document = bson::doc! { "total": 12.5 };
+        assert!(bson::deserialize_from_document::<Invoice>(document).is_err());
+    }
+}
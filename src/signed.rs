@@ -0,0 +1,109 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_traits::Signed;
+
+/// Sign-related methods, mirroring the primitive integers. Like those, `abs` overflows (and
+/// panics in debug builds) for the most negative base.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(-534, 2);
+/// assert_eq!(a.abs(), IntFloat::new(534, 2));
+/// assert_eq!(a.signum(), IntFloat::new(-1, 0));
+/// assert!(a.is_negative());
+/// assert!(!a.is_positive());
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn abs(self) -> Self {
+        IntFloat {
+            base: self.base.abs(),
+            pow: self.pow,
+        }
+    }
+
+    /// One with the sign of the value, or zero for zero.
+    pub fn signum(self) -> Self {
+        IntFloat::new(self.base.signum(), 0)
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.base.is_negative()
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.base.is_positive()
+    }
+}
+
+impl<T: BaseInt> Signed for IntFloat<T> {
+    fn abs(&self) -> Self {
+        IntFloat::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            IntFloat::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        IntFloat::signum(*self)
+    }
+
+    fn is_positive(&self) -> bool {
+        IntFloat::is_positive(*self)
+    }
+
+    fn is_negative(&self) -> bool {
+        IntFloat::is_negative(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(IntFloat::new(-534, 2).abs(), IntFloat::new(534, 2));
+        assert_eq!(IntFloat::new(534, 2).abs(), IntFloat::new(534, 2));
+        assert_eq!(IntFloat::new(0, 2).abs(), IntFloat::new(0, 2));
+        assert_eq!(IntFloat::new(-5, -2).abs().pow, -2);
+    }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!(IntFloat::new(-534, 2).signum(), IntFloat::new(-1, 0));
+        assert_eq!(IntFloat::new(1, 20).signum(), IntFloat::new(1, 0));
+        assert_eq!(IntFloat::new(0, 2).signum(), IntFloat::new(0, 0));
+    }
+
+    #[test]
+    fn test_is_negative_positive() {
+        assert!(IntFloat::new(-1, 5).is_negative());
+        assert!(IntFloat::new(1, 5).is_positive());
+        assert!(!IntFloat::new(0, 5).is_negative());
+        assert!(!IntFloat::new(0, 5).is_positive());
+    }
+
+    #[test]
+    fn test_signed_trait() {
+        fn distance<T: Signed + Copy>(a: T, b: T) -> T {
+            Signed::abs(&(a - b))
+        }
+
+        let this = IntFloat::new(15, 1);
+        let that = IntFloat::new(4, 0);
+        assert_eq!(distance(this, that), IntFloat::new(25, 1));
+        assert_eq!(that.abs_sub(&this), IntFloat::new(25, 1));
+        assert_eq!(this.abs_sub(&that), IntFloat::new(0, 0));
+        assert_eq!(Signed::signum(&-this), IntFloat::new(-1, 0));
+        assert!(Signed::is_positive(&this));
+        assert!(Signed::is_negative(&-this));
+    }
+}
@@ -0,0 +1,210 @@
+//! Vectorized operations on slices of IntFloats, for hot loops over large columns of values.
+//! When all values share the same pow, which is the common case for prices or ticks, no
+//! rescaling is needed and the loops run over fixed-size lanes without branches, so that the
+//! compiler can turn them into SIMD instructions. Otherwise they fall back to the scalar
+//! operations. The results are the same either way.
+//!
+//! ```
+//! use intfloat::{simd, IntFloat};
+//! let prices: Vec<IntFloat> = (1..=1000).map(|cents| IntFloat::new(cents, 2)).collect();
+//! assert_eq!(simd::sum(&prices).to_string(), "5005.00");
+//! let doubled = simd::scale(&prices, IntFloat::new(2, 0));
+//! assert_eq!(simd::add(&prices, &prices), doubled);
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+
+/// The number of values handled per iteration of the fast paths.
+const LANES: usize = 8;
+
+/// The pow of all values, or None if they differ or there are none.
+fn shared_pow<T: BaseInt>(values: &[IntFloat<T>]) -> Option<isize> {
+    let pow = values.first()?.pow;
+    values.iter().all(|value| value.pow == pow).then_some(pow)
+}
+
+/// The sum of the bases, or None if a lane overflows.
+fn lane_sum<T: BaseInt>(values: &[IntFloat<T>]) -> Option<T> {
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    let mut lanes = [T::zero(); LANES];
+    let mut overflow = false;
+    for chunk in chunks {
+        for (lane, value) in lanes.iter_mut().zip(chunk) {
+            let (sum, overflowed) = lane.overflowing_add(&value.base);
+            *lane = sum;
+            overflow |= overflowed;
+        }
+    }
+    if overflow {
+        return None;
+    }
+    lanes
+        .iter()
+        .chain(remainder.iter().map(|value| &value.base))
+        .try_fold(T::zero(), |total, base| total.checked_add(base))
+}
+
+/// The sum of all values, like `values.iter().sum()`. Panics if it doesn't fit in the base.
+pub fn sum<T: BaseInt>(values: &[IntFloat<T>]) -> IntFloat<T> {
+    checked_sum(values).expect("attempt to add with overflow")
+}
+
+/// Like `sum`, but None if it doesn't fit in the base.
+pub fn checked_sum<T: BaseInt>(values: &[IntFloat<T>]) -> Option<IntFloat<T>> {
+    if let Some(pow) = shared_pow(values) {
+        if let Some(base) = lane_sum(values) {
+            return IntFloat::zero().checked_add(IntFloat { base, pow });
+        }
+    }
+    values
+        .iter()
+        .try_fold(IntFloat::zero(), |total: IntFloat<T>, value| {
+            total.checked_add(*value)
+        })
+}
+
+/// The element-wise sums of two slices. Panics if the slices have different lengths or if a sum
+/// doesn't fit in the base.
+pub fn add<T: BaseInt>(lhs: &[IntFloat<T>], rhs: &[IntFloat<T>]) -> Vec<IntFloat<T>> {
+    checked_add(lhs, rhs).expect("attempt to add with overflow")
+}
+
+/// Like `add`, but None if a sum doesn't fit in the base. Still panics if the slices have
+/// different lengths.
+pub fn checked_add<T: BaseInt>(
+    lhs: &[IntFloat<T>],
+    rhs: &[IntFloat<T>],
+) -> Option<Vec<IntFloat<T>>> {
+    assert_eq!(
+        lhs.len(),
+        rhs.len(),
+        "attempt to add slices of different lengths"
+    );
+    match (shared_pow(lhs), shared_pow(rhs)) {
+        (Some(pow), Some(rhs_pow)) if pow == rhs_pow => {
+            let mut overflow = false;
+            let sums = lhs
+                .iter()
+                .zip(rhs)
+                .map(|(lhs, rhs)| {
+                    let (base, overflowed) = lhs.base.overflowing_add(&rhs.base);
+                    overflow |= overflowed;
+                    IntFloat { base, pow }
+                })
+                .collect();
+            (!overflow).then_some(sums)
+        }
+        _ => lhs
+            .iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| lhs.checked_add(*rhs))
+            .collect(),
+    }
+}
+
+/// Every value multiplied by `factor`. Panics if a product doesn't fit in the base.
+pub fn scale<T: BaseInt>(values: &[IntFloat<T>], factor: IntFloat<T>) -> Vec<IntFloat<T>> {
+    checked_scale(values, factor).expect("attempt to multiply with overflow")
+}
+
+/// Like `scale`, but None if a product doesn't fit in the base.
+pub fn checked_scale<T: BaseInt>(
+    values: &[IntFloat<T>],
+    factor: IntFloat<T>,
+) -> Option<Vec<IntFloat<T>>> {
+    match shared_pow(values) {
+        Some(pow) => {
+            let pow = pow.checked_add(factor.pow)?;
+            let mut overflow = false;
+            let products = values
+                .iter()
+                .map(|value| {
+                    let (base, overflowed) = value.base.overflowing_mul(&factor.base);
+                    overflow |= overflowed;
+                    IntFloat { base, pow }
+                })
+                .collect();
+            (!overflow).then_some(products)
+        }
+        None => values
+            .iter()
+            .map(|value| value.checked_mul(factor))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+
+    fn values(strs: &[&str]) -> Vec<IntFloat> {
+        strs.iter().map(|str| str.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_sum() {
+        let shared: Vec<IntFloat> = (-20..=30).map(|base| IntFloat::new(base, 2)).collect();
+        assert_eq!(sum(&shared), shared.iter().sum::<IntFloat>());
+        assert_eq!(sum(&shared).to_string(), "2.55");
+        let mixed = values(&["1.5", "0.25", "-3", "10.125", "2"]);
+        assert_eq!(sum(&mixed), IntFloat::new(10875, 3));
+        assert_eq!(sum(&values(&["1e2", "2e2"])).to_string(), "300");
+        assert_eq!(sum::<isize>(&[]), IntFloat::new(0, 0));
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        // The lanes overflow, but the scalar fallback doesn't.
+        let large: Vec<IntFloat32> = (0..2 * LANES)
+            .map(|i| IntFloat32::new(if i % 2 == 0 { i32::MAX } else { -i32::MAX }, 0))
+            .collect();
+        assert_eq!(checked_sum(&large), Some(IntFloat32::new(0, 0)));
+        let too_large = vec![IntFloat32::new(i32::MAX / 4, 1); 5 * LANES];
+        assert_eq!(checked_sum(&too_large), None);
+        let remainder = [IntFloat32::new(i32::MAX, 0), IntFloat32::new(1, 0)];
+        assert_eq!(checked_sum(&remainder), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_sum_overflow() {
+        sum(&[IntFloat32::new(i32::MAX, 0), IntFloat32::new(1, 0)]);
+    }
+
+    #[test]
+    fn test_add() {
+        let lhs = values(&["1.00", "2.50", "-0.75"]);
+        let rhs = values(&["0.01", "-2.50", "1.00"]);
+        assert_eq!(add(&lhs, &rhs), values(&["1.01", "0.00", "0.25"]));
+        let mixed = values(&["1", "2.5", "-0.75"]);
+        assert_eq!(add(&mixed, &rhs), values(&["1.01", "0.00", "0.25"]));
+        let large = [IntFloat32::new(i32::MAX, 0)];
+        assert_eq!(checked_add(&large, &large), None);
+        assert!(add::<isize>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add slices of different lengths")]
+    fn test_add_lengths() {
+        add(&values(&["1", "2"]), &values(&["1"]));
+    }
+
+    #[test]
+    fn test_scale() {
+        let prices = values(&["1.25", "-0.10", "3.00"]);
+        let scaled = scale(&prices, IntFloat::new(15, 1));
+        assert_eq!(scaled, values(&["1.875", "-0.150", "4.500"]));
+        assert_eq!(scaled[1].to_string(), "-0.150");
+        let mixed = values(&["1.25", "-0.1", "3"]);
+        assert_eq!(scale(&mixed, IntFloat::new(15, 1)), scaled);
+        let large = [IntFloat32::new(i32::MAX / 2 + 1, 0)];
+        assert_eq!(checked_scale(&large, IntFloat32::new(2, 0)), None);
+        assert_eq!(
+            checked_scale(&[IntFloat::new(1, isize::MAX)], IntFloat::new(1, 1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, FixedIntFloat};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+use rusqlite::ToSql;
+
+/// Binds as TEXT with the exact decimal string, e.g. `"12.50"`, which keeps the number of
+/// decimals. Use a column with TEXT affinity: SQLite converts numeric text in a NUMERIC or
+/// DECIMAL column to a REAL. To bind as a scaled INTEGER instead, convert to a FixedIntFloat.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::{FixedIntFloat, IntFloat};
+/// use rusqlite::Connection;
+/// let db = Connection::open_in_memory().unwrap();
+/// db.execute("CREATE TABLE prices (text TEXT, cents INTEGER)", ()).unwrap();
+/// let price = IntFloat::new(1999, 2);
+/// let cents = FixedIntFloat::<2>::from_intfloat(price).unwrap();
+/// db.execute("INSERT INTO prices VALUES (?1, ?2)", (price, cents)).unwrap();
+/// let (text, cents): (IntFloat, FixedIntFloat<2>) = db
+///     .query_row("SELECT text, cents FROM prices", (), |row| Ok((row.get(0)?, row.get(1)?)))
+///     .unwrap();
+/// assert_eq!(text, price);
+/// assert_eq!(FixedIntFloat::from_intfloat(price), Some(cents));
+/// ```
+impl<T: BaseInt> ToSql for IntFloat<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+/// Reads TEXT with a decimal string, or an INTEGER as a value without decimals. Fails for a
+/// REAL, which can't be converted exactly, and if the value doesn't fit in the base.
+impl<T: BaseInt> FromSql for IntFloat<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(_) => value.as_str()?.parse().map_err(FromSqlError::other),
+            ValueRef::Integer(integer) => num_traits::cast(integer)
+                .map(|base| IntFloat::new(base, 0))
+                .ok_or(FromSqlError::OutOfRange(integer)),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// Binds as a scaled INTEGER: the stored integer, without the scale.
+impl<const SCALE: i32> ToSql for FixedIntFloat<SCALE> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let base = i64::try_from(self.base())
+            .map_err(|error| rusqlite::Error::ToSqlConversionFailure(Box::new(error)))?;
+        Ok(ToSqlOutput::from(base))
+    }
+}
+
+/// Reads an INTEGER as the stored integer for SCALE decimals.
+impl<const SCALE: i32> FromSql for FixedIntFloat<SCALE> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let integer = value.as_i64()?;
+        isize::try_from(integer)
+            .map(FixedIntFloat::new)
+            .map_err(|_| FromSqlError::OutOfRange(integer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FixedIntFloat, IntFloat, IntFloat128, IntFloat32};
+    use rusqlite::types::{FromSql, FromSqlError, ValueRef};
+    use rusqlite::Connection;
+
+    fn select<T: FromSql>(db: &Connection, sql: &str) -> rusqlite::Result<T> {
+        db.query_row(sql, (), |row| row.get(0))
+    }
+
+    #[test]
+    fn test_text() {
+        let db = Connection::open_in_memory().unwrap();
+        db.execute("CREATE TABLE t (value TEXT)", ()).unwrap();
+        let values = [
+            IntFloat::new(1250, 2),
+            IntFloat::new(-7, 3),
+            IntFloat::new(0, 6),
+        ];
+        for value in values {
+            db.execute("INSERT INTO t VALUES (?1)", [value]).unwrap();
+        }
+        let texts: Vec<String> = db
+            .prepare("SELECT value FROM t")
+            .unwrap()
+            .query_map((), |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(texts, ["12.50", "-0.007", "0.000000"]);
+        let value: IntFloat = select(&db, "SELECT value FROM t").unwrap();
+        assert_eq!(value.to_string(), "12.50");
+        let wide = IntFloat128::new(i128::MAX, 20);
+        assert_eq!(
+            db.query_row("SELECT ?1", [wide], |row| row.get::<_, IntFloat128>(0)),
+            Ok(wide)
+        );
+    }
+
+    #[test]
+    fn test_column_result() {
+        assert_eq!(
+            IntFloat::column_result(ValueRef::Integer(42)),
+            Ok(IntFloat::new(42, 0))
+        );
+        assert_eq!(
+            IntFloat32::column_result(ValueRef::Integer(1 << 40)),
+            Err(FromSqlError::OutOfRange(1 << 40))
+        );
+        assert_eq!(
+            IntFloat::column_result(ValueRef::Real(1.5)),
+            Err(FromSqlError::InvalidType)
+        );
+        assert!(IntFloat::column_result(ValueRef::Text(b"1.2.3")).is_err());
+    }
+
+    #[test]
+    fn test_scaled_integer() {
+        let db = Connection::open_in_memory().unwrap();
+        db.execute("CREATE TABLE t (cents INTEGER)", ()).unwrap();
+        let price = FixedIntFloat::<2>::new(-1999);
+        db.execute("INSERT INTO t VALUES (?1)", [price]).unwrap();
+        assert_eq!(select::<i64>(&db, "SELECT cents FROM t"), Ok(-1999));
+        let price: FixedIntFloat<2> = select(&db, "SELECT cents FROM t").unwrap();
+        assert_eq!(price.to_string(), "-19.99");
+        assert_eq!(
+            FixedIntFloat::<2>::column_result(ValueRef::Text(b"12")),
+            Err(FromSqlError::InvalidType)
+        );
+    }
+}
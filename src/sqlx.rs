@@ -0,0 +1,83 @@
+use crate::intfloat::IntFloat;
+use crate::pg_numeric::PgNumeric;
+use crate::BaseInt;
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::postgres::types::Oid;
+use ::sqlx::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use ::sqlx::{Decode, Encode, Type};
+
+/// The NUMERIC type of Postgres.
+const NUMERIC: PgTypeInfo = PgTypeInfo::with_oid(Oid(1700));
+/// The NUMERIC[] type of Postgres.
+const NUMERIC_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1231));
+
+/// IntFloat is stored as a Postgres NUMERIC, with the pow as the scale.
+impl<T: BaseInt> Type<Postgres> for IntFloat<T> {
+    fn type_info() -> PgTypeInfo {
+        NUMERIC
+    }
+}
+
+impl<T: BaseInt> PgHasArrayType for IntFloat<T> {
+    fn array_type_info() -> PgTypeInfo {
+        NUMERIC_ARRAY
+    }
+}
+
+/// Encodes as the binary NUMERIC with the pow as the scale, so the number of decimals is kept.
+/// A negative pow is rescaled to zero decimals. Fails if that overflows the base, or if the pow
+/// is above 16383, the largest scale of a NUMERIC.
+impl<T: BaseInt> Encode<'_, Postgres> for IntFloat<T> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend(PgNumeric::from_intfloat(*self)?.to_bytes());
+        Ok(IsNull::No)
+    }
+}
+
+/// Decodes a NUMERIC with its scale as the number of decimals. Fails for NaN and infinity, and
+/// if the value doesn't fit in the base.
+impl<T: BaseInt> Decode<'_, Postgres> for IntFloat<T> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(PgNumeric::from_bytes(value.as_bytes()?)?.to_intfloat()?),
+            PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, OutOfRangeError};
+
+    fn encode(value: IntFloat) -> Result<Vec<u8>, BoxDynError> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf)?, IsNull::No));
+        Ok(buf.to_vec())
+    }
+
+    #[test]
+    fn test_encode() {
+        let bytes = encode(IntFloat::new(-1250, 2)).unwrap();
+        assert_eq!(bytes, [0, 2, 0, 0, 0x40, 0, 0, 2, 0, 12, 0x13, 0x88]);
+        let numeric = PgNumeric::from_bytes(&bytes).unwrap();
+        assert_eq!(numeric.to_intfloat(), Ok(IntFloat::new(-1250, 2)));
+        let error = encode(IntFloat::new(1, 20000)).unwrap_err();
+        assert_eq!(error.downcast_ref(), Some(&OutOfRangeError));
+    }
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <IntFloat as Type<Postgres>>::type_info().oid(),
+            Some(Oid(1700))
+        );
+        assert_eq!(
+            <IntFloat as PgHasArrayType>::array_type_info().oid(),
+            Some(Oid(1231))
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Descriptive statistics of slices of IntFloats. Every function returns its result with exactly
+//! `decimals` decimals, rounded once with the given `RoundingMode`, so aggregates of the same
+//! data line up however many decimals the values have. The values are brought to the largest pow
+//! among them and summed exactly as i128 bases. The functions return None for an empty slice (or
+//! a single value for the sample variance), if a base or sum doesn't fit in an i128 at that pow,
+//! or if the result doesn't fit in the base.
+//!
+//! ```
+//! use intfloat::{stats, IntFloat, RoundingMode};
+//! let prices = [IntFloat::new(1999, 2), IntFloat::new(5, 0), IntFloat::new(125, 1)];
+//! let mode = RoundingMode::HalfEven;
+//! assert_eq!(stats::mean(&prices, 2, mode).unwrap().to_string(), "12.50");
+//! assert_eq!(stats::median(&prices, 2, mode).unwrap().to_string(), "12.50");
+//! assert_eq!(stats::min(&prices, 2, mode).unwrap().to_string(), "5.00");
+//! assert_eq!(stats::max(&prices, 0, mode).unwrap().to_string(), "20");
+//! assert_eq!(stats::variance(&prices, 4, mode).unwrap().to_string(), "56.1750");
+//! assert_eq!(stats::stddev(&prices, 2, mode).unwrap().to_string(), "7.50");
+//! ```
+
+use crate::intfloat::IntFloat;
+use crate::root::isqrt;
+use crate::{BaseInt, IntFloat128, RoundingMode};
+use std::cmp::Ordering;
+
+/// The bases of `values` at the largest pow among them, as i128s, and that pow. None if `values`
+/// is empty or a base doesn't fit.
+fn aligned<T: BaseInt>(values: &[IntFloat<T>]) -> Option<(Vec<i128>, isize)> {
+    let pow = values.iter().map(|value| value.pow).max()?;
+    let bases = values
+        .iter()
+        .map(|value| Some(value.cast::<i128>()?.with_scale(pow)?.base))
+        .collect::<Option<_>>()?;
+    Some((bases, pow))
+}
+
+/// `numerator / denominator` with `decimals` decimals, rounded with `mode`.
+fn quotient<T: BaseInt>(
+    numerator: IntFloat128,
+    denominator: i128,
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    numerator
+        .checked_div_with_mode(IntFloat::new(denominator, 0), decimals, mode)?
+        .cast()
+}
+
+/// The sum of the squared deviations from the mean times the number of values, which is an
+/// integer at twice the pow, and the denominator of the variance with `ddof` delta degrees of
+/// freedom, also times the number of values.
+fn spread<T: BaseInt>(values: &[IntFloat<T>], ddof: i128) -> Option<(IntFloat128, i128)> {
+    let (bases, pow) = aligned(values)?;
+    let count = bases.len() as i128;
+    if count <= ddof {
+        return None;
+    }
+    let (sum, squares) = bases
+        .iter()
+        .try_fold((0_i128, 0_i128), |(sum, squares), &base| {
+            Some((
+                sum.checked_add(base)?,
+                squares.checked_add(base.checked_mul(base)?)?,
+            ))
+        })?;
+    // n * sum((x - mean)^2) = n * sum(x^2) - sum(x)^2, with only integers.
+    let numerator = count
+        .checked_mul(squares)?
+        .checked_sub(sum.checked_mul(sum)?)?;
+    Some((
+        IntFloat::new(numerator, pow.checked_mul(2)?),
+        count.checked_mul(count - ddof)?,
+    ))
+}
+
+/// The square root of `numerator / denominator` with `decimals` decimals, rounded with `mode`.
+fn sqrt_quotient<T: BaseInt>(
+    numerator: IntFloat128,
+    denominator: i128,
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    // Four times the radicand with twice the decimals, truncated, and whether that was exact.
+    let shift = decimals.checked_mul(2)?.checked_sub(numerator.pow)?;
+    let factor = u32::try_from(shift.unsigned_abs())
+        .ok()
+        .and_then(|shift| 10_u128.checked_pow(shift));
+    let scaled = numerator.base.unsigned_abs().checked_mul(4)?;
+    let denominator = denominator.unsigned_abs();
+    let (scaled, denominator) = if shift >= 0 {
+        (scaled.checked_mul(factor?)?, Some(denominator))
+    } else {
+        (
+            scaled,
+            factor.and_then(|factor| denominator.checked_mul(factor)),
+        )
+    };
+    let (quadrupled, is_exact) = match denominator {
+        Some(denominator) => (scaled / denominator, scaled % denominator == 0),
+        None => (0, scaled == 0),
+    };
+    // The truncated root of four times the radicand is twice the root, and its last bit tells
+    // whether the remainder of the root is at least a half.
+    let doubled = isqrt(quadrupled);
+    let is_square = is_exact && doubled * doubled == quadrupled;
+    let is_half_or_more = doubled % 2 == 1;
+    let half = match (is_half_or_more, is_square) {
+        (false, _) => Ordering::Less,
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+    };
+    let root = doubled / 2;
+    let away = mode.rounds_away(root % 2 == 1, !is_half_or_more && is_square, half, false);
+    let base = num_traits::cast(root + away as u128)?;
+    Some(IntFloat::new(base, decimals))
+}
+
+/// The arithmetic mean.
+pub fn mean<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (bases, pow) = aligned(values)?;
+    let sum = bases
+        .iter()
+        .try_fold(0_i128, |sum, &base| sum.checked_add(base))?;
+    quotient(IntFloat::new(sum, pow), bases.len() as i128, decimals, mode)
+}
+
+/// The middle value, or the mean of the two middle values for an even number of values.
+pub fn median<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (mut bases, pow) = aligned(values)?;
+    bases.sort_unstable();
+    let middle = bases.len() / 2;
+    if bases.len() % 2 == 1 {
+        quotient(IntFloat::new(bases[middle], pow), 1, decimals, mode)
+    } else {
+        let sum = bases[middle - 1].checked_add(bases[middle])?;
+        quotient(IntFloat::new(sum, pow), 2, decimals, mode)
+    }
+}
+
+/// The sample variance, which divides the squared deviations from the mean by one less than the
+/// number of values. None for fewer than two values.
+pub fn variance<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (numerator, denominator) = spread(values, 1)?;
+    quotient(numerator, denominator, decimals, mode)
+}
+
+/// The population variance, which divides the squared deviations from the mean by the number of
+/// values.
+pub fn population_variance<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (numerator, denominator) = spread(values, 0)?;
+    quotient(numerator, denominator, decimals, mode)
+}
+
+/// The sample standard deviation, the square root of `variance`. It is computed from the exact
+/// variance, so it is rounded only once. None for fewer than two values.
+pub fn stddev<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (numerator, denominator) = spread(values, 1)?;
+    sqrt_quotient(numerator, denominator, decimals, mode)
+}
+
+/// The population standard deviation, the square root of `population_variance`.
+pub fn population_stddev<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (numerator, denominator) = spread(values, 0)?;
+    sqrt_quotient(numerator, denominator, decimals, mode)
+}
+
+/// The smallest value.
+pub fn min<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (bases, pow) = aligned(values)?;
+    quotient(IntFloat::new(*bases.iter().min()?, pow), 1, decimals, mode)
+}
+
+/// The largest value.
+pub fn max<T: BaseInt>(
+    values: &[IntFloat<T>],
+    decimals: isize,
+    mode: RoundingMode,
+) -> Option<IntFloat<T>> {
+    let (bases, pow) = aligned(values)?;
+    quotient(IntFloat::new(*bases.iter().max()?, pow), 1, decimals, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+
+    const HALF_UP: RoundingMode = RoundingMode::HalfUp;
+
+    fn values(strs: &[&str]) -> Vec<IntFloat> {
+        strs.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    fn string(value: Option<IntFloat>) -> String {
+        value.unwrap().to_string()
+    }
+
+    #[test]
+    fn test_mean_median() {
+        let data = values(&["1.5", "2", "4.25", "-0.75"]);
+        assert_eq!(string(mean(&data, 2, HALF_UP)), "1.75");
+        assert_eq!(string(mean(&data, 4, HALF_UP)), "1.7500");
+        assert_eq!(string(mean(&data, 0, HALF_UP)), "2");
+        assert_eq!(string(mean(&data, 0, RoundingMode::Floor)), "1");
+        assert_eq!(string(median(&data, 3, HALF_UP)), "1.750");
+        assert_eq!(string(median(&data[..3], 1, HALF_UP)), "2.0");
+        assert_eq!(string(median(&data[..1], 0, HALF_UP)), "2");
+        assert_eq!(
+            string(median(&values(&["0.1", "0.2"]), 1, RoundingMode::HalfEven)),
+            "0.2"
+        );
+        assert_eq!(
+            string(median(&values(&["0.3", "0.2"]), 1, RoundingMode::HalfEven)),
+            "0.2"
+        );
+        assert_eq!(string(mean(&values(&["1", "1", "2"]), 3, HALF_UP)), "1.333");
+        assert_eq!(
+            string(mean(&values(&["-1", "-1", "-2"]), 3, RoundingMode::Floor)),
+            "-1.334"
+        );
+        assert_eq!(mean::<isize>(&[], 2, HALF_UP), None);
+        assert_eq!(median::<isize>(&[], 2, HALF_UP), None);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let data = values(&["1.5", "2", "4.25", "-0.75"]);
+        assert_eq!(string(min(&data, 2, HALF_UP)), "-0.75");
+        assert_eq!(string(min(&data, 1, HALF_UP)), "-0.8");
+        assert_eq!(string(min(&data, 1, RoundingMode::TowardZero)), "-0.7");
+        assert_eq!(string(max(&data, 3, HALF_UP)), "4.250");
+        assert_eq!(string(max(&data, -1, HALF_UP)), "0");
+        assert_eq!(max::<isize>(&[], 2, HALF_UP), None);
+    }
+
+    #[test]
+    fn test_variance() {
+        let data = values(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+        assert_eq!(string(population_variance(&data, 0, HALF_UP)), "4");
+        assert_eq!(string(population_stddev(&data, 2, HALF_UP)), "2.00");
+        assert_eq!(string(variance(&data, 6, HALF_UP)), "4.571429");
+        assert_eq!(string(stddev(&data, 6, HALF_UP)), "2.138090");
+        assert_eq!(string(stddev(&data, 6, RoundingMode::Ceiling)), "2.138090");
+        assert_eq!(string(stddev(&data, 6, RoundingMode::Floor)), "2.138089");
+
+        let data = values(&["0.1", "0.25", "-0.3"]);
+        assert_eq!(string(variance(&data, 4, HALF_UP)), "0.0808");
+        assert_eq!(string(stddev(&data, 4, HALF_UP)), "0.2843");
+        let constant = values(&["1.5", "1.50", "1.500"]);
+        assert_eq!(string(variance(&constant, 2, HALF_UP)), "0.00");
+        assert_eq!(string(stddev(&constant, 2, RoundingMode::Ceiling)), "0.00");
+
+        assert_eq!(variance(&data[..1], 2, HALF_UP), None);
+        assert_eq!(string(population_variance(&data[..1], 2, HALF_UP)), "0.00");
+        assert_eq!(population_stddev::<isize>(&[], 2, HALF_UP), None);
+    }
+
+    #[test]
+    fn test_sqrt_rounding() {
+        // The population stddev of 0 and 3 is exactly 1.5.
+        let data = values(&["0", "3"]);
+        let rounded = |mode| string(population_stddev(&data, 0, mode));
+        assert_eq!(rounded(RoundingMode::HalfUp), "2");
+        assert_eq!(rounded(RoundingMode::HalfDown), "1");
+        assert_eq!(rounded(RoundingMode::HalfEven), "2");
+        assert_eq!(rounded(RoundingMode::TowardZero), "1");
+        assert_eq!(
+            string(population_stddev(&data, 1, RoundingMode::Floor)),
+            "1.5"
+        );
+        assert_eq!(string(population_stddev(&data, -1, HALF_UP)), "0");
+
+        let data: Vec<IntFloat> = (1..=100).map(|i| IntFloat::new(i * i, 3)).collect();
+        let floats: Vec<f64> = data
+            .iter()
+            .map(|value| value.to_string().parse().unwrap())
+            .collect();
+        let mean = floats.iter().sum::<f64>() / 100.0;
+        let expected = (floats.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 99.0).sqrt();
+        assert_eq!(
+            string(stddev(&data, 6, HALF_UP)),
+            format!("{:.6}", expected)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let data = [IntFloat32::new(i32::MAX, 0), IntFloat32::new(i32::MAX, 0)];
+        assert_eq!(mean(&data, 0, HALF_UP), Some(IntFloat32::new(i32::MAX, 0)));
+        assert_eq!(mean(&data, 1, HALF_UP), None);
+        assert_eq!(variance(&data, 2, HALF_UP), Some(IntFloat32::new(0, 2)));
+        let far = [IntFloat::new(1, -40), IntFloat::new(1, 0)];
+        assert_eq!(mean(&far, 0, HALF_UP), None);
+        let large = [IntFloat::new(isize::MAX, 0), IntFloat::new(isize::MIN, 0)];
+        assert_eq!(string(mean(&large, 0, HALF_UP)), "-1");
+        assert_eq!(variance(&large, 0, HALF_UP), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::intfloat::IntFloat;
+use crate::math;
+use crate::BaseInt;
+
+/// Trigonometric functions of angles in radians, rounded half away from zero to `decimals`
+/// decimals. Angles are reduced with a 38-decimal pi, and the series are summed in fixed point
+/// with 32 decimals, so the error before rounding is below 10^-30 for angles up to about 1.7
+/// million radians. The tangent divides the sine by the cosine, so its error grows near the
+/// poles. Only integer arithmetic is used, so the results are identical on every platform.
+///
+/// The panicking versions panic if the result doesn't fit in the base, or if the angle is out of
+/// range.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let angle = IntFloat::new(5, 1);
+/// assert_eq!(angle.sin(6).to_string(), "0.479426");
+/// assert_eq!(angle.cos(6).to_string(), "0.877583");
+/// assert_eq!(angle.tan(6).to_string(), "0.546302");
+/// assert_eq!(angle.atan(6).to_string(), "0.463648");
+/// let (y, x) = (IntFloat::new(1, 0), IntFloat::new(-2, 0));
+/// assert_eq!(y.atan2(x, 6).to_string(), "2.677945");
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    fn expect_trig(result: Option<Self>) -> Self {
+        result.expect("attempt to compute a trigonometric function with overflow")
+    }
+
+    pub fn sin(self, decimals: isize) -> Self {
+        Self::expect_trig(self.checked_sin(decimals))
+    }
+
+    pub fn cos(self, decimals: isize) -> Self {
+        Self::expect_trig(self.checked_cos(decimals))
+    }
+
+    pub fn tan(self, decimals: isize) -> Self {
+        Self::expect_trig(self.checked_tan(decimals))
+    }
+
+    /// The arctangent, in [-pi/2, pi/2].
+    pub fn atan(self, decimals: isize) -> Self {
+        Self::expect_trig(self.checked_atan(decimals))
+    }
+
+    /// The angle of the point (`x`, `self`), in (-pi, pi]. Like for the primitive floats, the
+    /// angle of the origin is zero.
+    pub fn atan2(self, x: Self, decimals: isize) -> Self {
+        Self::expect_trig(self.checked_atan2(x, decimals))
+    }
+
+    pub fn checked_sin(self, decimals: isize) -> Option<Self> {
+        let (sin, _) = math::sin_cos(math::to_fixed(self)?)?;
+        math::from_fixed(sin, 0, decimals)
+    }
+
+    pub fn checked_cos(self, decimals: isize) -> Option<Self> {
+        let (_, cos) = math::sin_cos(math::to_fixed(self)?)?;
+        math::from_fixed(cos, 0, decimals)
+    }
+
+    pub fn checked_tan(self, decimals: isize) -> Option<Self> {
+        let (sin, cos) = math::sin_cos(math::to_fixed(self)?)?;
+        math::from_fixed(math::div(sin, cos)?, 0, decimals)
+    }
+
+    pub fn checked_atan(self, decimals: isize) -> Option<Self> {
+        math::from_fixed(math::atan2(self, IntFloat::one())?, 0, decimals)
+    }
+
+    pub fn checked_atan2(self, x: Self, decimals: isize) -> Option<Self> {
+        math::from_fixed(math::atan2(self, x)?, 0, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntFloat128, IntFloat32, IntFloat64};
+
+    #[test]
+    fn test_sin_cos_tan() {
+        let half = IntFloat64::new(5, 1);
+        assert_eq!(half.sin(18), IntFloat64::new(479425538604203000, 18));
+        assert_eq!(half.cos(18), IntFloat64::new(877582561890372716, 18));
+        assert_eq!(half.tan(18), IntFloat64::new(546302489843790513, 18));
+        assert_eq!((-half).sin(18), IntFloat64::new(-479425538604203000, 18));
+        assert_eq!((-half).cos(18), half.cos(18));
+
+        let two = IntFloat32::new(2, 0);
+        assert_eq!(two.sin(9), IntFloat32::new(909297427, 9));
+        assert_eq!(two.cos(9), IntFloat32::new(-416146837, 9));
+        assert_eq!(two.checked_tan(9), None);
+        assert_eq!(two.tan(8), IntFloat32::new(-218503986, 8));
+
+        assert_eq!(IntFloat64::new(0, 0).sin(2), IntFloat64::new(0, 2));
+        assert_eq!(IntFloat64::new(0, 0).cos(2), IntFloat64::new(100, 2));
+        assert_eq!(
+            IntFloat64::new(1, 10).sin(20),
+            IntFloat64::new(10000000000, 20)
+        );
+        assert_eq!(
+            IntFloat128::new(15707963267948966, 16).sin(30),
+            IntFloat128::new(1000000000000000000000000000000, 30)
+        );
+        let sixth_pi =
+            (IntFloat64::new(1, 0).atan(17) * 2_i32).div_with_precision(IntFloat64::new(3, 0), 17);
+        assert_eq!(sixth_pi.sin(6), IntFloat64::new(500000, 6));
+    }
+
+    #[test]
+    fn test_atan() {
+        assert_eq!(
+            IntFloat64::new(5, 1).atan(18),
+            IntFloat64::new(463647609000806116, 18)
+        );
+        assert_eq!(
+            IntFloat64::new(-3, 0).atan(12),
+            IntFloat64::new(-1249045772398, 12)
+        );
+        assert_eq!(
+            IntFloat64::new(1, -20).atan(10),
+            IntFloat64::new(15707963268, 10)
+        );
+        assert_eq!(IntFloat64::new(0, 0).atan(3), IntFloat64::new(0, 3));
+    }
+
+    #[test]
+    fn test_atan2() {
+        let (one, two) = (IntFloat64::new(1, 0), IntFloat64::new(2, 0));
+        let zero = IntFloat64::new(0, 0);
+        assert_eq!(one.atan2(-two, 15), IntFloat64::new(2677945044588987, 15));
+        assert_eq!(one.atan2(two, 15), one.div_with_precision(two, 2).atan(15));
+        assert_eq!(
+            (-one).atan2(-two, 15),
+            IntFloat64::new(-2677945044588987, 15)
+        );
+        assert_eq!(zero.atan2(zero, 2), IntFloat64::new(0, 2));
+        assert_eq!(zero.atan2(-one, 4), IntFloat64::new(31416, 4));
+        assert_eq!(one.atan2(zero, 4), IntFloat64::new(15708, 4));
+        assert_eq!((-one).atan2(zero, 4), IntFloat64::new(-15708, 4));
+        assert_eq!(
+            IntFloat64::new(1, -30).atan2(IntFloat64::new(1, 30), 4),
+            IntFloat64::new(15708, 4)
+        );
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(IntFloat64::new(1, -7).checked_sin(2), None);
+        assert_eq!(IntFloat64::new(1, 0).checked_cos(20), None);
+        assert_eq!(IntFloat64::new(1, 0).checked_atan(20), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to compute a trigonometric function with overflow")]
+    fn test_trig_overflow() {
+        IntFloat64::new(1, -7).sin(2);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use ::utoipa::openapi::{ObjectBuilder, RefOr, Schema, SchemaFormat, Type};
+use ::utoipa::{PartialSchema, ToSchema};
+use std::borrow::Cow;
+
+/// IntFloat is documented as a string with the custom format `decimal`, matching its default
+/// serde representation, e.g. `"12.34"`.
+impl<T: BaseInt> PartialSchema for IntFloat<T> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::Custom("decimal".to_string())))
+            .description(Some("An exact decimal number"))
+            .examples(["12.34"])
+            .into()
+    }
+}
+
+/// Every IntFloat has the schema name `IntFloat`, whatever its base: the base doesn't change the
+/// representation.
+impl<T: BaseInt> ToSchema for IntFloat<T> {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("IntFloat")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntFloat, IntFloat32};
+
+    #[test]
+    fn test_schema() {
+        let schema = serde_json::to_value(IntFloat::schema()).unwrap();
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "type": "string",
+                "format": "decimal",
+                "description": "An exact decimal number",
+                "examples": ["12.34"],
+            })
+        );
+        assert_eq!(IntFloat::name(), "IntFloat");
+        assert_eq!(IntFloat32::name(), "IntFloat");
+    }
+
+    #[derive(::utoipa::ToSchema)]
+    #[allow(dead_code)]
+    struct Order {
+        price: IntFloat,
+        discount: Option<IntFloat>,
+        lines: Vec<IntFloat32>,
+    }
+
+    #[test]
+    fn test_derive() {
+        let schema = serde_json::to_value(Order::schema()).unwrap();
+        let reference = "#/components/schemas/IntFloat";
+        assert_eq!(schema["properties"]["price"]["$ref"], reference);
+        assert_eq!(
+            schema["properties"]["discount"]["oneOf"][0]["$ref"],
+            reference
+        );
+        assert_eq!(schema["properties"]["lines"]["items"]["$ref"], reference);
+        let mut schemas = Vec::new();
+        Order::schemas(&mut schemas);
+        assert!(schemas
+            .iter()
+            .any(|(name, schema)| name == "IntFloat" && *schema == IntFloat::schema()));
+    }
+}
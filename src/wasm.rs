@@ -0,0 +1,157 @@
+use crate::intfloat::IntFloat;
+use crate::{BaseInt, IntFloatError};
+use js_sys::{Number, Object, RangeError, Reflect, SyntaxError, TypeError};
+use wasm_bindgen::JsValue;
+
+/// Converts to a JS string with the exact decimal string, e.g. `"12.50"`, which is how the value
+/// is usually shown or sent on. Use `to_js_object` for `{ base, pow }` instead.
+impl<T: BaseInt> From<IntFloat<T>> for JsValue {
+    fn from(value: IntFloat<T>) -> Self {
+        JsValue::from_str(&value.to_string())
+    }
+}
+
+impl<T: BaseInt> IntFloat<T> {
+    /// Convert to a JS object `{ base, pow }`. The base is a number if it is a safe integer, and
+    /// a BigInt otherwise, so it is always exact.
+    pub fn to_js_object(&self) -> JsValue {
+        let base = self.base.to_i128().expect("every base fits in an i128");
+        let base = if base.unsigned_abs() <= Number::MAX_SAFE_INTEGER as u128 {
+            JsValue::from_f64(base as f64)
+        } else {
+            JsValue::from(base)
+        };
+        let object = Object::new();
+        Reflect::set(&object, &"base".into(), &base).expect("an Object accepts new properties");
+        Reflect::set(&object, &"pow".into(), &(self.pow as f64).into())
+            .expect("an Object accepts new properties");
+        object.into()
+    }
+}
+
+fn range_error() -> JsValue {
+    RangeError::new("value doesn't fit in the base of the IntFloat").into()
+}
+
+fn integer(value: &JsValue) -> Result<i128, JsValue> {
+    if Number::is_safe_integer(value) {
+        Ok(value.as_f64().expect("a safe integer is a number") as i128)
+    } else if value.is_bigint() {
+        i128::try_from(value.clone()).map_err(|_| range_error())
+    } else if let Some(str) = value.as_string() {
+        str.parse()
+            .map_err(|_| SyntaxError::new("invalid integer for IntFloat").into())
+    } else {
+        Err(
+            TypeError::new("expected an integer, a BigInt or an integer string for IntFloat")
+                .into(),
+        )
+    }
+}
+
+/// Converts a decimal string like `"12.50"` or `"1.5e-3"`, or an object `{ base, pow }` like the
+/// one from `to_js_object`. The base of that object can be a safe integer, a BigInt or an integer
+/// string, and the pow must be a safe integer. A plain number isn't accepted, because it usually
+/// isn't exactly the decimal number it was written as. Fails with a TypeError or a SyntaxError
+/// for other values, and with a RangeError if the value doesn't fit in the base.
+///
+/// # Examples
+///
+/// ```no_run
+/// use intfloat::IntFloat;
+/// use wasm_bindgen::prelude::*;
+///
+/// #[wasm_bindgen]
+/// pub fn total(price: JsValue, quantity: u32) -> Result<JsValue, JsValue> {
+///     let price = IntFloat::try_from(price)?;
+///     Ok((price * IntFloat::new(quantity as isize, 0)).into())
+/// }
+/// ```
+impl<T: BaseInt> TryFrom<JsValue> for IntFloat<T> {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if let Some(str) = value.as_string() {
+            return str.parse().map_err(|error| match error {
+                IntFloatError::Overflow | IntFloatError::ScaleTooLarge => range_error(),
+                _ => SyntaxError::new(&format!("cannot parse {:?} as IntFloat", str)).into(),
+            });
+        }
+        if !value.is_object() {
+            return Err(
+                TypeError::new("expected a decimal string or { base, pow } for IntFloat").into(),
+            );
+        }
+        let base = integer(&Reflect::get(&value, &"base".into())?)?;
+        let pow = Reflect::get(&value, &"pow".into())?;
+        if !Number::is_safe_integer(&pow) {
+            return Err(TypeError::new("expected an integer pow for IntFloat").into());
+        }
+        let pow = pow.as_f64().expect("a safe integer is a number");
+        let pow = isize::try_from(pow as i64).map_err(|_| range_error())?;
+        let base = num_traits::cast(base).ok_or_else(range_error)?;
+        Ok(IntFloat::new(base, pow))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use crate::{IntFloat, IntFloat128, IntFloat32};
+    use js_sys::{Object, RangeError, Reflect, SyntaxError, TypeError};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn object(base: JsValue, pow: JsValue) -> JsValue {
+        let object = Object::new();
+        Reflect::set(&object, &"base".into(), &base).unwrap();
+        Reflect::set(&object, &"pow".into(), &pow).unwrap();
+        object.into()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_string() {
+        let value = JsValue::from(IntFloat::new(1250, 2));
+        assert_eq!(value.as_string().as_deref(), Some("12.50"));
+        assert_eq!(IntFloat::try_from(value), Ok(IntFloat::new(1250, 2)));
+        assert_eq!(
+            IntFloat::try_from(JsValue::from_str("1.5e-3")),
+            Ok(IntFloat::new(15, 4))
+        );
+        let error = IntFloat::try_from(JsValue::from_str("1.2.3")).unwrap_err();
+        assert!(error.is_instance_of::<SyntaxError>());
+        let error = IntFloat32::try_from(JsValue::from_str("12345678901.5")).unwrap_err();
+        assert!(error.is_instance_of::<RangeError>());
+        let error = IntFloat::try_from(JsValue::from_f64(1.5)).unwrap_err();
+        assert!(error.is_instance_of::<TypeError>());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_object() {
+        let value = IntFloat::new(-7, 3).to_js_object();
+        assert_eq!(Reflect::get(&value, &"base".into()).unwrap(), -7.0);
+        assert_eq!(Reflect::get(&value, &"pow".into()).unwrap(), 3.0);
+        assert_eq!(IntFloat::try_from(value), Ok(IntFloat::new(-7, 3)));
+
+        let wide = IntFloat128::new(i128::MIN, 20);
+        let value = wide.to_js_object();
+        assert!(Reflect::get(&value, &"base".into()).unwrap().is_bigint());
+        assert_eq!(IntFloat128::try_from(value), Ok(wide));
+
+        let value = object("123456789012345678901234".into(), 10.into());
+        assert_eq!(
+            IntFloat128::try_from(value.clone()).map(|value| value.to_string()),
+            Ok("12345678901234.5678901234".to_string())
+        );
+        assert!(IntFloat::try_from(value)
+            .unwrap_err()
+            .is_instance_of::<RangeError>());
+        let value = object(1.5.into(), 0.into());
+        assert!(IntFloat::try_from(value)
+            .unwrap_err()
+            .is_instance_of::<TypeError>());
+        let value = object(15.into(), 0.5.into());
+        assert!(IntFloat::try_from(value)
+            .unwrap_err()
+            .is_instance_of::<TypeError>());
+    }
+}
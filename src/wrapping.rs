@@ -0,0 +1,136 @@
+use crate::intfloat::IntFloat;
+use crate::BaseInt;
+use num_traits::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+/// Arithmetic that wraps the base around at the boundary of its integer type (two's complement),
+/// just like the wrapping_* methods of the primitive integers. The outcome is deterministic, but
+/// of course loses its numeric meaning once the base wrapped around.
+///
+/// # Examples
+///
+/// ```
+/// use intfloat::IntFloat;
+/// let a = IntFloat::new(isize::MAX, 2);
+/// assert_eq!(a.wrapping_add(IntFloat::new(1, 2)), IntFloat::new(isize::MIN, 2));
+/// assert_eq!(
+///     IntFloat::new(1, 0).wrapping_sub(IntFloat::new(1, 2)),
+///     IntFloat::new(99, 2)
+/// );
+/// ```
+impl<T: BaseInt> IntFloat<T> {
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Panics if `rhs` is zero, just like the primitive integers do.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        IntFloat {
+            base: self.base.wrapping_neg(),
+            pow: self.pow,
+        }
+    }
+}
+
+impl<T: BaseInt> WrappingAdd for IntFloat<T> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        IntFloat::wrapping_add(*self, *v)
+    }
+}
+
+impl<T: BaseInt> WrappingSub for IntFloat<T> {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        IntFloat::wrapping_sub(*self, *v)
+    }
+}
+
+impl<T: BaseInt> WrappingMul for IntFloat<T> {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        IntFloat::wrapping_mul(*self, *v)
+    }
+}
+
+impl<T: BaseInt> WrappingNeg for IntFloat<T> {
+    fn wrapping_neg(&self) -> Self {
+        IntFloat::wrapping_neg(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntFloat;
+
+    #[test]
+    fn test_wrapping_add() {
+        let this = IntFloat::new(534, 0);
+        let that = IntFloat::new(100, 2);
+        assert_eq!(this.wrapping_add(that), this + that);
+
+        let result = IntFloat::new(isize::MAX, 0).wrapping_add(IntFloat::new(0, 1));
+        assert_eq!((result.base, result.pow), (-10, 1));
+    }
+
+    #[test]
+    fn test_wrapping_sub() {
+        let this = IntFloat::new(534, -2);
+        let that = IntFloat::new(1068, -2);
+        assert_eq!(that.wrapping_sub(this), this);
+
+        let result = IntFloat::new(isize::MIN, 3).wrapping_sub(IntFloat::new(1, 3));
+        assert_eq!((result.base, result.pow), (isize::MAX, 3));
+    }
+
+    #[test]
+    fn test_wrapping_mul() {
+        let this = IntFloat::new(500, 2);
+        assert_eq!(this.wrapping_mul(this), IntFloat::new(250000, 4));
+
+        let result = IntFloat::new(isize::MAX, 3).wrapping_mul(IntFloat::new(2, 1));
+        assert_eq!((result.base, result.pow), (-2, 4));
+    }
+
+    #[test]
+    fn test_wrapping_div() {
+        let this = IntFloat::new(500, 2);
+        let that = IntFloat::new(250000, 4);
+        assert_eq!(that.wrapping_div(this), this);
+
+        let result = IntFloat::new(isize::MIN, 0).wrapping_div(IntFloat::new(-1, 0));
+        assert_eq!((result.base, result.pow), (isize::MIN, 0));
+    }
+
+    #[test]
+    fn test_wrapping_neg() {
+        assert_eq!(IntFloat::new(5, 1).wrapping_neg(), IntFloat::new(-5, 1));
+        let result = IntFloat::new(isize::MIN, 1).wrapping_neg();
+        assert_eq!((result.base, result.pow), (isize::MIN, 1));
+    }
+
+    #[test]
+    fn test_wrapping_traits() {
+        let this = IntFloat::new(isize::MAX, 0);
+        let that = IntFloat::new(1, 0);
+        assert_eq!(
+            WrappingAdd::wrapping_add(&this, &that),
+            IntFloat::new(isize::MIN, 0)
+        );
+        assert_eq!(
+            WrappingSub::wrapping_sub(&-this, &that),
+            IntFloat::new(isize::MIN, 0)
+        );
+        assert_eq!(WrappingMul::wrapping_mul(&this, &that), this);
+        assert_eq!(WrappingNeg::wrapping_neg(&this), -this);
+    }
+}